@@ -80,7 +80,7 @@ impl DebtTokenTrait for DebtToken {
 
     /// Returns the current version of the contract.
     fn version() -> u32 {
-        1
+        debt_token_interface::VERSION
     }
 
     /// Returns the balance of tokens for a specified `id`.
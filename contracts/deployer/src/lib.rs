@@ -1,19 +1,79 @@
 #![deny(warnings)]
 #![no_std]
 
+use pool_interface::types::collateral_params_input::CollateralParamsInput;
 use pool_interface::types::pool_config::PoolConfig;
+use pool_interface::types::price_feed_config_input::PriceFeedConfigInput;
+use pool_interface::types::reserve_type::ReserveType;
+use pool_interface::LendingPoolClient;
 use soroban_sdk::{
-    contract, contractimpl, vec, Address, BytesN, Env, IntoVal, String, Symbol, Val,
+    contract, contracterror, contractimpl, contracttype, panic_with_error, vec, Address, Bytes,
+    BytesN, Env, IntoVal, String, Symbol, Val, Vec,
 };
+use storage::{
+    add_pool, add_pool_reserve, has_admin, read_admin, read_pool_reserves, read_pools, write_admin,
+};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    InvalidPoolVersion = 0,
+    TimelockedPool = 1,
+    AlreadyInitialized = 2,
+    Uninitialized = 3,
+    NotDeployer = 4,
+}
+
+/// Wasm and metadata of a reserve token to deploy
+#[contracttype]
+#[derive(Clone)]
+pub struct TokenParams {
+    pub wasm_hash: BytesN<32>,
+    pub name: String,
+    pub symbol: String,
+}
+
+/// Reserve tokens deployed for the asset of a pool
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeployedReserve {
+    pub asset: Address,
+    pub s_token: Address,
+    pub debt_token: Address,
+}
 
 #[contract]
 pub struct Deployer;
 
 #[contractimpl]
 impl Deployer {
+    /// Sets the admin who authorizes the deployments recorded in the registry.
+    /// The admin has to be the account that deployed the contract with the `salt`,
+    /// so nobody else can take the deployer over before it is initialized.
+    pub fn initialize(env: Env, admin: Address, salt: BytesN<32>) {
+        if has_admin(&env) {
+            panic_with_error!(&env, Error::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+
+        let deployed_by_admin = env
+            .deployer()
+            .with_address(admin.clone(), salt)
+            .deployed_address();
+        if deployed_by_admin != env.current_contract_address() {
+            panic_with_error!(&env, Error::NotDeployer);
+        }
+
+        write_admin(&env, &admin);
+    }
+
     /// Deploy the pool contract wasm and after deployment invoke the `initialize` function
-    /// of the contract with the given admin address. Returns the contract ID and
-    /// result of the `initialize` function.
+    /// of the contract with the given admin address. Requires authorization of the deployer
+    /// admin. Fails if the pool reports a version the deployer isn't able to work with,
+    /// otherwise the pool is added to the registry.
+    /// Returns the contract ID and result of the `initialize` function.
     #[allow(clippy::too_many_arguments)]
     pub fn deploy_pool(
         env: Env,
@@ -22,16 +82,30 @@ impl Deployer {
         admin: Address,
         pool_config: PoolConfig,
     ) -> (Address, Val) {
+        read_admin(&env).require_auth();
+
         let id = env.deployer().with_current_contract(salt).deploy(wasm_hash);
         let init_fn = Symbol::new(&env, "initialize");
         let init_args = vec![&env, admin.into_val(&env), pool_config.into_val(&env)];
         let res: Val = env.invoke_contract(&id, &init_fn, init_args);
+
+        let version = LendingPoolClient::new(&env, &id).version();
+        let compatible =
+            pool_interface::MIN_COMPATIBLE_VERSION..=pool_interface::MAX_COMPATIBLE_VERSION;
+
+        if !compatible.contains(&version) {
+            panic_with_error!(&env, Error::InvalidPoolVersion);
+        }
+
+        add_pool(&env, &id);
+
         (id, res)
     }
 
     /// Deploy the s-token contract wasm and after deployment invoke the `initialize` function
-    /// of the contract with the given arguments. Returns the contract ID and
-    /// result of the `initialize` function.
+    /// of the contract with the given arguments. Requires authorization of the deployer admin,
+    /// so nobody else is able to take the addresses `deploy_reserve` derives.
+    /// Returns the contract ID and result of the `initialize` function.
     #[allow(clippy::too_many_arguments)]
     pub fn deploy_s_token(
         env: Env,
@@ -42,22 +116,15 @@ impl Deployer {
         pool: Address,
         underlying_asset: Address,
     ) -> (Address, Val) {
-        let id = env.deployer().with_current_contract(salt).deploy(wasm_hash);
-        let init_fn = Symbol::new(&env, "initialize");
-        let init_args = vec![
-            &env,
-            name.into_val(&env),
-            symbol.into_val(&env),
-            pool.into_val(&env),
-            underlying_asset.into_val(&env),
-        ];
-        let res: Val = env.invoke_contract(&id, &init_fn, init_args);
-        (id, res)
+        read_admin(&env).require_auth();
+
+        deploy_token(&env, salt, wasm_hash, name, symbol, pool, underlying_asset)
     }
 
     /// Deploy the debt token contract wasm and after deployment invoke the `initialize` function
-    /// of the contract with the given arguments. Returns the contract ID and
-    /// result of the `initialize` function.
+    /// of the contract with the given arguments. Requires authorization of the deployer admin,
+    /// so nobody else is able to take the addresses `deploy_reserve` derives.
+    /// Returns the contract ID and result of the `initialize` function.
     #[allow(clippy::too_many_arguments)]
     pub fn deploy_debt_token(
         env: Env,
@@ -68,18 +135,138 @@ impl Deployer {
         pool: Address,
         underlying_asset: Address,
     ) -> (Address, Val) {
-        let id = env.deployer().with_current_contract(salt).deploy(wasm_hash);
-        let init_fn = Symbol::new(&env, "initialize");
-        let init_args = vec![
+        read_admin(&env).require_auth();
+
+        deploy_token(&env, salt, wasm_hash, name, symbol, pool, underlying_asset)
+    }
+
+    /// Deploy the s-token and the debt token of the asset with salts derived from `salt`,
+    /// initialize the reserve in the pool and optionally configure it as collateral and
+    /// set its price feed. Requires authorization of the deployer admin and the pool admin
+    /// roles. Collateral and price feed configuration have to be scheduled on a timelocked
+    /// pool, so these fail with `TimelockedPool` when the pool has a timelock delay.
+    /// The reserve is added to the registry of the pool.
+    /// Returns the s-token and debt token contract IDs.
+    #[allow(clippy::too_many_arguments)]
+    pub fn deploy_reserve(
+        env: Env,
+        salt: BytesN<32>,
+        pool: Address,
+        asset: Address,
+        s_token: TokenParams,
+        debt_token: TokenParams,
+        collat_params: Option<CollateralParamsInput>,
+        price_feed: Option<PriceFeedConfigInput>,
+    ) -> (Address, Address) {
+        read_admin(&env).require_auth();
+
+        let pool = LendingPoolClient::new(&env, &pool);
+
+        if (collat_params.is_some() || price_feed.is_some()) && pool.timelock_delay() > 0 {
+            panic_with_error!(&env, Error::TimelockedPool);
+        }
+
+        let (s_token_id, _) = deploy_token(
             &env,
-            name.into_val(&env),
-            symbol.into_val(&env),
-            pool.into_val(&env),
-            underlying_asset.into_val(&env),
-        ];
-        let res: Val = env.invoke_contract(&id, &init_fn, init_args);
-        (id, res)
+            derive_salt(&env, &salt, 0),
+            s_token.wasm_hash,
+            s_token.name,
+            s_token.symbol,
+            pool.address.clone(),
+            asset.clone(),
+        );
+        let (debt_token_id, _) = deploy_token(
+            &env,
+            derive_salt(&env, &salt, 1),
+            debt_token.wasm_hash,
+            debt_token.name,
+            debt_token.symbol,
+            pool.address.clone(),
+            asset.clone(),
+        );
+
+        pool.init_reserve(
+            &asset,
+            &ReserveType::Fungible(s_token_id.clone(), debt_token_id.clone()),
+        );
+
+        if let Some(collat_params) = collat_params {
+            pool.configure_as_collateral(&asset, &collat_params);
+        }
+
+        if let Some(price_feed) = price_feed {
+            pool.set_price_feeds(&vec![&env, price_feed]);
+        }
+
+        add_pool_reserve(
+            &env,
+            &pool.address,
+            &DeployedReserve {
+                asset,
+                s_token: s_token_id.clone(),
+                debt_token: debt_token_id.clone(),
+            },
+        );
+
+        (s_token_id, debt_token_id)
+    }
+
+    /// Returns up to `limit` pools deployed by `deploy_pool` starting from the `from`-th one
+    /// in the order of deployment.
+    pub fn pools(env: Env, from: u32, limit: u32) -> Vec<Address> {
+        read_pools(&env, from, limit)
+    }
+
+    /// Returns up to `limit` reserves of the pool deployed by `deploy_reserve` starting from
+    /// the `from`-th one in the order of deployment.
+    pub fn reserves(env: Env, pool: Address, from: u32, limit: u32) -> Vec<DeployedReserve> {
+        read_pool_reserves(&env, &pool, from, limit)
+    }
+
+    /// Returns the contract ID which a deployment with the `salt` results in.
+    pub fn deployed_address(env: Env, salt: BytesN<32>) -> Address {
+        env.deployer()
+            .with_current_contract(salt)
+            .deployed_address()
+    }
+
+    /// Returns the s-token and debt token contract IDs which `deploy_reserve`
+    /// with the `salt` results in.
+    pub fn reserve_addresses(env: Env, salt: BytesN<32>) -> (Address, Address) {
+        (
+            Self::deployed_address(env.clone(), derive_salt(&env, &salt, 0)),
+            Self::deployed_address(env.clone(), derive_salt(&env, &salt, 1)),
+        )
     }
 }
 
+fn deploy_token(
+    env: &Env,
+    salt: BytesN<32>,
+    wasm_hash: BytesN<32>,
+    name: String,
+    symbol: String,
+    pool: Address,
+    underlying_asset: Address,
+) -> (Address, Val) {
+    let id = env.deployer().with_current_contract(salt).deploy(wasm_hash);
+    let init_fn = Symbol::new(env, "initialize");
+    let init_args = vec![
+        env,
+        name.into_val(env),
+        symbol.into_val(env),
+        pool.into_val(env),
+        underlying_asset.into_val(env),
+    ];
+    let res: Val = env.invoke_contract(&id, &init_fn, init_args);
+    (id, res)
+}
+
+fn derive_salt(env: &Env, salt: &BytesN<32>, index: u8) -> BytesN<32> {
+    let mut data = Bytes::from_array(env, &salt.to_array());
+    data.push_back(index);
+    env.crypto().sha256(&data).to_bytes()
+}
+
+mod storage;
 mod test;
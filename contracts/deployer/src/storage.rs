@@ -0,0 +1,135 @@
+use core::ops::Range;
+
+use soroban_sdk::{contracttype, panic_with_error, vec, Address, Env, Vec};
+
+use crate::{DeployedReserve, Error};
+
+pub(crate) const DAY_IN_LEDGERS: u32 = 17_280;
+
+pub(crate) const LOW_INSTANCE_BUMP_LEDGERS: u32 = DAY_IN_LEDGERS;
+pub(crate) const HIGH_INSTANCE_BUMP_LEDGERS: u32 = 7 * DAY_IN_LEDGERS;
+
+pub(crate) const LOW_REGISTRY_BUMP_LEDGERS: u32 = 10 * DAY_IN_LEDGERS;
+pub(crate) const HIGH_REGISTRY_BUMP_LEDGERS: u32 = 20 * DAY_IN_LEDGERS;
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    PoolCount,
+    Pool(u32),
+    PoolReserveCount(Address),
+    PoolReserve(Address, u32),
+}
+
+fn bump_instance(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(LOW_INSTANCE_BUMP_LEDGERS, HIGH_INSTANCE_BUMP_LEDGERS);
+}
+
+fn bump_registry(env: &Env, key: &DataKey) {
+    env.storage().persistent().extend_ttl(
+        key,
+        LOW_REGISTRY_BUMP_LEDGERS,
+        HIGH_REGISTRY_BUMP_LEDGERS,
+    );
+}
+
+pub fn has_admin(env: &Env) -> bool {
+    bump_instance(env);
+
+    env.storage().instance().has(&DataKey::Admin)
+}
+
+pub fn write_admin(env: &Env, admin: &Address) {
+    bump_instance(env);
+
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+pub fn read_admin(env: &Env) -> Address {
+    bump_instance(env);
+
+    env.storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .unwrap_or_else(|| panic_with_error!(env, Error::Uninitialized))
+}
+
+fn read_count(env: &Env, key: &DataKey) -> u32 {
+    let count = env.storage().persistent().get(key);
+
+    if count.is_some() {
+        bump_registry(env, key);
+    }
+
+    count.unwrap_or(0)
+}
+
+fn write_count(env: &Env, key: &DataKey, count: u32) {
+    env.storage().persistent().set(key, &count);
+    bump_registry(env, key);
+}
+
+/// Returns the indexes of up to `limit` entries starting from `from` out of `count`.
+fn page(count: u32, from: u32, limit: u32) -> Range<u32> {
+    from..count.min(from.saturating_add(limit))
+}
+
+pub fn read_pools(env: &Env, from: u32, limit: u32) -> Vec<Address> {
+    let mut pools = vec![env];
+
+    for index in page(read_count(env, &DataKey::PoolCount), from, limit) {
+        let key = DataKey::Pool(index);
+
+        if let Some(pool) = env.storage().persistent().get(&key) {
+            bump_registry(env, &key);
+            pools.push_back(pool);
+        }
+    }
+
+    pools
+}
+
+pub fn add_pool(env: &Env, pool: &Address) {
+    let count = read_count(env, &DataKey::PoolCount);
+    let key = DataKey::Pool(count);
+
+    env.storage().persistent().set(&key, pool);
+    bump_registry(env, &key);
+
+    write_count(env, &DataKey::PoolCount, count + 1);
+}
+
+pub fn read_pool_reserves(
+    env: &Env,
+    pool: &Address,
+    from: u32,
+    limit: u32,
+) -> Vec<DeployedReserve> {
+    let count = read_count(env, &DataKey::PoolReserveCount(pool.clone()));
+    let mut reserves = vec![env];
+
+    for index in page(count, from, limit) {
+        let key = DataKey::PoolReserve(pool.clone(), index);
+
+        if let Some(reserve) = env.storage().persistent().get(&key) {
+            bump_registry(env, &key);
+            reserves.push_back(reserve);
+        }
+    }
+
+    reserves
+}
+
+pub fn add_pool_reserve(env: &Env, pool: &Address, reserve: &DeployedReserve) {
+    let count_key = DataKey::PoolReserveCount(pool.clone());
+    let count = read_count(env, &count_key);
+    let key = DataKey::PoolReserve(pool.clone(), count);
+
+    env.storage().persistent().set(&key, reserve);
+    bump_registry(env, &key);
+
+    write_count(env, &count_key, count + 1);
+}
@@ -1,10 +1,15 @@
 #![cfg(test)]
 extern crate std;
 
-use crate::{Deployer, DeployerClient};
+use crate::{DeployedReserve, Deployer, DeployerClient, Error, TokenParams};
+use pool_interface::types::collateral_params_input::CollateralParamsInput;
+use pool_interface::types::oracle_asset::OracleAsset;
 use pool_interface::types::pool_config::PoolConfig;
+use pool_interface::types::price_feed::PriceFeed;
+use pool_interface::types::price_feed_config_input::PriceFeedConfigInput;
+use pool_interface::types::timestamp_precision::TimestampPrecision;
 use soroban_sdk::{
-    testutils::Address as _, token::Client as TokenClient, Address, BytesN, Env, String,
+    testutils::Address as _, token::Client as TokenClient, vec, Address, BytesN, Env, String,
 };
 
 mod pool {
@@ -21,23 +26,29 @@ mod debt_token {
     );
 }
 
-#[test]
-fn deploy_pool_and_s_token() {
-    let env = Env::default();
-    let client = DeployerClient::new(&env, &env.register_contract(None, Deployer));
+fn create_deployer(env: &Env) -> DeployerClient {
+    let admin = Address::generate(env);
+    let salt = BytesN::from_array(env, &[7; 32]);
+    let address = env
+        .deployer()
+        .with_address(admin.clone(), salt.clone())
+        .deployed_address();
+    let client = DeployerClient::new(env, &env.register_contract(Some(&address), Deployer));
 
-    let flash_loan_fee = 5;
-    let initial_health = 2_500;
-    let grace_period = 60 * 60 * 24;
+    client.mock_all_auths().initialize(&admin, &salt);
+    env.set_auths(&[]);
 
-    // Deploy pool
-    let pool_config = PoolConfig {
-        base_asset_address: Address::generate(&env),
+    client
+}
+
+fn pool_config(env: &Env) -> PoolConfig {
+    PoolConfig {
+        base_asset_address: Address::generate(env),
         base_asset_decimals: 7,
-        flash_loan_fee: flash_loan_fee,
-        initial_health: initial_health,
+        flash_loan_fee: 5,
+        initial_health: 2_500,
         timestamp_window: 20,
-        grace_period: grace_period,
+        grace_period: 60 * 60 * 24,
         user_assets_limit: 4,
         min_collat_amount: 0,
         min_debt_amount: 0,
@@ -46,11 +57,23 @@ fn deploy_pool_and_s_token() {
         ir_initial_rate: 200,
         ir_max_rate: 50_000,
         ir_scaling_coeff: 9_000,
-    };
+    }
+}
+
+#[test]
+fn deploy_pool_and_s_token() {
+    let env = Env::default();
+
+    let client = create_deployer(&env);
+    env.mock_all_auths();
+
+    // Deploy pool
+    let pool_config = pool_config(&env);
 
     let pool_contract_id = {
         // Install the WASM code to be deployed from the deployer contract.
         let pool_wasm_hash = env.deployer().upload_contract_wasm(pool::WASM);
+        env.budget().reset_default();
 
         // Deploy contract using deployer, and include an init function to call.
         let salt = BytesN::from_array(&env, &[0; 32]);
@@ -129,3 +152,301 @@ fn deploy_pool_and_s_token() {
         onchain_pool_config.ir_scaling_coeff
     );
 }
+
+#[test]
+fn deploy_reserve() {
+    let env = Env::default();
+
+    let client = create_deployer(&env);
+    env.mock_all_auths_allowing_non_root_auth();
+    let pool_wasm_hash = env.deployer().upload_contract_wasm(pool::WASM);
+    env.budget().reset_default();
+    let (pool_contract_id, _) = client.deploy_pool(
+        &BytesN::from_array(&env, &[0; 32]),
+        &pool_wasm_hash,
+        &Address::generate(&env),
+        &pool_config(&env),
+    );
+
+    env.budget().reset_default();
+
+    let asset = env.register_stellar_asset_contract(Address::generate(&env));
+    let s_token = TokenParams {
+        wasm_hash: env.deployer().upload_contract_wasm(s_token::WASM),
+        name: String::from_str(&env, "s-token"),
+        symbol: String::from_str(&env, "STOKEN"),
+    };
+    let debt_token = TokenParams {
+        wasm_hash: env.deployer().upload_contract_wasm(debt_token::WASM),
+        name: String::from_str(&env, "debt token"),
+        symbol: String::from_str(&env, "DTOKEN"),
+    };
+    let collat_params = CollateralParamsInput {
+        liq_cap: 1_000_000_000,
+        pen_order: 1,
+        util_cap: 9_000,
+        discount: 6_000,
+    };
+    let price_feed = PriceFeedConfigInput {
+        asset: asset.clone(),
+        asset_decimals: 7,
+        min_sanity_price_in_base: 1,
+        max_sanity_price_in_base: 1_000_000_000,
+        lastprice_fallback: false,
+        stored_price_max_age: 0,
+        max_feed_deviation: 0,
+        min_feeds: 1,
+        max_price_change: 0,
+        price_change_window: 0,
+        breaker_timeout: 0,
+        conservative_pricing: false,
+        feeds: vec![
+            &env,
+            PriceFeed {
+                feed: Address::generate(&env),
+                feed_asset: OracleAsset::Stellar(asset.clone()),
+                feed_decimals: 14,
+                twap_window: 3600,
+                min_twap_span: 0,
+                min_timestamp_delta: 0,
+                timestamp_precision: TimestampPrecision::Sec,
+                path: vec![&env],
+                weight: None,
+            },
+        ],
+    };
+
+    let salt = BytesN::from_array(&env, &[1; 32]);
+    let expected_addresses = client.reserve_addresses(&salt);
+
+    let (s_token_id, debt_token_id) = client.deploy_reserve(
+        &salt,
+        &pool_contract_id,
+        &asset,
+        &s_token,
+        &debt_token,
+        &Some(collat_params),
+        &Some(price_feed),
+    );
+
+    assert_eq!(
+        expected_addresses,
+        (s_token_id.clone(), debt_token_id.clone())
+    );
+
+    env.budget().reset_default();
+
+    let pool_client = pool::Client::new(&env, &pool_contract_id);
+    let reserve = pool_client.get_reserve(&asset).unwrap();
+
+    assert_eq!(
+        reserve.reserve_type,
+        pool::ReserveType::Fungible(s_token_id.clone(), debt_token_id.clone())
+    );
+    assert_eq!(reserve.configuration.discount, 6_000);
+    assert_eq!(reserve.configuration.util_cap, 9_000);
+    assert!(pool_client.price_feeds(&asset).is_some());
+    assert_eq!(
+        s_token::Client::new(&env, &s_token_id).underlying_asset(),
+        asset
+    );
+    assert_eq!(
+        debt_token::Client::new(&env, &debt_token_id).symbol(),
+        debt_token.symbol
+    );
+}
+
+#[test]
+fn deploy_reserve_to_timelocked_pool() {
+    let env = Env::default();
+
+    let client = create_deployer(&env);
+    env.mock_all_auths_allowing_non_root_auth();
+    let pool_wasm_hash = env.deployer().upload_contract_wasm(pool::WASM);
+    env.budget().reset_default();
+    let (pool_contract_id, _) = client.deploy_pool(
+        &BytesN::from_array(&env, &[0; 32]),
+        &pool_wasm_hash,
+        &Address::generate(&env),
+        &pool_config(&env),
+    );
+
+    env.budget().reset_default();
+
+    let pool_client = pool::Client::new(&env, &pool_contract_id);
+    pool_client.set_timelock_delay(&60);
+
+    let asset = env.register_stellar_asset_contract(Address::generate(&env));
+    let s_token = TokenParams {
+        wasm_hash: env.deployer().upload_contract_wasm(s_token::WASM),
+        name: String::from_str(&env, "s-token"),
+        symbol: String::from_str(&env, "STOKEN"),
+    };
+    let debt_token = TokenParams {
+        wasm_hash: env.deployer().upload_contract_wasm(debt_token::WASM),
+        name: String::from_str(&env, "debt token"),
+        symbol: String::from_str(&env, "DTOKEN"),
+    };
+    let collat_params = CollateralParamsInput {
+        liq_cap: 1_000_000_000,
+        pen_order: 1,
+        util_cap: 9_000,
+        discount: 6_000,
+    };
+
+    let result = client.try_deploy_reserve(
+        &BytesN::from_array(&env, &[1; 32]),
+        &pool_contract_id,
+        &asset,
+        &s_token,
+        &debt_token,
+        &Some(collat_params),
+        &None,
+    );
+
+    assert_eq!(result, Err(Ok(Error::TimelockedPool.into())));
+
+    env.budget().reset_default();
+
+    client.deploy_reserve(
+        &BytesN::from_array(&env, &[1; 32]),
+        &pool_contract_id,
+        &asset,
+        &s_token,
+        &debt_token,
+        &None,
+        &None,
+    );
+
+    assert!(pool_client.get_reserve(&asset).is_some());
+}
+
+#[test]
+fn register_deployments() {
+    let env = Env::default();
+
+    let client = create_deployer(&env);
+    env.mock_all_auths_allowing_non_root_auth();
+    let pool_wasm_hash = env.deployer().upload_contract_wasm(pool::WASM);
+    let pool_salt = BytesN::from_array(&env, &[0; 32]);
+    env.budget().reset_default();
+
+    assert!(client.pools(&0, &10).is_empty());
+
+    let expected_pool = client.deployed_address(&pool_salt);
+    let (pool_contract_id, _) = client.deploy_pool(
+        &pool_salt,
+        &pool_wasm_hash,
+        &Address::generate(&env),
+        &pool_config(&env),
+    );
+
+    assert_eq!(expected_pool, pool_contract_id);
+    assert_eq!(client.pools(&0, &10), vec![&env, pool_contract_id.clone()]);
+    assert!(client.pools(&1, &10).is_empty());
+    assert!(client.reserves(&pool_contract_id, &0, &10).is_empty());
+
+    env.budget().reset_default();
+
+    let s_token_wasm_hash = env.deployer().upload_contract_wasm(s_token::WASM);
+    let debt_token_wasm_hash = env.deployer().upload_contract_wasm(debt_token::WASM);
+    let mut expected_reserves = vec![&env];
+
+    for i in 1..3u8 {
+        let asset = env.register_stellar_asset_contract(Address::generate(&env));
+        let (s_token_id, debt_token_id) = client.deploy_reserve(
+            &BytesN::from_array(&env, &[i; 32]),
+            &pool_contract_id,
+            &asset,
+            &TokenParams {
+                wasm_hash: s_token_wasm_hash.clone(),
+                name: String::from_str(&env, "s-token"),
+                symbol: String::from_str(&env, "STOKEN"),
+            },
+            &TokenParams {
+                wasm_hash: debt_token_wasm_hash.clone(),
+                name: String::from_str(&env, "debt token"),
+                symbol: String::from_str(&env, "DTOKEN"),
+            },
+            &None,
+            &None,
+        );
+
+        expected_reserves.push_back(DeployedReserve {
+            asset,
+            s_token: s_token_id,
+            debt_token: debt_token_id,
+        });
+
+        env.budget().reset_default();
+    }
+
+    assert_eq!(
+        client.reserves(&pool_contract_id, &0, &10),
+        expected_reserves
+    );
+    assert_eq!(
+        client.reserves(&pool_contract_id, &1, &1),
+        vec![&env, expected_reserves.get_unchecked(1)]
+    );
+}
+
+#[test]
+fn initialize_only_by_deploying_account() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let salt = BytesN::from_array(&env, &[7; 32]);
+    let address = env
+        .deployer()
+        .with_address(admin.clone(), salt.clone())
+        .deployed_address();
+    let client = DeployerClient::new(&env, &env.register_contract(Some(&address), Deployer));
+
+    let result = client.try_initialize(&Address::generate(&env), &salt);
+
+    assert_eq!(result, Err(Ok(Error::NotDeployer.into())));
+
+    client.initialize(&admin, &salt);
+
+    let result = client.try_initialize(&admin, &salt);
+
+    assert_eq!(result, Err(Ok(Error::AlreadyInitialized.into())));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
+fn register_only_authorized_pools() {
+    let env = Env::default();
+
+    let client = create_deployer(&env);
+    let pool_wasm_hash = env.deployer().upload_contract_wasm(pool::WASM);
+    env.budget().reset_default();
+
+    client.deploy_pool(
+        &BytesN::from_array(&env, &[0; 32]),
+        &pool_wasm_hash,
+        &Address::generate(&env),
+        &pool_config(&env),
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
+fn deploy_only_authorized_tokens() {
+    let env = Env::default();
+
+    let client = create_deployer(&env);
+    let s_token_wasm_hash = env.deployer().upload_contract_wasm(s_token::WASM);
+    env.budget().reset_default();
+
+    client.deploy_s_token(
+        &BytesN::from_array(&env, &[1; 32]),
+        &s_token_wasm_hash,
+        &String::from_str(&env, "name"),
+        &String::from_str(&env, "symbol"),
+        &Address::generate(&env),
+        &Address::generate(&env),
+    );
+}
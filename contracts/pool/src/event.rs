@@ -1,7 +1,13 @@
 use pool_interface::types::{
-    collateral_params_input::CollateralParamsInput, pool_config::PoolConfig,
+    collateral_params_input::CollateralParamsInput, pause_action::PauseAction,
+    pool_config::PoolConfig, price_feed_config::PriceFeedConfig, price_source::PriceSource,
+    reserve_data::ReserveData, reserve_type::ReserveType, role::Role,
 };
-use soroban_sdk::{symbol_short, Address, Env, Symbol};
+use soroban_sdk::{symbol_short, Address, BytesN, Env, Symbol};
+
+/// Version of the layout of configuration change events. Goes right after the event name
+/// in the topics and is bumped on every change of the topics or data of these events.
+pub(crate) const CONFIG_EVENT_VERSION: u32 = 1;
 
 pub(crate) fn initialized(e: &Env, admin: &Address, pool_config: &PoolConfig) {
     let topics = (
@@ -29,6 +35,78 @@ pub(crate) fn initialized(e: &Env, admin: &Address, pool_config: &PoolConfig) {
     );
 }
 
+pub(crate) fn role_granted(e: &Env, role: Role, who: &Address) {
+    let topics = (Symbol::new(e, "role_granted"), CONFIG_EVENT_VERSION, role);
+    e.events().publish(topics, who.clone());
+}
+
+pub(crate) fn role_revoked(e: &Env, role: Role, who: &Address) {
+    let topics = (Symbol::new(e, "role_revoked"), CONFIG_EVENT_VERSION, role);
+    e.events().publish(topics, who.clone());
+}
+
+pub(crate) fn admin_proposed(e: &Env, admin: &Address, pending_admin: &Address) {
+    let topics = (
+        Symbol::new(e, "admin_proposed"),
+        CONFIG_EVENT_VERSION,
+        admin.clone(),
+    );
+    e.events().publish(topics, pending_admin.clone());
+}
+
+pub(crate) fn admin_proposal_cancelled(e: &Env, admin: &Address, pending_admin: &Address) {
+    let topics = (
+        Symbol::new(e, "admin_proposal_cancelled"),
+        CONFIG_EVENT_VERSION,
+        admin.clone(),
+    );
+    e.events().publish(topics, pending_admin.clone());
+}
+
+pub(crate) fn admin_changed(e: &Env, prev_admin: &Address, admin: &Address) {
+    let topics = (
+        Symbol::new(e, "admin_changed"),
+        CONFIG_EVENT_VERSION,
+        prev_admin.clone(),
+    );
+    e.events().publish(topics, admin.clone());
+}
+
+pub(crate) fn timelock_delay_changed(e: &Env, delay: u64) {
+    let topics = (
+        Symbol::new(e, "timelock_delay_changed"),
+        CONFIG_EVENT_VERSION,
+    );
+    e.events().publish(topics, delay);
+}
+
+pub(crate) fn operation_scheduled(e: &Env, id: u32, eta: u64) {
+    let topics = (
+        Symbol::new(e, "operation_scheduled"),
+        CONFIG_EVENT_VERSION,
+        id,
+    );
+    e.events().publish(topics, eta);
+}
+
+pub(crate) fn operation_executed(e: &Env, id: u32) {
+    let topics = (
+        Symbol::new(e, "operation_executed"),
+        CONFIG_EVENT_VERSION,
+        id,
+    );
+    e.events().publish(topics, ());
+}
+
+pub(crate) fn operation_cancelled(e: &Env, id: u32) {
+    let topics = (
+        Symbol::new(e, "operation_cancelled"),
+        CONFIG_EVENT_VERSION,
+        id,
+    );
+    e.events().publish(topics, ());
+}
+
 pub(crate) fn reserve_used_as_collateral_enabled(e: &Env, who: &Address, asset: &Address) {
     let topics = (Symbol::new(e, "reserve_used_as_coll_enabled"), who.clone());
     e.events().publish(topics, asset.clone());
@@ -59,11 +137,29 @@ pub(crate) fn repay(e: &Env, who: &Address, asset: &Address, amount: i128) {
     e.events().publish(topics, (asset.clone(), amount));
 }
 
-pub(crate) fn collat_config_change(e: &Env, asset: &Address, params: &CollateralParamsInput) {
-    let topics = (Symbol::new(e, "collat_config_change"), asset.clone());
+pub(crate) fn debt_written_off(e: &Env, who: &Address, asset: &Address, amount: i128) {
+    let topics = (Symbol::new(e, "debt_written_off"), who.clone());
+    e.events().publish(topics, (asset.clone(), amount));
+}
+
+pub(crate) fn collat_config_change(
+    e: &Env,
+    asset: &Address,
+    prev_params: &CollateralParamsInput,
+    params: &CollateralParamsInput,
+) {
+    let topics = (
+        Symbol::new(e, "collat_config_change"),
+        CONFIG_EVENT_VERSION,
+        asset.clone(),
+    );
     e.events().publish(
         topics,
         (
+            prev_params.liq_cap,
+            prev_params.pen_order,
+            prev_params.util_cap,
+            prev_params.discount,
             params.liq_cap,
             params.pen_order,
             params.util_cap,
@@ -73,18 +169,222 @@ pub(crate) fn collat_config_change(e: &Env, asset: &Address, params: &Collateral
 }
 
 pub(crate) fn borrowing_enabled(e: &Env, asset: &Address) {
-    let topics = (Symbol::new(e, "borrowing_enabled"), asset.clone());
+    let topics = (
+        Symbol::new(e, "borrowing_enabled"),
+        CONFIG_EVENT_VERSION,
+        asset.clone(),
+    );
     e.events().publish(topics, ());
 }
 
 pub(crate) fn borrowing_disabled(e: &Env, asset: &Address) {
-    let topics = (Symbol::new(e, "borrowing_disabled"), asset.clone());
+    let topics = (
+        Symbol::new(e, "borrowing_disabled"),
+        CONFIG_EVENT_VERSION,
+        asset.clone(),
+    );
+    e.events().publish(topics, ());
+}
+
+pub(crate) fn reserve_status_changed(e: &Env, asset: &Address, prev_active: bool, active: bool) {
+    let topics = (
+        Symbol::new(e, "reserve_status_changed"),
+        CONFIG_EVENT_VERSION,
+        asset.clone(),
+    );
+    e.events().publish(topics, (prev_active, active));
+}
+
+pub(crate) fn reserve_updated(e: &Env, asset: &Address, reserve: &ReserveData, utilization: i128) {
+    let topics = (Symbol::new(e, "reserve_updated"), asset.clone());
+    e.events().publish(
+        topics,
+        (
+            reserve.lender_ar,
+            reserve.borrower_ar,
+            reserve.lender_ir,
+            reserve.borrower_ir,
+            utilization,
+            reserve.last_update_timestamp,
+        ),
+    );
+}
+
+pub(crate) fn reserve_initialized(e: &Env, asset: &Address, reserve_type: &ReserveType, id: u32) {
+    let topics = (
+        Symbol::new(e, "reserve_initialized"),
+        CONFIG_EVENT_VERSION,
+        asset.clone(),
+    );
+    e.events().publish(topics, (reserve_type.clone(), id));
+}
+
+pub(crate) fn price_feeds_changed(
+    e: &Env,
+    asset: &Address,
+    prev_config: &Option<PriceFeedConfig>,
+    config: &PriceFeedConfig,
+) {
+    let topics = (
+        Symbol::new(e, "price_feeds_changed"),
+        CONFIG_EVENT_VERSION,
+        asset.clone(),
+    );
+    e.events()
+        .publish(topics, (prev_config.clone(), config.clone()));
+}
+
+pub(crate) fn twap_window_defaulted(e: &Env, asset: &Address, feed: &Address, twap_window: u64) {
+    let topics = (
+        Symbol::new(e, "twap_window_defaulted"),
+        CONFIG_EVENT_VERSION,
+        asset.clone(),
+    );
+    e.events().publish(topics, (feed.clone(), twap_window));
+}
+
+pub(crate) fn price_fallback(e: &Env, asset: &Address, source: PriceSource, price: i128) {
+    let topics = (
+        Symbol::new(e, "price_fallback"),
+        CONFIG_EVENT_VERSION,
+        asset.clone(),
+    );
+    e.events().publish(topics, (source, price));
+}
+
+pub(crate) fn price_breaker_tripped(e: &Env, asset: &Address, reference_price: i128, price: i128) {
+    let topics = (
+        Symbol::new(e, "price_breaker_tripped"),
+        CONFIG_EVENT_VERSION,
+        asset.clone(),
+    );
+    e.events().publish(topics, (reference_price, price));
+}
+
+pub(crate) fn price_breaker_reset(e: &Env, asset: &Address) {
+    let topics = (
+        Symbol::new(e, "price_breaker_reset"),
+        CONFIG_EVENT_VERSION,
+        asset.clone(),
+    );
+    e.events().publish(topics, ());
+}
+
+pub(crate) fn pause_changed(e: &Env, prev_paused: bool, paused: bool) {
+    let topics = (Symbol::new(e, "pause_changed"), CONFIG_EVENT_VERSION);
+    e.events().publish(topics, (prev_paused, paused));
+}
+
+pub(crate) fn pool_configuration_changed(
+    e: &Env,
+    prev_config: &Option<PoolConfig>,
+    config: &PoolConfig,
+) {
+    let topics = (
+        Symbol::new(e, "pool_configuration_changed"),
+        CONFIG_EVENT_VERSION,
+    );
+    e.events()
+        .publish(topics, (prev_config.clone(), config.clone()));
+}
+
+pub(crate) fn protocol_fee_claimed(e: &Env, asset: &Address, recipient: &Address, amount: i128) {
+    let topics = (
+        Symbol::new(e, "protocol_fee_claimed"),
+        CONFIG_EVENT_VERSION,
+        asset.clone(),
+    );
+    e.events().publish(topics, (recipient.clone(), amount));
+}
+
+pub(crate) fn upgraded(e: &Env, prev_version: u32, new_wasm_hash: &BytesN<32>) {
+    let topics = (Symbol::new(e, "upgraded"), CONFIG_EVENT_VERSION);
+    e.events()
+        .publish(topics, (prev_version, new_wasm_hash.clone()));
+}
+
+pub(crate) fn reserve_freeze_changed(e: &Env, asset: &Address, frozen: bool) {
+    let topics = (
+        Symbol::new(e, "reserve_freeze_changed"),
+        CONFIG_EVENT_VERSION,
+        asset.clone(),
+    );
+    e.events().publish(topics, frozen);
+}
+
+pub(crate) fn reserve_delisted(e: &Env, asset: &Address) {
+    let topics = (
+        Symbol::new(e, "reserve_delisted"),
+        CONFIG_EVENT_VERSION,
+        asset.clone(),
+    );
     e.events().publish(topics, ());
 }
 
-pub(crate) fn reserve_status_changed(e: &Env, asset: &Address, activated: bool) {
-    let topics = (asset.clone(),);
-    e.events().publish(topics, activated);
+pub(crate) fn storage_migrated(e: &Env, from_version: u32, to_version: u32) {
+    let topics = (Symbol::new(e, "storage_migrated"), CONFIG_EVENT_VERSION);
+    e.events().publish(topics, (from_version, to_version));
+}
+
+pub(crate) fn user_configs_migrated(e: &Env, migrated: u32) {
+    let topics = (
+        Symbol::new(e, "user_configs_migrated"),
+        CONFIG_EVENT_VERSION,
+    );
+    e.events().publish(topics, migrated);
+}
+
+pub(crate) fn token_upgraded(
+    e: &Env,
+    asset: &Address,
+    token: &Address,
+    prev_version: u32,
+    version: u32,
+) {
+    let topics = (
+        Symbol::new(e, "token_upgraded"),
+        CONFIG_EVENT_VERSION,
+        asset.clone(),
+        token.clone(),
+    );
+    e.events().publish(topics, (prev_version, version));
+}
+
+pub(crate) fn wind_down_started(e: &Env, rate_step: u32, step_secs: u64) {
+    let topics = (Symbol::new(e, "wind_down_started"), CONFIG_EVENT_VERSION);
+    e.events().publish(topics, (rate_step, step_secs));
+}
+
+pub(crate) fn settlement_started(e: &Env) {
+    let topics = (Symbol::new(e, "settlement_started"), CONFIG_EVENT_VERSION);
+    e.events().publish(topics, ());
+}
+
+pub(crate) fn accrual_suspension_changed(e: &Env, enabled: bool) {
+    let topics = (
+        Symbol::new(e, "accrual_suspension_changed"),
+        CONFIG_EVENT_VERSION,
+    );
+    e.events().publish(topics, enabled);
+}
+
+pub(crate) fn action_pause_changed(e: &Env, action: PauseAction, paused: bool) {
+    let topics = (
+        Symbol::new(e, "action_pause_changed"),
+        CONFIG_EVENT_VERSION,
+        action,
+    );
+    e.events().publish(topics, paused);
+}
+
+pub(crate) fn reserve_pause_changed(e: &Env, asset: &Address, action: PauseAction, paused: bool) {
+    let topics = (
+        Symbol::new(e, "reserve_pause_changed"),
+        CONFIG_EVENT_VERSION,
+        asset.clone(),
+        action,
+    );
+    e.events().publish(topics, paused);
 }
 
 pub(crate) fn liquidation(e: &Env, who: &Address, covered_debt: i128, liquidated_collateral: i128) {
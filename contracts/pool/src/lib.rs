@@ -2,23 +2,38 @@
 #![no_std]
 
 use methods::{
-    account_position::account_position, borrow::borrow, claim_protocol_fee::claim_protocol_fee,
-    collat_coeff::collat_coeff, configure_as_collateral::configure_as_collateral,
-    debt_coeff::debt_coeff, deposit::deposit,
-    enable_borrowing_on_reserve::enable_borrowing_on_reserve, finalize_transfer::finalize_transfer,
-    flash_loan::flash_loan, init_reserve::init_reserve, initialize::initialize,
-    liquidate::liquidate, repay::repay, set_as_collateral::set_as_collateral, set_pause::set_pause,
+    accept_admin::accept_admin, account_position::account_position, borrow::borrow,
+    cancel_admin_proposal::cancel_admin_proposal, cancel_operation::cancel_operation,
+    claim_protocol_fee::claim_protocol_fee, collat_coeff::collat_coeff,
+    configure_as_collateral::configure_as_collateral, debt_coeff::debt_coeff,
+    delist_reserve::delist_reserve, deposit::deposit,
+    enable_borrowing_on_reserve::enable_borrowing_on_reserve, execute_operation::execute_operation,
+    finalize_transfer::finalize_transfer, flash_loan::flash_loan, grant_role::grant_role,
+    init_reserve::init_reserve, initialize::initialize, liquidate::liquidate, migrate::migrate,
+    migrate_user_configs::migrate_user_configs, pause_matrix::pause_matrix,
+    propose_admin::propose_admin, redeem::redeem, repay::repay,
+    reset_price_breaker::reset_price_breaker, revoke_role::revoke_role,
+    schedule_operation::schedule_operation, set_accrual_suspension::set_accrual_suspension,
+    set_action_pause::set_action_pause, set_as_collateral::set_as_collateral, set_pause::set_pause,
     set_pool_configuration::set_pool_configuration, set_price_feeds::set_price_feeds,
-    set_reserve_status::set_reserve_status, twap_median_price::twap_median_price, upgrade::upgrade,
-    upgrade_token::upgrade_token, withdraw::withdraw,
+    set_reserve_freeze::set_reserve_freeze, set_reserve_pause::set_reserve_pause,
+    set_reserve_status::set_reserve_status, set_timelock_delay::set_timelock_delay,
+    start_settlement::start_settlement, start_wind_down::start_wind_down,
+    twap_median_price::twap_median_price, upgrade::upgrade, upgrade_token::upgrade_token,
+    upgrade_tokens::upgrade_tokens, withdraw::withdraw, write_off_debt::write_off_debt,
 };
 use pool_interface::types::{
     account_position::AccountPosition, collateral_params_input::CollateralParamsInput,
-    error::Error, flash_loan_asset::FlashLoanAsset, pause_info::PauseInfo, pool_config::PoolConfig,
-    price_feed_config::PriceFeedConfig, price_feed_config_input::PriceFeedConfigInput,
-    reserve_data::ReserveData, reserve_type::ReserveType, user_config::UserConfiguration,
+    error::Error, flash_loan_asset::FlashLoanAsset, migration_input::MigrationInput,
+    pause_action::PauseAction, pause_info::PauseInfo, pause_matrix::PauseMatrix,
+    pool_config::PoolConfig, price_breaker::PriceBreaker, price_feed_config::PriceFeedConfig,
+    price_feed_config_input::PriceFeedConfigInput, queued_operation::QueuedOperation,
+    reserve_data::ReserveData, reserve_type::ReserveType, role::Role,
+    timelock_operation::TimelockOperation, user_config::UserConfiguration,
+    wind_down_info::WindDownInfo,
 };
 use pool_interface::LendingPoolTrait;
+use price_feed_interface::types::price_data::PriceData;
 use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, Vec};
 
 use crate::storage::*;
@@ -40,7 +55,7 @@ impl LendingPoolTrait for LendingPool {
     }
 
     fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
-        upgrade(&env, &new_wasm_hash)
+        upgrade(&env, &new_wasm_hash, true)
     }
 
     fn upgrade_token(
@@ -49,21 +64,109 @@ impl LendingPoolTrait for LendingPool {
         s_token: bool,
         new_wasm_hash: BytesN<32>,
     ) -> Result<(), Error> {
-        upgrade_token(&env, &asset, &new_wasm_hash, s_token)
+        upgrade_token(&env, &asset, &new_wasm_hash, s_token, true)
+    }
+
+    fn upgrade_tokens(
+        env: Env,
+        s_token_wasm_hash: BytesN<32>,
+        debt_token_wasm_hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        upgrade_tokens(&env, &s_token_wasm_hash, &debt_token_wasm_hash, true)
     }
 
     fn version() -> u32 {
-        1
+        pool_interface::VERSION
+    }
+
+    fn migrate(env: Env, from_version: u32, input: MigrationInput) -> Result<(), Error> {
+        migrate(&env, from_version, &input)
+    }
+
+    fn migrate_user_configs(env: Env, users: Vec<Address>) -> Result<(), Error> {
+        migrate_user_configs(&env, &users)
+    }
+
+    fn storage_version(env: Env) -> u32 {
+        read_storage_version(&env)
+    }
+
+    fn grant_role(env: Env, role: Role, who: Address) -> Result<(), Error> {
+        grant_role(&env, role, &who)
+    }
+
+    fn revoke_role(env: Env, role: Role) -> Result<(), Error> {
+        revoke_role(&env, role)
+    }
+
+    fn role(env: Env, role: Role) -> Result<Address, Error> {
+        read_role_holder(&env, role)
+    }
+
+    fn propose_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        propose_admin(&env, &new_admin)
+    }
+
+    fn accept_admin(env: Env) -> Result<(), Error> {
+        accept_admin(&env)
+    }
+
+    fn cancel_admin_proposal(env: Env) -> Result<(), Error> {
+        cancel_admin_proposal(&env)
+    }
+
+    fn pending_admin(env: Env) -> Option<Address> {
+        read_pending_admin(&env)
+    }
+
+    fn set_timelock_delay(env: Env, delay: u64) -> Result<(), Error> {
+        set_timelock_delay(&env, delay, true)
+    }
+
+    fn timelock_delay(env: Env) -> u64 {
+        read_timelock_delay(&env)
+    }
+
+    fn schedule_operation(env: Env, operation: TimelockOperation, eta: u64) -> Result<u32, Error> {
+        schedule_operation(&env, &operation, eta)
+    }
+
+    fn execute_operation(env: Env, id: u32) -> Result<(), Error> {
+        execute_operation(&env, id)
+    }
+
+    fn cancel_operation(env: Env, id: u32) -> Result<(), Error> {
+        cancel_operation(&env, id)
+    }
+
+    fn queued_operations(env: Env) -> Vec<QueuedOperation> {
+        let mut operations = Vec::new(&env);
+
+        for id in read_timelock_queue(&env) {
+            if let Some(operation) = read_queued_operation(&env, id) {
+                operations.push_back(operation);
+            }
+        }
+
+        operations
     }
 
     fn init_reserve(env: Env, asset: Address, reserve_type: ReserveType) -> Result<(), Error> {
         init_reserve(&env, &asset, reserve_type)
     }
 
+    fn delist_reserve(env: Env, asset: Address) -> Result<(), Error> {
+        delist_reserve(&env, &asset)
+    }
+
     fn set_reserve_status(env: Env, asset: Address, is_active: bool) -> Result<(), Error> {
         set_reserve_status(&env, &asset, is_active)
     }
 
+    fn set_reserve_freeze(env: Env, asset: Address, is_frozen: bool) -> Result<(), Error> {
+        set_reserve_freeze(&env, &asset, is_frozen)
+    }
+
     fn enable_borrowing_on_reserve(env: Env, asset: Address, enabled: bool) -> Result<(), Error> {
         enable_borrowing_on_reserve(&env, &asset, enabled)
     }
@@ -73,7 +176,7 @@ impl LendingPoolTrait for LendingPool {
         asset: Address,
         params: CollateralParamsInput,
     ) -> Result<(), Error> {
-        configure_as_collateral(&env, &asset, &params)
+        configure_as_collateral(&env, &asset, &params, true)
     }
 
     fn get_reserve(env: Env, asset: Address) -> Option<ReserveData> {
@@ -97,13 +200,25 @@ impl LendingPoolTrait for LendingPool {
     }
 
     fn set_price_feeds(env: Env, inputs: Vec<PriceFeedConfigInput>) -> Result<(), Error> {
-        set_price_feeds(&env, &inputs)
+        set_price_feeds(&env, &inputs, true)
     }
 
     fn price_feeds(env: Env, asset: Address) -> Option<PriceFeedConfig> {
         read_price_feeds(&env, &asset).ok()
     }
 
+    fn last_good_price(env: Env, asset: Address) -> Option<PriceData> {
+        read_last_good_price(&env, &asset)
+    }
+
+    fn reset_price_breaker(env: Env, asset: Address) -> Result<(), Error> {
+        reset_price_breaker(&env, &asset)
+    }
+
+    fn price_breaker(env: Env, asset: Address) -> Option<PriceBreaker> {
+        read_price_breaker(&env, &asset)
+    }
+
     fn deposit(env: Env, who: Address, asset: Address, amount: i128) -> Result<(), Error> {
         deposit(&env, &who, &asset, amount)
     }
@@ -149,14 +264,61 @@ impl LendingPoolTrait for LendingPool {
         borrow(&env, &who, &asset, amount)
     }
 
-    fn set_pause(env: Env, value: bool) -> Result<(), Error> {
-        set_pause(&env, value)
+    fn set_pause(env: Env, who: Address, value: bool) -> Result<(), Error> {
+        set_pause(&env, &who, value)
     }
 
     fn pause_info(env: Env) -> PauseInfo {
         read_pause_info(&env)
     }
 
+    fn start_wind_down(env: Env, rate_step: u32, step_secs: u64) -> Result<(), Error> {
+        start_wind_down(&env, rate_step, step_secs, true)
+    }
+
+    fn start_settlement(env: Env) -> Result<(), Error> {
+        start_settlement(&env)
+    }
+
+    fn wind_down_info(env: Env) -> WindDownInfo {
+        read_wind_down_info(&env)
+    }
+
+    fn redeem(env: Env, who: Address, asset: Address, to: Address) -> Result<(), Error> {
+        redeem(&env, &who, &asset, &to)
+    }
+
+    fn write_off_debt(env: Env, who: Address, asset: Address) -> Result<(), Error> {
+        write_off_debt(&env, &who, &asset)
+    }
+
+    fn set_accrual_suspension(env: Env, enabled: bool) -> Result<(), Error> {
+        set_accrual_suspension(&env, enabled)
+    }
+
+    fn set_action_pause(
+        env: Env,
+        who: Address,
+        action: PauseAction,
+        value: bool,
+    ) -> Result<(), Error> {
+        set_action_pause(&env, &who, action, value)
+    }
+
+    fn set_reserve_pause(
+        env: Env,
+        who: Address,
+        asset: Address,
+        action: PauseAction,
+        value: bool,
+    ) -> Result<(), Error> {
+        set_reserve_pause(&env, &who, &asset, action, value)
+    }
+
+    fn pause_matrix(env: Env) -> PauseMatrix {
+        pause_matrix(&env)
+    }
+
     fn account_position(env: Env, who: Address) -> Result<AccountPosition, Error> {
         account_position(&env, &who, &read_pool_config(&env)?)
     }
@@ -0,0 +1,19 @@
+use pool_interface::types::error::Error;
+use soroban_sdk::Env;
+
+use crate::event;
+use crate::storage::{read_admin, read_pending_admin, remove_pending_admin, write_admin};
+
+pub fn accept_admin(env: &Env) -> Result<(), Error> {
+    let pending_admin = read_pending_admin(env).ok_or(Error::Uninitialized)?;
+    pending_admin.require_auth();
+
+    let prev_admin = read_admin(env)?;
+
+    write_admin(env, &pending_admin);
+    remove_pending_admin(env);
+
+    event::admin_changed(env, &prev_admin, &pending_admin);
+
+    Ok(())
+}
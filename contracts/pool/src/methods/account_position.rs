@@ -64,7 +64,10 @@ pub fn calc_account_data(
             continue;
         }
 
-        let asset = reserves.get_unchecked(i.into());
+        let asset = match reserves.get_unchecked(i.into()) {
+            Some(asset) => asset,
+            None => continue,
+        };
         let reserve = read_reserve(env, &asset)?;
 
         assert_with_error!(
@@ -191,7 +194,7 @@ fn calculate(
                 )
             };
 
-        let balance_in_base = price_provider.convert_to_base(&asset, balance)?;
+        let balance_in_base = price_provider.convert_collat_to_base(&asset, balance)?;
 
         let discounted_balance_in_base = discount
             .mul_int(balance_in_base)
@@ -232,7 +235,8 @@ fn calculate(
                 .mul_int(who_debt)
                 .ok_or(Error::CalcAccountDataMathError)?;
 
-            let debt_balance_in_base = price_provider.convert_to_base(&asset, compounded_debt)?;
+            let debt_balance_in_base =
+                price_provider.convert_debt_to_base(&asset, compounded_debt)?;
 
             *total_debt_in_base = total_debt_in_base
                 .checked_add(debt_balance_in_base)
@@ -1,6 +1,7 @@
 use debt_token_interface::DebtTokenClient;
 use pool_interface::types::asset_balance::AssetBalance;
 use pool_interface::types::error::Error;
+use pool_interface::types::pause_action::PauseAction;
 use pool_interface::types::pool_config::PoolConfig;
 use pool_interface::types::reserve_data::ReserveData;
 use s_token_interface::STokenClient;
@@ -25,21 +26,28 @@ use super::utils::validation::require_min_position_amounts;
 use super::utils::validation::require_not_in_grace_period;
 use super::utils::validation::{
     require_active_reserve, require_borrowing_enabled, require_gte_initial_health,
-    require_not_in_collateral_asset, require_not_paused, require_positive_amount,
+    require_migrated, require_not_frozen_reserve, require_not_in_collateral_asset,
+    require_not_paused, require_not_wind_down, require_positive_amount,
+    require_price_breakers_not_tripped, require_reserve_action_not_paused,
     require_util_cap_not_exceeded,
 };
 
 pub fn borrow(env: &Env, who: &Address, asset: &Address, amount: i128) -> Result<(), Error> {
     who.require_auth();
 
+    require_migrated(env);
+
     let pause_info = read_pause_info(env);
     require_not_paused(env, &pause_info);
+    require_reserve_action_not_paused(env, asset, PauseAction::Borrow);
     require_not_in_grace_period(env, &pause_info);
 
+    require_not_wind_down(env);
     require_positive_amount(env, amount);
 
     let reserve = read_reserve(env, asset)?;
     require_active_reserve(env, &reserve);
+    require_not_frozen_reserve(env, &reserve);
     require_borrowing_enabled(env, &reserve);
 
     let (s_token_address, debt_token_address) = reserve.get_fungible()?;
@@ -121,6 +129,8 @@ pub fn do_borrow(
 
     user_configurator.borrow(reserve.get_id(), who_debt == 0)?;
 
+    require_price_breakers_not_tripped(env, user_configurator.user_config()?);
+
     let account_data = calc_account_data(
         env,
         who,
@@ -143,7 +153,9 @@ pub fn do_borrow(
         },
         pool_config,
         user_configurator.user_config()?,
-        &mut PriceProvider::new(env, pool_config)?,
+        &mut PriceProvider::new(env, pool_config)?
+            .conservative()
+            .record_prices(),
         false,
     )?;
 
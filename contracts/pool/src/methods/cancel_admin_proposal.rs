@@ -0,0 +1,20 @@
+use pool_interface::types::error::Error;
+use pool_interface::types::role::Role;
+use soroban_sdk::Env;
+
+use crate::event;
+use crate::storage::{read_admin, read_pending_admin, remove_pending_admin};
+
+use super::utils::validation::require_role;
+
+pub fn cancel_admin_proposal(env: &Env) -> Result<(), Error> {
+    require_role(env, Role::PoolAdmin)?;
+
+    let pending_admin = read_pending_admin(env).ok_or(Error::Uninitialized)?;
+
+    remove_pending_admin(env);
+
+    event::admin_proposal_cancelled(env, &read_admin(env)?, &pending_admin);
+
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use pool_interface::types::error::Error;
+use soroban_sdk::Env;
+
+use crate::event;
+use crate::storage::{read_queued_operation, remove_queued_operation};
+
+use super::schedule_operation::operation_role;
+use super::utils::validation::require_role;
+
+pub fn cancel_operation(env: &Env, id: u32) -> Result<(), Error> {
+    let queued = read_queued_operation(env, id).ok_or(Error::Uninitialized)?;
+
+    require_role(env, operation_role(&queued.operation))?;
+
+    remove_queued_operation(env, id);
+
+    event::operation_cancelled(env, id);
+
+    Ok(())
+}
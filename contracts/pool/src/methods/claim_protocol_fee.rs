@@ -1,13 +1,14 @@
-use pool_interface::types::{error::Error, reserve_type::ReserveType};
+use pool_interface::types::{error::Error, reserve_type::ReserveType, role::Role};
 use s_token_interface::STokenClient;
 use soroban_sdk::{token, Address, Env};
 
+use crate::event;
 use crate::{read_protocol_fee_vault, read_reserve, write_protocol_fee_vault};
 
-use super::utils::validation::require_admin;
+use super::utils::validation::require_role;
 
 pub fn claim_protocol_fee(env: &Env, asset: &Address, recipient: &Address) -> Result<(), Error> {
-    require_admin(env)?;
+    require_role(env, Role::Treasury)?;
 
     let reserve_data = read_reserve(env, asset)?;
     let amount = &read_protocol_fee_vault(env, asset);
@@ -25,5 +26,7 @@ pub fn claim_protocol_fee(env: &Env, asset: &Address, recipient: &Address) -> Re
 
     write_protocol_fee_vault(env, asset, 0);
 
+    event::protocol_fee_claimed(env, asset, recipient, *amount);
+
     Ok(())
 }
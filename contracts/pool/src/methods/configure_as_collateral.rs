@@ -1,28 +1,42 @@
 use pool_interface::types::collateral_params_input::CollateralParamsInput;
 use pool_interface::types::error::Error;
+use pool_interface::types::role::Role;
 use soroban_sdk::{Address, Env};
 
 use crate::event;
-use crate::storage::{read_reserve, write_reserve};
+use crate::storage::read_reserve;
 
+use super::utils::update_reserve::{update_reserve, utilization};
 use super::utils::validation::{
-    require_admin, require_unique_liquidation_order, require_valid_collateral_params,
+    require_not_timelocked, require_role, require_unique_liquidation_order,
+    require_valid_collateral_params,
 };
 
 pub fn configure_as_collateral(
     env: &Env,
     asset: &Address,
     params: &CollateralParamsInput,
+    check_admin: bool,
 ) -> Result<(), Error> {
-    require_admin(env)?;
+    if check_admin {
+        require_role(env, Role::RiskAdmin)?;
+        require_not_timelocked(env);
+    }
+
     require_valid_collateral_params(env, params);
     require_unique_liquidation_order(env, asset, params.pen_order)?;
 
     let mut reserve = read_reserve(env, asset)?;
+    let prev_params = CollateralParamsInput {
+        liq_cap: reserve.configuration.liquidity_cap,
+        pen_order: reserve.configuration.pen_order,
+        util_cap: reserve.configuration.util_cap,
+        discount: reserve.configuration.discount,
+    };
     reserve.update_collateral_config(params);
 
-    write_reserve(env, asset, &reserve);
-    event::collat_config_change(env, asset, params);
+    update_reserve(env, asset, &reserve, utilization(env, &reserve)?);
+    event::collat_config_change(env, asset, &prev_params, params);
 
     Ok(())
 }
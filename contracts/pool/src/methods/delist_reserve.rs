@@ -0,0 +1,57 @@
+use pool_interface::types::error::Error;
+use pool_interface::types::reserve_type::ReserveType;
+use pool_interface::types::role::Role;
+use soroban_sdk::{assert_with_error, Address, Env};
+
+use crate::event;
+use crate::storage::{
+    read_protocol_fee_vault, read_reserve, read_reserves, read_token_total_supply,
+    remove_price_feeds, remove_protocol_fee_vault, remove_reserve, remove_token_balance,
+    remove_token_total_supply, write_reserve_paused_actions, write_reserves,
+};
+
+use super::utils::validation::require_role;
+
+pub fn delist_reserve(env: &Env, asset: &Address) -> Result<(), Error> {
+    require_role(env, Role::PoolAdmin)?;
+
+    let reserve = read_reserve(env, asset)?;
+
+    // Balances are checked by the pool accounting, tokens sent to the pool directly are ignored
+    let is_empty = match &reserve.reserve_type {
+        ReserveType::Fungible(s_token_address, debt_token_address) => {
+            read_token_total_supply(env, s_token_address) == 0
+                && read_token_total_supply(env, debt_token_address) == 0
+        }
+        ReserveType::RWA => read_token_total_supply(env, asset) == 0,
+    };
+
+    assert_with_error!(env, is_empty, Error::ReserveNotEmpty);
+    assert_with_error!(
+        env,
+        read_protocol_fee_vault(env, asset) == 0,
+        Error::ReserveNotEmpty
+    );
+
+    let mut reserves = read_reserves(env);
+    reserves.set(reserve.get_id().into(), None);
+
+    write_reserves(env, &reserves);
+    remove_reserve(env, asset);
+    remove_price_feeds(env, asset);
+    remove_protocol_fee_vault(env, asset);
+    write_reserve_paused_actions(env, asset, 0);
+
+    match &reserve.reserve_type {
+        ReserveType::Fungible(s_token_address, debt_token_address) => {
+            remove_token_total_supply(env, s_token_address);
+            remove_token_total_supply(env, debt_token_address);
+            remove_token_balance(env, asset, s_token_address);
+        }
+        ReserveType::RWA => remove_token_total_supply(env, asset),
+    }
+
+    event::reserve_delisted(env, asset);
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use pool_interface::types::error::Error;
+use pool_interface::types::pause_action::PauseAction;
 use pool_interface::types::pool_config::PoolConfig;
 use pool_interface::types::reserve_data::ReserveData;
 use pool_interface::types::reserve_type::ReserveType;
@@ -10,28 +11,34 @@ use crate::event;
 use crate::read_pause_info;
 use crate::read_pool_config;
 use crate::storage::{
-    read_reserve, read_token_balance, read_token_total_supply, write_token_balance,
-    write_token_total_supply,
+    add_token_total_supply, read_reserve, read_token_balance, read_token_total_supply,
+    write_token_balance, write_token_total_supply,
 };
 use crate::types::user_configurator::UserConfigurator;
 
 use super::utils::get_collat_coeff::get_lp_amount;
 use super::utils::recalculate_reserve_data::recalculate_reserve_data;
 use super::utils::validation::{
-    require_active_reserve, require_liquidity_cap_not_exceeded, require_not_paused,
-    require_positive_amount, require_zero_debt,
+    require_active_reserve, require_liquidity_cap_not_exceeded, require_migrated,
+    require_not_frozen_reserve, require_not_paused, require_not_wind_down, require_positive_amount,
+    require_reserve_action_not_paused, require_zero_debt,
 };
 
 pub fn deposit(env: &Env, who: &Address, asset: &Address, amount: i128) -> Result<(), Error> {
     who.require_auth();
 
+    require_migrated(env);
+
     let pause_info = read_pause_info(env);
     require_not_paused(env, &pause_info);
+    require_reserve_action_not_paused(env, asset, PauseAction::Deposit);
 
+    require_not_wind_down(env);
     require_positive_amount(env, amount);
 
     let reserve = read_reserve(env, asset)?;
     require_active_reserve(env, &reserve);
+    require_not_frozen_reserve(env, &reserve);
 
     let pool_config = read_pool_config(env)?;
     let mut user_configurator =
@@ -136,6 +143,7 @@ fn do_deposit_rwa(env: &Env, who: &Address, asset: &Address, amount: i128) -> Re
         .checked_add(amount)
         .ok_or(Error::MathOverflowError)?;
     write_token_balance(env, asset, who, balance_after)?;
+    add_token_total_supply(env, asset, amount)?;
 
     Ok(balance_before == 0)
 }
@@ -1,13 +1,15 @@
 use pool_interface::types::error::Error;
+use pool_interface::types::role::Role;
 use soroban_sdk::{Address, Env};
 
 use crate::event;
-use crate::storage::{read_reserve, write_reserve};
+use crate::storage::read_reserve;
 
-use super::utils::validation::{require_admin, require_fungible_reserve};
+use super::utils::update_reserve::{update_reserve, utilization};
+use super::utils::validation::{require_fungible_reserve, require_role};
 
 pub fn enable_borrowing_on_reserve(env: &Env, asset: &Address, enabled: bool) -> Result<(), Error> {
-    require_admin(env)?;
+    require_role(env, Role::RiskAdmin)?;
 
     let mut reserve = read_reserve(env, asset)?;
 
@@ -16,7 +18,7 @@ pub fn enable_borrowing_on_reserve(env: &Env, asset: &Address, enabled: bool) ->
     }
 
     reserve.configuration.borrowing_enabled = enabled;
-    write_reserve(env, asset, &reserve);
+    update_reserve(env, asset, &reserve, utilization(env, &reserve)?);
 
     if enabled {
         event::borrowing_enabled(env, asset);
@@ -0,0 +1,76 @@
+use common::ONE_DAY;
+use pool_interface::types::error::Error;
+use pool_interface::types::timelock_operation::TimelockOperation;
+use soroban_sdk::{assert_with_error, Env};
+
+use crate::event;
+use crate::storage::{read_queued_operation, read_timelock_queue, remove_queued_operation};
+
+use super::configure_as_collateral::configure_as_collateral;
+use super::set_pool_configuration::set_pool_configuration;
+use super::set_price_feeds::set_price_feeds;
+use super::set_timelock_delay::set_timelock_delay;
+use super::start_wind_down::start_wind_down;
+use super::upgrade::upgrade;
+use super::upgrade_token::upgrade_token;
+use super::upgrade_tokens::upgrade_tokens;
+
+/// Time after the eta during which the operation can be executed, stale operations have to be
+/// scheduled again
+pub const TIMELOCK_GRACE_PERIOD: u64 = 14 * ONE_DAY;
+
+pub fn execute_operation(env: &Env, id: u32) -> Result<(), Error> {
+    let queued = read_queued_operation(env, id).ok_or(Error::Uninitialized)?;
+    let now = env.ledger().timestamp();
+
+    assert_with_error!(env, now >= queued.eta, Error::OperationNotReady);
+    assert_with_error!(
+        env,
+        now <= queued.eta.saturating_add(TIMELOCK_GRACE_PERIOD),
+        Error::OperationExpired
+    );
+
+    remove_queued_operation(env, id);
+    remove_expired_operations(env);
+
+    match queued.operation {
+        TimelockOperation::SetPoolConfiguration(config) => {
+            set_pool_configuration(env, &config, false)
+        }
+        TimelockOperation::ConfigureAsCollateral(asset, params) => {
+            configure_as_collateral(env, &asset, &params, false)
+        }
+        TimelockOperation::SetPriceFeeds(inputs) => set_price_feeds(env, &inputs, false),
+        TimelockOperation::Upgrade(new_wasm_hash) => upgrade(env, &new_wasm_hash, false),
+        TimelockOperation::UpgradeToken(asset, s_token, new_wasm_hash) => {
+            upgrade_token(env, &asset, &new_wasm_hash, s_token, false)
+        }
+        TimelockOperation::UpgradeTokens(s_token_wasm_hash, debt_token_wasm_hash) => {
+            upgrade_tokens(env, &s_token_wasm_hash, &debt_token_wasm_hash, false)
+        }
+        TimelockOperation::SetTimelockDelay(delay) => set_timelock_delay(env, delay, false),
+        TimelockOperation::StartWindDown(rate_step, step_secs) => {
+            start_wind_down(env, rate_step, step_secs, false)
+        }
+    }?;
+
+    event::operation_executed(env, id);
+
+    Ok(())
+}
+
+/// Removes the operations past the grace period, which can't be executed anymore,
+/// so that they don't stay in the queue loaded with the instance storage
+pub(crate) fn remove_expired_operations(env: &Env) {
+    let now = env.ledger().timestamp();
+
+    for id in read_timelock_queue(env) {
+        let is_expired = read_queued_operation(env, id).map_or(true, |queued| {
+            now > queued.eta.saturating_add(TIMELOCK_GRACE_PERIOD)
+        });
+
+        if is_expired {
+            remove_queued_operation(env, id);
+        }
+    }
+}
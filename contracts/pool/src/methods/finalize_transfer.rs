@@ -1,5 +1,6 @@
 use pool_interface::types::asset_balance::AssetBalance;
 use pool_interface::types::error::Error;
+use pool_interface::types::pause_action::PauseAction;
 use soroban_sdk::{Address, Env};
 
 use crate::storage::{read_reserve, read_token_total_supply, write_token_balance};
@@ -10,8 +11,9 @@ use crate::{read_pause_info, read_pool_config};
 
 use super::account_position::calc_account_data;
 use super::utils::validation::{
-    require_active_reserve, require_gte_initial_health, require_min_position_amounts,
-    require_not_in_grace_period, require_not_paused, require_zero_debt,
+    require_active_reserve, require_gte_initial_health, require_migrated,
+    require_min_position_amounts, require_not_in_grace_period, require_not_paused,
+    require_price_breakers_not_tripped, require_reserve_action_not_paused, require_zero_debt,
 };
 
 #[allow(clippy::too_many_arguments)]
@@ -25,8 +27,11 @@ pub fn finalize_transfer(
     balance_to_before: i128,
     s_token_supply: i128,
 ) -> Result<(), Error> {
+    require_migrated(env);
+
     let pause_info = read_pause_info(env);
     require_not_paused(env, &pause_info);
+    require_reserve_action_not_paused(env, asset, PauseAction::Transfer);
     require_not_in_grace_period(env, &pause_info);
 
     let reserve: pool_interface::types::reserve_data::ReserveData = read_reserve(env, asset)?;
@@ -55,6 +60,8 @@ pub fn finalize_transfer(
     if is_borrowing_any && is_using_as_collateral {
         from_configurator.withdraw(reserve_id, asset, balance_from_after == 0)?;
 
+        require_price_breakers_not_tripped(env, from_configurator.user_config()?);
+
         let from_account_data = calc_account_data(
             env,
             from,
@@ -77,7 +84,9 @@ pub fn finalize_transfer(
             },
             &pool_config,
             from_configurator.user_config()?,
-            &mut PriceProvider::new(env, &pool_config)?,
+            &mut PriceProvider::new(env, &pool_config)?
+                .conservative()
+                .record_prices(),
             false,
         )?;
 
@@ -93,7 +102,9 @@ pub fn finalize_transfer(
         write_token_balance(env, s_token_address, from, balance_from_after)?;
         write_token_balance(env, s_token_address, to, balance_to_after)?;
 
-        let is_to_deposit = balance_to_before == 0 && amount != 0;
+        // Receiving s-tokens of a frozen reserve doesn't enable them as collateral
+        let is_to_deposit =
+            balance_to_before == 0 && amount != 0 && !reserve.configuration.is_frozen;
 
         from_configurator
             .withdraw(reserve_id, asset, balance_from_after == 0)?
@@ -2,6 +2,7 @@ use common::FixedI128;
 use flash_loan_receiver_interface::{FlashLoanReceiverClient, LoanAsset as ReceiverAsset};
 use pool_interface::types::error::Error;
 use pool_interface::types::flash_loan_asset::FlashLoanAsset;
+use pool_interface::types::pause_action::PauseAction;
 use s_token_interface::STokenClient;
 use soroban_sdk::{assert_with_error, token, vec, Address, Bytes, Env, Vec};
 
@@ -12,7 +13,9 @@ use crate::{add_protocol_fee_vault, event, read_pause_info, read_pool_config};
 use super::borrow::do_borrow;
 use super::utils::recalculate_reserve_data::recalculate_reserve_data;
 use super::utils::validation::{
-    require_active_reserve, require_borrowing_enabled, require_not_paused, require_positive_amount,
+    require_active_reserve, require_borrowing_enabled, require_migrated,
+    require_not_frozen_reserve, require_not_paused, require_not_wind_down, require_positive_amount,
+    require_reserve_action_not_paused,
 };
 
 pub fn flash_loan(
@@ -23,9 +26,13 @@ pub fn flash_loan(
     params: &Bytes,
 ) -> Result<(), Error> {
     who.require_auth();
+    require_migrated(env);
+
     let pause_info = read_pause_info(env);
     require_not_paused(env, &pause_info);
 
+    require_not_wind_down(env);
+
     let pool_config = read_pool_config(env)?;
     let fee =
         FixedI128::from_percentage(pool_config.flash_loan_fee).ok_or(Error::MathOverflowError)?;
@@ -40,6 +47,7 @@ pub fn flash_loan(
         let loan_asset = loan_assets.get_unchecked(i);
 
         require_positive_amount(env, loan_asset.amount);
+        require_reserve_action_not_paused(env, &loan_asset.asset, PauseAction::FlashLoan);
 
         let reserve = read_reserve(env, &loan_asset.asset)?;
         require_active_reserve(env, &reserve);
@@ -47,6 +55,8 @@ pub fn flash_loan(
 
         if loan_asset.borrow {
             require_not_in_grace_period(env, &pause_info);
+            require_not_frozen_reserve(env, &reserve);
+            require_reserve_action_not_paused(env, &loan_asset.asset, PauseAction::Borrow);
         }
 
         let (s_token_address, _) = reserve.get_fungible()?;
@@ -0,0 +1,20 @@
+use pool_interface::types::error::Error;
+use pool_interface::types::role::Role;
+use soroban_sdk::{assert_with_error, Address, Env};
+
+use crate::event;
+use crate::storage::write_role;
+
+use super::utils::validation::require_role;
+
+pub fn grant_role(env: &Env, role: Role, who: &Address) -> Result<(), Error> {
+    require_role(env, Role::PoolAdmin)?;
+    // Pool admin is changed only through `propose_admin` and `accept_admin`
+    assert_with_error!(env, role != Role::PoolAdmin, Error::InvalidRole);
+
+    write_role(env, role, who);
+
+    event::role_granted(env, role, who);
+
+    Ok(())
+}
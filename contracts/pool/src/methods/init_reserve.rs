@@ -1,37 +1,61 @@
 use pool_interface::types::reserve_data::ReserveData;
-use pool_interface::types::{error::Error, reserve_type::ReserveType};
+use pool_interface::types::{error::Error, reserve_type::ReserveType, role::Role};
 use soroban_sdk::{assert_with_error, Address, BytesN, Env};
 
+use crate::event;
 use crate::read_reserve;
-use crate::storage::{read_reserves, write_reserve, write_reserves};
+use crate::storage::{
+    read_reserve_id_holders, read_reserves, write_reserve_id_holders, write_reserves,
+};
 
-use super::utils::validation::require_admin;
+use super::utils::update_reserve::update_reserve;
+use super::utils::validation::require_role;
 
 pub fn init_reserve(env: &Env, asset: &Address, reserve_type: ReserveType) -> Result<(), Error> {
-    require_admin(env)?;
+    require_role(env, Role::PoolAdmin)?;
     assert_with_error!(
         env,
         read_reserve(env, asset).is_err(),
         Error::AlreadyInitialized
     );
 
-    let mut reserve_data = ReserveData::new(env, reserve_type);
+    let mut reserve_data = ReserveData::new(env, reserve_type.clone());
     let mut reserves = read_reserves(env);
-    let reserves_len = reserves.len();
 
-    assert_with_error!(
-        env,
-        reserves_len <= u8::MAX as u32,
-        Error::ReservesMaxCapacityExceeded
-    );
+    // Freed ids are reused once no user configuration refers to them anymore, otherwise
+    // stale collateral and borrowing bits would point to the new asset
+    let free_id = (0..reserves.len()).find(|&id| {
+        reserves.get_unchecked(id).is_none() && read_reserve_id_holders(env, id as u8) == Some(0)
+    });
+
+    let id = match free_id {
+        Some(free_id) => {
+            reserves.set(free_id, Some(asset.clone()));
+            free_id
+        }
+        None => {
+            let reserves_len = reserves.len();
 
-    let id = reserves_len as u8;
+            assert_with_error!(
+                env,
+                reserves_len <= u8::MAX as u32,
+                Error::ReservesMaxCapacityExceeded
+            );
 
-    reserve_data.id = BytesN::from_array(env, &[id; 1]);
-    reserves.push_back(asset.clone());
+            reserves.push_back(Some(asset.clone()));
+            write_reserve_id_holders(env, reserves_len as u8, 0);
+
+            reserves_len
+        }
+    };
+
+    reserve_data.id = BytesN::from_array(env, &[id as u8; 1]);
 
     write_reserves(env, &reserves);
-    write_reserve(env, asset, &reserve_data);
+
+    event::reserve_initialized(env, asset, &reserve_type, id);
+
+    update_reserve(env, asset, &reserve_data, 0);
 
     Ok(())
 }
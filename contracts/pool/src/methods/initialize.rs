@@ -3,7 +3,7 @@ use pool_interface::types::pool_config::PoolConfig;
 use soroban_sdk::{Address, Env};
 
 use crate::event;
-use crate::storage::write_admin;
+use crate::storage::{write_admin, write_storage_version, STORAGE_VERSION};
 
 use super::set_pool_configuration::set_pool_configuration;
 use super::utils::validation::require_admin_not_exist;
@@ -12,6 +12,7 @@ pub fn initialize(env: &Env, admin: &Address, pool_config: &PoolConfig) -> Resul
     require_admin_not_exist(env);
 
     write_admin(env, admin);
+    write_storage_version(env, STORAGE_VERSION);
 
     set_pool_configuration(env, pool_config, false)?;
 
@@ -1,6 +1,7 @@
 use common::{FixedI128, PERCENTAGE_FACTOR};
 use debt_token_interface::DebtTokenClient;
 use pool_interface::types::error::Error;
+use pool_interface::types::pause_action::PauseAction;
 use pool_interface::types::pool_config::PoolConfig;
 use pool_interface::types::reserve_type::ReserveType;
 use s_token_interface::STokenClient;
@@ -14,25 +15,34 @@ use crate::types::liquidation_asset::LiquidationAsset;
 use crate::types::price_provider::PriceProvider;
 use crate::types::user_configurator::UserConfigurator;
 use crate::{
-    add_protocol_fee_vault, add_token_balance, event, read_pause_info, read_pool_config,
-    read_token_balance, read_token_total_supply, write_token_balance, write_token_total_supply,
+    add_protocol_fee_vault, add_token_balance, add_token_total_supply, event, read_pause_info,
+    read_pool_config, read_token_balance, read_token_total_supply, write_token_balance,
+    write_token_total_supply,
 };
 
 use super::account_position::calc_account_data;
 use super::utils::get_collat_coeff::get_lp_amount;
-use super::utils::validation::require_not_paused;
+use super::utils::validation::{
+    require_action_not_paused, require_migrated, require_not_paused,
+    require_price_breakers_not_tripped, require_reserve_action_not_paused,
+};
 
 pub fn liquidate(env: &Env, liquidator: &Address, who: &Address) -> Result<(), Error> {
     liquidator.require_auth();
 
+    require_migrated(env);
+
     let pause_info = read_pause_info(env);
     require_not_paused(env, &pause_info);
     require_not_in_grace_period(env, &pause_info);
+    require_action_not_paused(env, PauseAction::Liquidate);
 
     let mut user_configurator = UserConfigurator::new(env, who, false, None);
     let user_config = user_configurator.user_config()?;
+    require_price_breakers_not_tripped(env, user_config);
+
     let pool_config = read_pool_config(env)?;
-    let mut price_provider = PriceProvider::new(env, &pool_config)?;
+    let mut price_provider = PriceProvider::new(env, &pool_config)?.record_prices();
 
     let account_data = calc_account_data(
         env,
@@ -226,6 +236,7 @@ fn do_liquidate(
             );
 
             write_token_balance(env, &collat.asset, who, who_rwa_balance_after)?;
+            add_token_total_supply(env, &collat.asset, -liq_comp_amount)?;
         }
 
         if protocol_part_underlying > 0 {
@@ -258,6 +269,10 @@ fn do_liquidate(
             break;
         }
 
+        // Pausing liquidations of a reserve stops repaying its debt, the collateral is seized
+        // regardless so that a paused reserve doesn't shield every position holding it
+        require_reserve_action_not_paused(env, &debt.asset, PauseAction::Liquidate);
+
         if let ReserveType::Fungible(s_token_address, debt_token_address) =
             &debt.reserve.reserve_type
         {
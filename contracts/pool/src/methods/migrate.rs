@@ -0,0 +1,146 @@
+use pool_interface::types::error::Error;
+use pool_interface::types::migration_input::MigrationInput;
+use pool_interface::types::reserve_type::ReserveType;
+use pool_interface::types::role::Role;
+use soroban_sdk::{assert_with_error, token, Env, Map};
+
+use crate::event;
+use crate::storage::{
+    read_legacy_pause_info, read_legacy_price_feeds, read_legacy_reserve, read_protocol_fee_vault,
+    read_reserve, read_reserve_id_holders, read_reserves, read_storage_version, write_pause_info,
+    write_price_feed, write_reserve_id_holders, write_storage_version, write_token_total_supply,
+    DEFAULT_TWAP_WINDOW, STORAGE_VERSION,
+};
+use crate::types::price_provider::feed_resolution;
+
+use super::utils::update_reserve::{update_reserve, utilization};
+use super::utils::validation::{
+    require_compatible_debt_token, require_compatible_s_token, require_role,
+};
+
+/// Brings the storage written by the previous wasm to the current layout.
+/// Has to be called once after the upgrade, user operations are blocked until then.
+pub fn migrate(env: &Env, from_version: u32, input: &MigrationInput) -> Result<(), Error> {
+    require_role(env, Role::PoolAdmin)?;
+
+    assert_with_error!(
+        env,
+        read_storage_version(env) == from_version && from_version < STORAGE_VERSION,
+        Error::InvalidStorageVersion
+    );
+
+    for version in from_version..STORAGE_VERSION {
+        migrate_from(env, version, input)?;
+    }
+
+    require_compatible_tokens(env)?;
+
+    write_storage_version(env, STORAGE_VERSION);
+
+    event::storage_migrated(env, from_version, STORAGE_VERSION);
+
+    Ok(())
+}
+
+fn migrate_from(env: &Env, version: u32, input: &MigrationInput) -> Result<(), Error> {
+    match version {
+        0 => migrate_from_unversioned(env),
+        1 => migrate_from_v1(env),
+        2 => migrate_from_v2(env, input),
+        _ => Err(Error::InvalidStorageVersion),
+    }
+}
+
+/// Rewrites reserves without the frozen flag and pause info without accrual suspension
+/// in the current layout. User configurations are converted by `migrate_user_configs`.
+fn migrate_from_unversioned(env: &Env) -> Result<(), Error> {
+    for asset in read_reserves(env).into_iter().flatten() {
+        if let Some(reserve) = read_legacy_reserve(env, &asset) {
+            update_reserve(env, &asset, &reserve, utilization(env, &reserve)?);
+        }
+    }
+
+    if let Some(pause_info) = read_legacy_pause_info(env) {
+        write_pause_info(env, pause_info);
+    }
+
+    Ok(())
+}
+
+/// Rewrites price feed configurations without the fallback settings in the current layout.
+/// Feeds not reporting the resolution needed to convert their TWAP record count
+/// get the default window, which the oracle admin is expected to review.
+fn migrate_from_v1(env: &Env) -> Result<(), Error> {
+    for asset in read_reserves(env).into_iter().flatten() {
+        if let Some(legacy) = read_legacy_price_feeds(env, &asset) {
+            let mut feed_resolutions = Map::new(env);
+
+            for feed in legacy.feeds.iter() {
+                match feed_resolution(env, &feed.feed) {
+                    Some(resolution) => feed_resolutions.set(feed.feed, resolution),
+                    None => {
+                        event::twap_window_defaulted(env, &asset, &feed.feed, DEFAULT_TWAP_WINDOW)
+                    }
+                }
+            }
+
+            write_price_feed(env, &asset, &legacy.into_config(feed_resolutions));
+        }
+    }
+
+    Ok(())
+}
+
+/// Stores the total of the RWA deposits, which were tracked per user only, and the number of
+/// users holding each reserve id, which were not counted, as attested by the admin.
+/// Delisting checks the total instead of the balance of the pool, and only counted ids are reused.
+fn migrate_from_v2(env: &Env, input: &MigrationInput) -> Result<(), Error> {
+    let reserves = read_reserves(env);
+
+    for asset in reserves.iter().flatten() {
+        if read_reserve(env, &asset)?.reserve_type != ReserveType::RWA {
+            continue;
+        }
+
+        let total_supply = match input.rwa_total_supplies.get(asset.clone()) {
+            Some(total_supply) => total_supply,
+            None => token::Client::new(env, &asset)
+                .balance(&env.current_contract_address())
+                .checked_sub(read_protocol_fee_vault(env, &asset))
+                .ok_or(Error::MathOverflowError)?
+                .max(0),
+        };
+
+        assert_with_error!(env, total_supply >= 0, Error::MustBeNonNegative);
+
+        write_token_total_supply(env, &asset, total_supply)?;
+    }
+
+    for (reserve_id, holders) in input.reserve_id_holders.iter() {
+        assert_with_error!(
+            env,
+            reserve_id < reserves.len(),
+            Error::ReservesMaxCapacityExceeded
+        );
+
+        if read_reserve_id_holders(env, reserve_id as u8).is_none() {
+            write_reserve_id_holders(env, reserve_id as u8, holders);
+        }
+    }
+
+    Ok(())
+}
+
+/// The upgraded pool has to be able to work with the tokens of every reserve.
+fn require_compatible_tokens(env: &Env) -> Result<(), Error> {
+    for asset in read_reserves(env).into_iter().flatten() {
+        let reserve = read_reserve(env, &asset)?;
+
+        if let ReserveType::Fungible(s_token_address, debt_token_address) = reserve.reserve_type {
+            require_compatible_s_token(env, &s_token_address);
+            require_compatible_debt_token(env, &debt_token_address);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use pool_interface::types::error::Error;
+use pool_interface::types::role::Role;
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::event;
+use crate::storage::{read_legacy_user_config, write_user_config};
+
+use super::utils::validation::require_role;
+
+/// Rewrites user configurations stored before the bitmap was extended beyond 64 reserves
+/// in the current layout. Users can't be enumerated on chain, so they are passed in batches.
+/// Configurations already in the current layout are skipped. Optional, as legacy configurations
+/// are converted on read and stored in the current layout by the next update.
+pub fn migrate_user_configs(env: &Env, users: &Vec<Address>) -> Result<(), Error> {
+    require_role(env, Role::PoolAdmin)?;
+
+    let mut migrated = 0;
+
+    for user in users.iter() {
+        if let Some(user_config) = read_legacy_user_config(env, &user) {
+            write_user_config(env, &user, &user_config);
+            migrated += 1;
+        }
+    }
+
+    event::user_configs_migrated(env, migrated);
+
+    Ok(())
+}
@@ -1,24 +1,47 @@
+pub mod accept_admin;
 pub mod account_position;
 pub mod borrow;
+pub mod cancel_admin_proposal;
+pub mod cancel_operation;
 pub mod claim_protocol_fee;
 pub mod collat_coeff;
 pub mod configure_as_collateral;
 pub mod debt_coeff;
+pub mod delist_reserve;
 pub mod deposit;
 pub mod enable_borrowing_on_reserve;
+pub mod execute_operation;
 pub mod finalize_transfer;
 pub mod flash_loan;
+pub mod grant_role;
 pub mod init_reserve;
 pub mod initialize;
 pub mod liquidate;
+pub mod migrate;
+pub mod migrate_user_configs;
+pub mod pause_matrix;
+pub mod propose_admin;
+pub mod redeem;
 pub mod repay;
+pub mod reset_price_breaker;
+pub mod revoke_role;
+pub mod schedule_operation;
+pub mod set_accrual_suspension;
+pub mod set_action_pause;
 pub mod set_as_collateral;
 pub mod set_pause;
 pub mod set_pool_configuration;
 pub mod set_price_feeds;
+pub mod set_reserve_freeze;
+pub mod set_reserve_pause;
 pub mod set_reserve_status;
+pub mod set_timelock_delay;
+pub mod start_settlement;
+pub mod start_wind_down;
 pub mod twap_median_price;
 pub mod upgrade;
 pub mod upgrade_token;
+pub mod upgrade_tokens;
 pub mod utils;
 pub mod withdraw;
+pub mod write_off_debt;
@@ -0,0 +1,37 @@
+use pool_interface::types::pause_action::PauseAction;
+use pool_interface::types::pause_matrix::PauseMatrix;
+use soroban_sdk::{Env, Map, Vec};
+
+use crate::storage::{
+    read_pause_info, read_paused_actions, read_reserve_paused_actions, read_reserves,
+};
+
+pub fn pause_matrix(env: &Env) -> PauseMatrix {
+    let mut reserves = Map::new(env);
+
+    for asset in read_reserves(env).into_iter().flatten() {
+        let mask = read_reserve_paused_actions(env, &asset);
+
+        if mask != 0 {
+            reserves.set(asset, to_actions(env, mask));
+        }
+    }
+
+    PauseMatrix {
+        paused: read_pause_info(env).paused,
+        actions: to_actions(env, read_paused_actions(env)),
+        reserves,
+    }
+}
+
+fn to_actions(env: &Env, mask: u32) -> Vec<PauseAction> {
+    let mut actions = Vec::new(env);
+
+    for action in PauseAction::ALL {
+        if mask & action.mask() != 0 {
+            actions.push_back(action);
+        }
+    }
+
+    actions
+}
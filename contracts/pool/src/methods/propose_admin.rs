@@ -0,0 +1,18 @@
+use pool_interface::types::error::Error;
+use pool_interface::types::role::Role;
+use soroban_sdk::{Address, Env};
+
+use crate::event;
+use crate::storage::{read_admin, write_pending_admin};
+
+use super::utils::validation::require_role;
+
+pub fn propose_admin(env: &Env, new_admin: &Address) -> Result<(), Error> {
+    require_role(env, Role::PoolAdmin)?;
+
+    write_pending_admin(env, new_admin);
+
+    event::admin_proposed(env, &read_admin(env)?, new_admin);
+
+    Ok(())
+}
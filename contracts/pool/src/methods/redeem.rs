@@ -0,0 +1,95 @@
+use common::FixedI128;
+use pool_interface::types::error::Error;
+use pool_interface::types::pause_action::PauseAction;
+use pool_interface::types::wind_down_info::WindDownStage;
+use s_token_interface::STokenClient;
+use soroban_sdk::{assert_with_error, Address, Env};
+
+use crate::storage::{
+    read_reserve, read_token_balance, read_token_total_supply, read_wind_down_info,
+    write_token_balance, write_token_total_supply,
+};
+use crate::types::user_configurator::UserConfigurator;
+use crate::{add_token_balance, event, read_pause_info, read_pool_config};
+
+use super::utils::recalculate_reserve_data::recalculate_reserve_data;
+use super::utils::validation::{
+    require_migrated, require_not_paused, require_positive_amount,
+    require_reserve_action_not_paused,
+};
+
+/// Burns the whole s-token balance of `who` for the share of the underlying left in the pool.
+/// Available only at the settlement stage of the wind-down and once the debt of the reserve
+/// is repaid, liquidated or written off, so that later repayments can't change the shares.
+pub fn redeem(env: &Env, who: &Address, asset: &Address, to: &Address) -> Result<(), Error> {
+    who.require_auth();
+
+    require_migrated(env);
+
+    let pause_info = read_pause_info(env);
+    require_not_paused(env, &pause_info);
+    require_reserve_action_not_paused(env, asset, PauseAction::Withdraw);
+
+    assert_with_error!(
+        env,
+        read_wind_down_info(env).stage == WindDownStage::Settlement,
+        Error::InvalidWindDownStage
+    );
+
+    let reserve = read_reserve(env, asset)?;
+    let (s_token_address, debt_token_address) = reserve.get_fungible()?;
+
+    let mut user_configurator = UserConfigurator::new(env, who, false, None);
+    // Collateral of the borrower is kept in the pool until the debt is repaid or liquidated
+    assert_with_error!(
+        env,
+        !user_configurator.user_config()?.is_borrowing_any(),
+        Error::DebtError
+    );
+
+    let pool_config = read_pool_config(env)?;
+    let s_token_supply = read_token_total_supply(env, s_token_address);
+    let debt_token_supply = read_token_total_supply(env, debt_token_address);
+    assert_with_error!(env, debt_token_supply == 0, Error::DebtError);
+
+    let stoken_underlying_balance = read_token_balance(env, asset, s_token_address);
+    let s_token_to_burn = read_token_balance(env, s_token_address, who);
+
+    require_positive_amount(env, s_token_to_burn);
+
+    let underlying_to_redeem = FixedI128::from_rational(s_token_to_burn, s_token_supply)
+        .and_then(|share| share.mul_int(stoken_underlying_balance))
+        .ok_or(Error::MathOverflowError)?;
+
+    require_positive_amount(env, underlying_to_redeem);
+
+    let s_token_supply_after = s_token_supply
+        .checked_sub(s_token_to_burn)
+        .ok_or(Error::InvalidAmount)?;
+    let amount_to_sub = underlying_to_redeem
+        .checked_neg()
+        .ok_or(Error::MathOverflowError)?;
+
+    STokenClient::new(env, s_token_address).burn(who, &s_token_to_burn, &underlying_to_redeem, to);
+
+    add_token_balance(env, asset, s_token_address, amount_to_sub)?;
+    write_token_total_supply(env, s_token_address, s_token_supply_after)?;
+    write_token_balance(env, s_token_address, who, 0)?;
+
+    user_configurator
+        .withdraw(reserve.get_id(), asset, true)?
+        .write();
+
+    recalculate_reserve_data(
+        env,
+        asset,
+        &reserve,
+        &pool_config,
+        s_token_supply_after,
+        debt_token_supply,
+    )?;
+
+    event::withdraw(env, who, asset, to, underlying_to_redeem);
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use debt_token_interface::DebtTokenClient;
 use pool_interface::types::asset_balance::AssetBalance;
 use pool_interface::types::error::Error;
+use pool_interface::types::pause_action::PauseAction;
 use pool_interface::types::pool_config::PoolConfig;
 use pool_interface::types::reserve_data::ReserveData;
 use soroban_sdk::{token, Address, Env};
@@ -19,15 +20,18 @@ use super::utils::get_collat_coeff::get_collat_coeff;
 use super::utils::rate::get_actual_borrower_accrued_rate;
 use super::utils::recalculate_reserve_data::recalculate_reserve_data;
 use super::utils::validation::{
-    require_active_reserve, require_debt, require_min_position_amounts, require_not_paused,
-    require_positive_amount,
+    require_active_reserve, require_debt, require_migrated, require_min_position_amounts,
+    require_not_paused, require_positive_amount, require_reserve_action_not_paused,
 };
 
 pub fn repay(env: &Env, who: &Address, asset: &Address, amount: i128) -> Result<(), Error> {
     who.require_auth();
 
+    require_migrated(env);
+
     let pause_info = read_pause_info(env);
     require_not_paused(env, &pause_info);
+    require_reserve_action_not_paused(env, asset, PauseAction::Repay);
 
     require_positive_amount(env, amount);
 
@@ -151,7 +155,7 @@ pub fn do_repay(
         },
         pool_config,
         user_configurator.user_config()?,
-        &mut PriceProvider::new(env, pool_config)?,
+        &mut PriceProvider::new(env, pool_config)?.record_prices(),
         false,
     )?;
 
@@ -0,0 +1,21 @@
+use pool_interface::types::error::Error;
+use pool_interface::types::role::Role;
+use soroban_sdk::{Address, Env};
+
+use crate::event;
+use crate::storage::{read_price_feeds, remove_price_breaker};
+
+use super::utils::validation::require_role;
+
+/// Resets the circuit breaker of the asset price. The next price read from the feeds
+/// is accepted as is and becomes the new reference price.
+pub fn reset_price_breaker(env: &Env, asset: &Address) -> Result<(), Error> {
+    require_role(env, Role::OracleAdmin)?;
+    read_price_feeds(env, asset)?;
+
+    remove_price_breaker(env, asset);
+
+    event::price_breaker_reset(env, asset);
+
+    Ok(())
+}
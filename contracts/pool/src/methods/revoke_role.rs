@@ -0,0 +1,21 @@
+use pool_interface::types::error::Error;
+use pool_interface::types::role::Role;
+use soroban_sdk::{assert_with_error, Env};
+
+use crate::event;
+use crate::storage::{read_role, remove_role};
+
+use super::utils::validation::require_role;
+
+pub fn revoke_role(env: &Env, role: Role) -> Result<(), Error> {
+    require_role(env, Role::PoolAdmin)?;
+    assert_with_error!(env, role != Role::PoolAdmin, Error::InvalidRole);
+
+    let who = read_role(env, role).ok_or(Error::Uninitialized)?;
+
+    remove_role(env, role);
+
+    event::role_revoked(env, role, &who);
+
+    Ok(())
+}
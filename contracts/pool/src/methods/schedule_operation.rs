@@ -0,0 +1,58 @@
+use pool_interface::types::error::Error;
+use pool_interface::types::queued_operation::QueuedOperation;
+use pool_interface::types::role::Role;
+use pool_interface::types::timelock_operation::TimelockOperation;
+use soroban_sdk::{assert_with_error, Env};
+
+use crate::event;
+use crate::storage::{next_timelock_id, read_timelock_delay, write_queued_operation};
+
+use super::execute_operation::remove_expired_operations;
+use super::utils::validation::require_role;
+
+pub fn schedule_operation(
+    env: &Env,
+    operation: &TimelockOperation,
+    eta: u64,
+) -> Result<u32, Error> {
+    require_role(env, operation_role(operation))?;
+
+    let min_eta = env
+        .ledger()
+        .timestamp()
+        .checked_add(read_timelock_delay(env))
+        .ok_or(Error::MathOverflowError)?;
+
+    assert_with_error!(env, eta >= min_eta, Error::InvalidEta);
+
+    remove_expired_operations(env);
+
+    let id = next_timelock_id(env);
+
+    write_queued_operation(
+        env,
+        &QueuedOperation {
+            id,
+            eta,
+            operation: operation.clone(),
+        },
+    );
+
+    event::operation_scheduled(env, id, eta);
+
+    Ok(id)
+}
+
+/// Role which is allowed to schedule and cancel the operation
+pub fn operation_role(operation: &TimelockOperation) -> Role {
+    match operation {
+        TimelockOperation::SetPoolConfiguration(_)
+        | TimelockOperation::ConfigureAsCollateral(_, _) => Role::RiskAdmin,
+        TimelockOperation::SetPriceFeeds(_) => Role::OracleAdmin,
+        TimelockOperation::Upgrade(_)
+        | TimelockOperation::UpgradeToken(_, _, _)
+        | TimelockOperation::UpgradeTokens(_, _)
+        | TimelockOperation::SetTimelockDelay(_)
+        | TimelockOperation::StartWindDown(_, _) => Role::PoolAdmin,
+    }
+}
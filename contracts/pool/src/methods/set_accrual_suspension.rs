@@ -0,0 +1,34 @@
+use pool_interface::types::error::Error;
+use pool_interface::types::role::Role;
+use soroban_sdk::Env;
+
+use crate::event;
+use crate::storage::{read_pause_info, write_pause_info};
+
+use super::set_pause::skip_paused_time;
+use super::utils::validation::require_role;
+
+pub fn set_accrual_suspension(env: &Env, enabled: bool) -> Result<(), Error> {
+    require_role(env, Role::RiskAdmin)?;
+
+    let mut pause_info = read_pause_info(env);
+    let now = env.ledger().timestamp();
+
+    // Enabled while paused, accrual is suspended from now on, not since the pause started
+    if enabled && !pause_info.suspend_accrual && pause_info.paused {
+        pause_info.paused_at = now;
+    }
+
+    // Disabled while paused, the time it was suspended for is skipped now, as the unpause won't
+    if !enabled && pause_info.suspend_accrual && pause_info.paused {
+        skip_paused_time(env, pause_info.paused_at, now)?;
+        pause_info.paused_at = now;
+    }
+
+    pause_info.suspend_accrual = enabled;
+    write_pause_info(env, pause_info);
+
+    event::accrual_suspension_changed(env, enabled);
+
+    Ok(())
+}
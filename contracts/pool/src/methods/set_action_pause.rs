@@ -0,0 +1,32 @@
+use pool_interface::types::error::Error;
+use pool_interface::types::pause_action::PauseAction;
+use soroban_sdk::{Address, Env};
+
+use crate::event;
+use crate::storage::{read_paused_actions, write_paused_actions};
+
+use super::utils::validation::require_pause_role;
+
+pub fn set_action_pause(
+    env: &Env,
+    who: &Address,
+    action: PauseAction,
+    value: bool,
+) -> Result<(), Error> {
+    require_pause_role(env, who, value)?;
+
+    let mask = read_paused_actions(env);
+
+    write_paused_actions(
+        env,
+        if value {
+            mask | action.mask()
+        } else {
+            mask & !action.mask()
+        },
+    );
+
+    event::action_pause_changed(env, action, value);
+
+    Ok(())
+}
@@ -3,7 +3,10 @@ use soroban_sdk::{assert_with_error, Address, Env};
 
 use crate::methods::account_position::calc_account_data;
 use crate::methods::utils::validation::require_gte_initial_health;
+use crate::methods::utils::validation::require_migrated;
 use crate::methods::utils::validation::require_min_position_amounts;
+use crate::methods::utils::validation::require_not_frozen_reserve;
+use crate::methods::utils::validation::require_price_breakers_not_tripped;
 use crate::read_pool_config;
 use crate::storage::read_reserve;
 use crate::types::calc_account_data_cache::CalcAccountDataCache;
@@ -17,12 +20,18 @@ pub fn set_as_collateral(
     use_as_collateral: bool,
 ) -> Result<(), Error> {
     who.require_auth();
+    require_migrated(env);
 
     let pool_config = read_pool_config(env)?;
     let mut user_configurator =
         UserConfigurator::new(env, who, false, Some(pool_config.user_assets_limit));
     let user_config = user_configurator.user_config()?;
-    let reserve_id = read_reserve(env, asset)?.get_id();
+    let reserve = read_reserve(env, asset)?;
+    let reserve_id = reserve.get_id();
+
+    if use_as_collateral {
+        require_not_frozen_reserve(env, &reserve);
+    }
 
     assert_with_error!(
         env,
@@ -37,13 +46,17 @@ pub fn set_as_collateral(
         let pool_config = read_pool_config(env)?;
         user_configurator.withdraw(reserve_id, asset, true)?;
 
+        require_price_breakers_not_tripped(env, user_configurator.user_config()?);
+
         let account_data = calc_account_data(
             env,
             who,
             &CalcAccountDataCache::none(),
             &pool_config,
             user_configurator.user_config()?,
-            &mut PriceProvider::new(env, &pool_config)?,
+            &mut PriceProvider::new(env, &pool_config)?
+                .conservative()
+                .record_prices(),
             false,
         )?;
 
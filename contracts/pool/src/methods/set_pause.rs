@@ -1,19 +1,51 @@
 use pool_interface::types::error::Error;
-use soroban_sdk::Env;
+use soroban_sdk::{Address, Env};
 
+use crate::event;
+use crate::storage::{read_reserve, read_reserves};
 use crate::{read_pause_info, storage::write_pause_info};
 
-use super::utils::validation::require_admin;
+use super::utils::update_reserve::{update_reserve, utilization};
+use super::utils::validation::require_pause_role;
 
-pub fn set_pause(env: &Env, value: bool) -> Result<(), Error> {
-    require_admin(env)?;
+pub fn set_pause(env: &Env, who: &Address, value: bool) -> Result<(), Error> {
+    require_pause_role(env, who, value)?;
     let mut pause_info = read_pause_info(env);
+    let prev_paused = pause_info.paused;
+    let now = env.ledger().timestamp();
+
+    if !pause_info.paused && value {
+        pause_info.paused_at = now;
+    }
 
     if pause_info.paused && !value {
-        pause_info.unpaused_at = env.ledger().timestamp();
+        pause_info.unpaused_at = now;
+
+        if pause_info.suspend_accrual {
+            skip_paused_time(env, pause_info.paused_at, now)?;
+        }
     }
 
     pause_info.paused = value;
     write_pause_info(env, pause_info);
+
+    event::pause_changed(env, prev_paused, value);
+
+    Ok(())
+}
+
+/// Moves the last update of the reserves forward by the paused interval,
+/// so the interest is accrued only for the time the pool was operational.
+pub(crate) fn skip_paused_time(env: &Env, paused_at: u64, unpaused_at: u64) -> Result<(), Error> {
+    for asset in read_reserves(env).into_iter().flatten() {
+        let mut reserve = read_reserve(env, &asset)?;
+        let paused_from = reserve.last_update_timestamp.max(paused_at);
+
+        if paused_from < unpaused_at {
+            reserve.last_update_timestamp += unpaused_at - paused_from;
+            update_reserve(env, &asset, &reserve, utilization(env, &reserve)?);
+        }
+    }
+
     Ok(())
 }
@@ -1,12 +1,16 @@
 use pool_interface::types::error::Error;
 use pool_interface::types::pool_config::PoolConfig;
+use pool_interface::types::role::Role;
 use soroban_sdk::Env;
 
+use crate::event;
 use crate::read_pause_info;
+use crate::read_pool_config;
 use crate::write_pause_info;
 use crate::write_pool_config;
 
-use super::utils::validation::require_admin;
+use super::utils::validation::require_not_timelocked;
+use super::utils::validation::require_role;
 use super::utils::validation::require_valid_pool_config;
 
 pub fn set_pool_configuration(
@@ -15,11 +19,14 @@ pub fn set_pool_configuration(
     check_admin: bool,
 ) -> Result<(), Error> {
     if check_admin {
-        require_admin(env)?;
+        require_role(env, Role::RiskAdmin)?;
+        require_not_timelocked(env);
     }
 
     require_valid_pool_config(env, config);
 
+    let prev_config = read_pool_config(env).ok();
+
     write_pool_config(env, config);
 
     let mut pause_info = read_pause_info(env);
@@ -27,5 +34,7 @@ pub fn set_pool_configuration(
 
     write_pause_info(env, pause_info);
 
+    event::pool_configuration_changed(env, &prev_config, config);
+
     Ok(())
 }
@@ -1,15 +1,35 @@
 use pool_interface::types::error::Error;
 use pool_interface::types::price_feed_config_input::PriceFeedConfigInput;
+use pool_interface::types::role::Role;
 use soroban_sdk::{Env, Vec};
 
-use crate::storage::write_price_feeds;
+use crate::event;
+use crate::storage::{read_price_feeds, write_price_feeds};
 
-use super::utils::validation::require_admin;
+use super::utils::validation::{require_not_timelocked, require_role, require_valid_price_feeds};
 
-pub fn set_price_feeds(env: &Env, inputs: &Vec<PriceFeedConfigInput>) -> Result<(), Error> {
-    require_admin(env)?;
+pub fn set_price_feeds(
+    env: &Env,
+    inputs: &Vec<PriceFeedConfigInput>,
+    check_admin: bool,
+) -> Result<(), Error> {
+    if check_admin {
+        require_role(env, Role::OracleAdmin)?;
+        require_not_timelocked(env);
+    }
+
+    let mut prev_configs = Vec::new(env);
+    for input in inputs.iter() {
+        require_valid_price_feeds(env, &input);
+        prev_configs.push_back(read_price_feeds(env, &input.asset).ok());
+    }
 
     write_price_feeds(env, inputs);
 
+    for (input, prev_config) in inputs.iter().zip(prev_configs.iter()) {
+        let config = read_price_feeds(env, &input.asset)?;
+        event::price_feeds_changed(env, &input.asset, &prev_config, &config);
+    }
+
     Ok(())
 }
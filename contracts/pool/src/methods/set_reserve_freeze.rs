@@ -0,0 +1,22 @@
+use pool_interface::types::error::Error;
+use pool_interface::types::role::Role;
+use soroban_sdk::{Address, Env};
+
+use crate::event;
+use crate::storage::read_reserve;
+
+use super::utils::update_reserve::{update_reserve, utilization};
+use super::utils::validation::require_role;
+
+pub fn set_reserve_freeze(env: &Env, asset: &Address, is_frozen: bool) -> Result<(), Error> {
+    require_role(env, Role::PoolAdmin)?;
+
+    let mut reserve = read_reserve(env, asset)?;
+
+    reserve.configuration.is_frozen = is_frozen;
+    update_reserve(env, asset, &reserve, utilization(env, &reserve)?);
+
+    event::reserve_freeze_changed(env, asset, is_frozen);
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use pool_interface::types::error::Error;
+use pool_interface::types::pause_action::PauseAction;
+use soroban_sdk::{Address, Env};
+
+use crate::event;
+use crate::storage::{read_reserve, read_reserve_paused_actions, write_reserve_paused_actions};
+
+use super::utils::validation::require_pause_role;
+
+pub fn set_reserve_pause(
+    env: &Env,
+    who: &Address,
+    asset: &Address,
+    action: PauseAction,
+    value: bool,
+) -> Result<(), Error> {
+    require_pause_role(env, who, value)?;
+    read_reserve(env, asset)?;
+
+    let mask = read_reserve_paused_actions(env, asset);
+
+    write_reserve_paused_actions(
+        env,
+        asset,
+        if value {
+            mask | action.mask()
+        } else {
+            mask & !action.mask()
+        },
+    );
+
+    event::reserve_pause_changed(env, asset, action, value);
+
+    Ok(())
+}
@@ -1,20 +1,23 @@
 use pool_interface::types::error::Error;
+use pool_interface::types::role::Role;
 use soroban_sdk::{Address, Env};
 
 use crate::event;
-use crate::storage::{read_reserve, write_reserve};
+use crate::storage::read_reserve;
 
-use super::utils::validation::require_admin;
+use super::utils::update_reserve::{update_reserve, utilization};
+use super::utils::validation::require_role;
 
 pub fn set_reserve_status(env: &Env, asset: &Address, is_active: bool) -> Result<(), Error> {
-    require_admin(env)?;
+    require_role(env, Role::PoolAdmin)?;
 
     let mut reserve = read_reserve(env, asset)?;
+    let prev_active = reserve.configuration.is_active;
 
     reserve.configuration.is_active = is_active;
-    write_reserve(env, asset, &reserve);
+    update_reserve(env, asset, &reserve, utilization(env, &reserve)?);
 
-    event::reserve_status_changed(env, asset, is_active);
+    event::reserve_status_changed(env, asset, prev_active, is_active);
 
     Ok(())
 }
@@ -0,0 +1,31 @@
+use common::ONE_DAY;
+use pool_interface::types::error::Error;
+use pool_interface::types::role::Role;
+use soroban_sdk::{assert_with_error, Env};
+
+use crate::event;
+use crate::storage::{read_timelock_delay, write_timelock_delay};
+
+use super::utils::validation::require_role;
+
+pub const MAX_TIMELOCK_DELAY: u64 = 30 * ONE_DAY;
+
+pub fn set_timelock_delay(env: &Env, delay: u64, check_admin: bool) -> Result<(), Error> {
+    if check_admin {
+        require_role(env, Role::PoolAdmin)?;
+        // Raising the delay can't harm users, lowering it has to wait for the current one
+        assert_with_error!(
+            env,
+            delay >= read_timelock_delay(env),
+            Error::MustBeScheduled
+        );
+    }
+
+    assert_with_error!(env, delay <= MAX_TIMELOCK_DELAY, Error::ExceededMaxValue);
+
+    write_timelock_delay(env, delay);
+
+    event::timelock_delay_changed(env, delay);
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use pool_interface::types::error::Error;
+use pool_interface::types::role::Role;
+use pool_interface::types::wind_down_info::WindDownStage;
+use soroban_sdk::{assert_with_error, Env};
+
+use crate::event;
+use crate::storage::{read_wind_down_info, write_wind_down_info};
+
+use super::utils::validation::require_role;
+
+/// Freezes the borrower rate premium. Lenders redeem a reserve once its debt is repaid,
+/// liquidated or written off, so every lender gets the same share of the same amount.
+pub fn start_settlement(env: &Env) -> Result<(), Error> {
+    require_role(env, Role::PoolAdmin)?;
+
+    let mut wind_down_info = read_wind_down_info(env);
+
+    assert_with_error!(
+        env,
+        wind_down_info.stage == WindDownStage::WindDown,
+        Error::InvalidWindDownStage
+    );
+
+    wind_down_info.stage = WindDownStage::Settlement;
+    wind_down_info.settled_at = env.ledger().timestamp();
+    write_wind_down_info(env, &wind_down_info);
+
+    event::settlement_started(env);
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use pool_interface::types::error::Error;
+use pool_interface::types::role::Role;
+use pool_interface::types::wind_down_info::{WindDownInfo, WindDownStage};
+use soroban_sdk::{assert_with_error, Env};
+
+use crate::event;
+use crate::storage::{read_wind_down_info, write_wind_down_info};
+
+use super::utils::validation::{
+    require_lte_percentage_factor, require_not_timelocked, require_role,
+};
+
+pub fn start_wind_down(
+    env: &Env,
+    rate_step: u32,
+    step_secs: u64,
+    check_admin: bool,
+) -> Result<(), Error> {
+    if check_admin {
+        require_role(env, Role::PoolAdmin)?;
+        require_not_timelocked(env);
+    }
+
+    // Wind-down is irreversible
+    assert_with_error!(
+        env,
+        read_wind_down_info(env).stage == WindDownStage::Inactive,
+        Error::InvalidWindDownStage
+    );
+    require_lte_percentage_factor(env, rate_step);
+    assert_with_error!(env, step_secs > 0, Error::BellowMinValue);
+
+    write_wind_down_info(
+        env,
+        &WindDownInfo {
+            stage: WindDownStage::WindDown,
+            started_at: env.ledger().timestamp(),
+            rate_step,
+            step_secs,
+            settled_at: 0,
+        },
+    );
+
+    event::wind_down_started(env, rate_step, step_secs);
+
+    Ok(())
+}
@@ -1,13 +1,22 @@
 use pool_interface::types::error::Error;
+use pool_interface::types::role::Role;
 use soroban_sdk::{BytesN, Env};
 
-use super::utils::validation::require_admin;
+use crate::event;
 
-pub fn upgrade(env: &Env, new_wasm_hash: &BytesN<32>) -> Result<(), Error> {
-    require_admin(env)?;
+use super::utils::validation::{require_not_timelocked, require_role};
+
+/// Compatibility of the new wasm with the reserve tokens is checked by `migrate`.
+pub fn upgrade(env: &Env, new_wasm_hash: &BytesN<32>, check_admin: bool) -> Result<(), Error> {
+    if check_admin {
+        require_role(env, Role::PoolAdmin)?;
+        require_not_timelocked(env);
+    }
 
     env.deployer()
         .update_current_contract_wasm(new_wasm_hash.clone());
 
+    event::upgraded(env, pool_interface::VERSION, new_wasm_hash);
+
     Ok(())
 }
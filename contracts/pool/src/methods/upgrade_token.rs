@@ -1,28 +1,68 @@
 use debt_token_interface::DebtTokenClient;
 use pool_interface::types::error::Error;
+use pool_interface::types::role::Role;
 use s_token_interface::STokenClient;
-use soroban_sdk::{Address, BytesN, Env};
+use soroban_sdk::{assert_with_error, Address, BytesN, Env};
 
+use crate::event;
 use crate::storage::read_reserve;
 
-use super::utils::validation::require_admin;
+use super::utils::validation::{
+    require_compatible_debt_token, require_compatible_s_token, require_not_timelocked, require_role,
+};
 
 pub fn upgrade_token(
     env: &Env,
     asset: &Address,
     new_wasm_hash: &BytesN<32>,
     s_token: bool,
+    check_admin: bool,
 ) -> Result<(), Error> {
-    require_admin(env)?;
+    if check_admin {
+        require_role(env, Role::PoolAdmin)?;
+        require_not_timelocked(env);
+    }
 
     let reserve = read_reserve(env, asset)?;
     let (s_token_address, debt_token_address) = reserve.get_fungible()?;
 
     if s_token {
-        STokenClient::new(env, s_token_address).upgrade(new_wasm_hash)
+        upgrade_s_token(env, asset, s_token_address, new_wasm_hash);
     } else {
-        DebtTokenClient::new(env, debt_token_address).upgrade(new_wasm_hash);
+        upgrade_debt_token(env, asset, debt_token_address, new_wasm_hash);
     }
 
     Ok(())
 }
+
+/// Fails if the upgraded s-token is incompatible with the pool
+/// or reports a lower version than before the upgrade
+pub(crate) fn upgrade_s_token(
+    env: &Env,
+    asset: &Address,
+    s_token_address: &Address,
+    new_wasm_hash: &BytesN<32>,
+) {
+    let s_token = STokenClient::new(env, s_token_address);
+    let prev_version = s_token.version();
+    s_token.upgrade(new_wasm_hash);
+    let version = require_compatible_s_token(env, s_token_address);
+    assert_with_error!(env, version >= prev_version, Error::InvalidTokenVersion);
+    event::token_upgraded(env, asset, s_token_address, prev_version, version);
+}
+
+/// Fails if the upgraded debt token is incompatible with the pool
+/// or reports a lower version than before the upgrade
+pub(crate) fn upgrade_debt_token(
+    env: &Env,
+    asset: &Address,
+    debt_token_address: &Address,
+    new_wasm_hash: &BytesN<32>,
+) {
+    let debt_token = DebtTokenClient::new(env, debt_token_address);
+    let prev_version = debt_token.version();
+    debt_token.upgrade(new_wasm_hash);
+    let version = require_compatible_debt_token(env, debt_token_address);
+    assert_with_error!(env, version >= prev_version, Error::InvalidTokenVersion);
+    event::token_upgraded(env, asset, debt_token_address, prev_version, version);
+}
@@ -0,0 +1,35 @@
+use pool_interface::types::error::Error;
+use pool_interface::types::reserve_type::ReserveType;
+use pool_interface::types::role::Role;
+use soroban_sdk::{BytesN, Env};
+
+use crate::storage::{read_reserve, read_reserves};
+
+use super::upgrade_token::{upgrade_debt_token, upgrade_s_token};
+use super::utils::validation::{require_not_timelocked, require_role};
+
+/// Upgrades s-token and debt token of every fungible reserve in a single call.
+/// Fails if any token is incompatible with the pool or reports a lower version
+/// after the upgrade than before.
+pub fn upgrade_tokens(
+    env: &Env,
+    s_token_wasm_hash: &BytesN<32>,
+    debt_token_wasm_hash: &BytesN<32>,
+    check_admin: bool,
+) -> Result<(), Error> {
+    if check_admin {
+        require_role(env, Role::PoolAdmin)?;
+        require_not_timelocked(env);
+    }
+
+    for asset in read_reserves(env).into_iter().flatten() {
+        let reserve = read_reserve(env, &asset)?;
+
+        if let ReserveType::Fungible(s_token_address, debt_token_address) = reserve.reserve_type {
+            upgrade_s_token(env, &asset, &s_token_address, s_token_wasm_hash);
+            upgrade_debt_token(env, &asset, &debt_token_address, debt_token_wasm_hash);
+        }
+    }
+
+    Ok(())
+}
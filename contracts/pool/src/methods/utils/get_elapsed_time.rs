@@ -1,11 +1,13 @@
 use soroban_sdk::Env;
 
+use crate::storage::read_pause_info;
+
 pub fn get_elapsed_time(
     env: &Env,
     last_update_timestamp: u64,
     reserve_timestamp_window: u64,
 ) -> (u64, u64) {
-    let current_time = env.ledger().timestamp();
+    let current_time = read_pause_info(env).accrual_timestamp(env.ledger().timestamp());
 
     current_time
         .checked_sub(last_update_timestamp)
@@ -2,4 +2,5 @@ pub mod get_collat_coeff;
 pub mod get_elapsed_time;
 pub mod rate;
 pub mod recalculate_reserve_data;
+pub mod update_reserve;
 pub mod validation;
@@ -1,4 +1,4 @@
-use common::{FixedI128, ALPHA_DENOMINATOR};
+use common::{FixedI128, ALPHA_DENOMINATOR, PERCENTAGE_FACTOR};
 use pool_interface::types::error::Error;
 use pool_interface::types::pool_config::PoolConfig;
 use pool_interface::types::reserve_data::ReserveData;
@@ -6,6 +6,9 @@ use soroban_sdk::Env;
 
 use super::get_elapsed_time::get_elapsed_time;
 
+/// Upper bound of the borrower rate premium accumulated during the wind-down, 1000% a year
+pub const WIND_DOWN_MAX_RATE_PREMIUM: u32 = 10 * PERCENTAGE_FACTOR;
+
 pub fn calc_interest_rate(
     total_collateral: i128,
     total_debt: i128,
@@ -86,8 +89,13 @@ pub fn calc_accrued_rates(
     elapsed_time: u64,
     pool_config: &PoolConfig,
     reserve_data: &ReserveData,
+    ir_premium: FixedI128,
 ) -> Option<AccruedRates> {
-    let borrower_ir = calc_interest_rate(total_collateral, total_debt, pool_config)?;
+    // The base rate is capped by `ir_max_rate`, so the wind-down premium is added on top of it
+    // and capped separately to keep accruals bounded
+    let max_premium = FixedI128::from_percentage(WIND_DOWN_MAX_RATE_PREMIUM)?;
+    let borrower_ir = calc_interest_rate(total_collateral, total_debt, pool_config)?
+        .checked_add(ir_premium.min(max_premium))?;
 
     let scale_coeff = FixedI128::from_percentage(pool_config.ir_scaling_coeff)?;
     let lender_ir = borrower_ir.checked_mul(scale_coeff)?;
@@ -1,10 +1,12 @@
+use common::FixedI128;
 use pool_interface::types::error::Error;
 use pool_interface::types::pool_config::PoolConfig;
 use pool_interface::types::reserve_data::ReserveData;
 use soroban_sdk::{Address, Env};
 
-use crate::storage::write_reserve;
+use crate::storage::read_wind_down_info;
 
+use super::update_reserve::update_reserve;
 use super::{get_elapsed_time::get_elapsed_time, rate::calc_accrued_rates};
 
 pub fn recalculate_reserve_data(
@@ -25,12 +27,17 @@ pub fn recalculate_reserve_data(
         return Ok(reserve.clone());
     }
 
+    let ir_premium =
+        FixedI128::from_percentage(read_wind_down_info(env).rate_premium(current_time))
+            .ok_or(Error::AccruedRateMathError)?;
+
     let accrued_rates = calc_accrued_rates(
         s_token_supply,
         debt_token_supply,
         elapsed_time,
         pool_config,
         reserve,
+        ir_premium,
     )
     .ok_or(Error::AccruedRateMathError)?;
 
@@ -41,7 +48,10 @@ pub fn recalculate_reserve_data(
     reserve.lender_ir = accrued_rates.lender_ir.into_inner();
     reserve.last_update_timestamp = current_time;
 
-    write_reserve(env, asset, &reserve);
+    let utilization = FixedI128::from_rational(debt_token_supply, s_token_supply)
+        .ok_or(Error::AccruedRateMathError)?;
+
+    update_reserve(env, asset, &reserve, utilization.into_inner());
 
     Ok(reserve)
 }
@@ -0,0 +1,39 @@
+use common::FixedI128;
+use pool_interface::types::error::Error;
+use pool_interface::types::reserve_data::ReserveData;
+use pool_interface::types::reserve_type::ReserveType;
+use soroban_sdk::{Address, Env};
+
+use crate::event;
+use crate::storage::{read_token_total_supply, write_reserve};
+
+/// Stores the reserve and emits `reserve_updated`, so every write of the reserve data
+/// is seen by the indexers. Utilization is the share of the s-token supply borrowed.
+pub fn update_reserve(env: &Env, asset: &Address, reserve: &ReserveData, utilization: i128) {
+    write_reserve(env, asset, reserve);
+
+    event::reserve_updated(env, asset, reserve, utilization);
+}
+
+/// Utilization of the reserve by the stored token supplies, zero for RWA reserves
+pub fn utilization(env: &Env, reserve: &ReserveData) -> Result<i128, Error> {
+    let (s_token_address, debt_token_address) = match &reserve.reserve_type {
+        ReserveType::Fungible(s_token_address, debt_token_address) => {
+            (s_token_address, debt_token_address)
+        }
+        ReserveType::RWA => return Ok(0),
+    };
+
+    let s_token_supply = read_token_total_supply(env, s_token_address);
+
+    if s_token_supply == 0 {
+        return Ok(0);
+    }
+
+    FixedI128::from_rational(
+        read_token_total_supply(env, debt_token_address),
+        s_token_supply,
+    )
+    .map(FixedI128::into_inner)
+    .ok_or(Error::AccruedRateMathError)
+}
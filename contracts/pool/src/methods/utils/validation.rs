@@ -1,16 +1,25 @@
 use common::FixedI128;
 use common::ONE_DAY;
 use common::PERCENTAGE_FACTOR;
+use debt_token_interface::DebtTokenClient;
 use pool_interface::types::collateral_params_input::CollateralParamsInput;
 use pool_interface::types::error::Error;
+use pool_interface::types::pause_action::PauseAction;
 use pool_interface::types::pause_info::PauseInfo;
 use pool_interface::types::pool_config::PoolConfig;
+use pool_interface::types::price_feed_config_input::PriceFeedConfigInput;
 use pool_interface::types::reserve_data::ReserveData;
 use pool_interface::types::reserve_type::ReserveType;
+use pool_interface::types::role::Role;
 use pool_interface::types::user_config::UserConfiguration;
+use s_token_interface::STokenClient;
 use soroban_sdk::{assert_with_error, panic_with_error, Address, Env};
 
-use crate::storage::{has_admin, read_admin};
+use crate::storage::{
+    has_admin, read_paused_actions, read_price_breaker, read_price_feeds,
+    read_reserve_paused_actions, read_role_holder, read_storage_version, read_timelock_delay,
+    read_wind_down_info, STORAGE_VERSION,
+};
 use crate::types::account_data::AccountData;
 use crate::{read_reserve, read_reserves};
 
@@ -20,12 +29,60 @@ pub fn require_admin_not_exist(env: &Env) {
     }
 }
 
-pub fn require_admin(env: &Env) -> Result<(), Error> {
-    let admin: Address = read_admin(env)?;
-    admin.require_auth();
+pub fn require_migrated(env: &Env) {
+    assert_with_error!(
+        env,
+        read_storage_version(env) == STORAGE_VERSION,
+        Error::MigrationRequired
+    );
+}
+
+/// Returns the version reported by the s-token. Fails if the contract doesn't
+/// report it or the version is out of the range the pool supports.
+pub fn require_compatible_s_token(env: &Env, s_token: &Address) -> u32 {
+    let compatible =
+        s_token_interface::MIN_COMPATIBLE_VERSION..=s_token_interface::MAX_COMPATIBLE_VERSION;
+
+    match STokenClient::new(env, s_token).try_version() {
+        Ok(Ok(version)) if compatible.contains(&version) => version,
+        _ => panic_with_error!(env, Error::InvalidTokenVersion),
+    }
+}
+
+/// Returns the version reported by the debt token. Fails if the contract doesn't
+/// report it or the version is out of the range the pool supports.
+pub fn require_compatible_debt_token(env: &Env, debt_token: &Address) -> u32 {
+    let compatible =
+        debt_token_interface::MIN_COMPATIBLE_VERSION..=debt_token_interface::MAX_COMPATIBLE_VERSION;
+
+    match DebtTokenClient::new(env, debt_token).try_version() {
+        Ok(Ok(version)) if compatible.contains(&version) => version,
+        _ => panic_with_error!(env, Error::InvalidTokenVersion),
+    }
+}
+
+pub fn require_role(env: &Env, role: Role) -> Result<(), Error> {
+    let holder: Address = read_role_holder(env, role)?;
+    holder.require_auth();
+    Ok(())
+}
+
+/// Pausing is allowed to the emergency guardian and the pool admin, unpausing to the pool admin only
+pub fn require_pause_role(env: &Env, who: &Address, pause: bool) -> Result<(), Error> {
+    let is_admin = read_role_holder(env, Role::PoolAdmin)? == *who;
+    let is_guardian = pause && read_role_holder(env, Role::EmergencyGuardian)? == *who;
+
+    assert_with_error!(env, is_admin || is_guardian, Error::InvalidRole);
+
+    who.require_auth();
+
     Ok(())
 }
 
+pub fn require_not_timelocked(env: &Env) {
+    assert_with_error!(env, read_timelock_delay(env) == 0, Error::MustBeScheduled);
+}
+
 pub fn require_valid_collateral_params(env: &Env, params: &CollateralParamsInput) {
     require_lte_percentage_factor(env, params.discount);
     require_lte_percentage_factor(env, params.util_cap);
@@ -68,6 +125,10 @@ pub fn require_active_reserve(env: &Env, reserve: &ReserveData) {
     assert_with_error!(env, reserve.configuration.is_active, Error::NoActiveReserve);
 }
 
+pub fn require_not_frozen_reserve(env: &Env, reserve: &ReserveData) {
+    assert_with_error!(env, !reserve.configuration.is_frozen, Error::ReserveFrozen);
+}
+
 pub fn require_borrowing_enabled(env: &Env, reserve: &ReserveData) {
     assert_with_error!(
         env,
@@ -157,6 +218,54 @@ pub fn require_not_paused(env: &Env, pause_info: &PauseInfo) {
     assert_with_error!(env, !pause_info.paused, Error::Paused);
 }
 
+pub fn require_action_not_paused(env: &Env, action: PauseAction) {
+    assert_with_error!(
+        env,
+        read_paused_actions(env) & action.mask() == 0,
+        Error::Paused
+    );
+}
+
+pub fn require_reserve_action_not_paused(env: &Env, asset: &Address, action: PauseAction) {
+    require_action_not_paused(env, action);
+    assert_with_error!(
+        env,
+        read_reserve_paused_actions(env, asset) & action.mask() == 0,
+        Error::Paused
+    );
+}
+
+pub fn require_price_breaker_not_tripped(env: &Env, asset: &Address) {
+    let breaker = match read_price_breaker(env, asset) {
+        Some(breaker) => breaker,
+        None => return,
+    };
+    let timeout = read_price_feeds(env, asset).map_or(0, |config| config.breaker_timeout);
+
+    assert_with_error!(
+        env,
+        !breaker.is_tripped(env.ledger().timestamp(), timeout),
+        Error::PriceBreakerTripped
+    );
+}
+
+/// Checked for every asset feeding the health check before the prices are read, so a trip
+/// recorded while reading them is not rolled back with the operation
+pub fn require_price_breakers_not_tripped(env: &Env, user_config: &UserConfiguration) {
+    for (i, asset) in read_reserves(env).iter().enumerate() {
+        let is_used = u8::try_from(i)
+            .is_ok_and(|index| user_config.is_using_as_collateral_or_borrowing(env, index));
+
+        if let Some(asset) = asset.filter(|_| is_used) {
+            require_price_breaker_not_tripped(env, &asset);
+        }
+    }
+}
+
+pub fn require_not_wind_down(env: &Env) {
+    assert_with_error!(env, !read_wind_down_info(env).is_active(), Error::WindDown);
+}
+
 pub fn require_not_in_grace_period(env: &Env, pause_info: &PauseInfo) {
     let now = env.ledger().timestamp();
     assert_with_error!(
@@ -195,7 +304,7 @@ pub fn require_unique_liquidation_order(
     asset: &Address,
     pen_order: u32,
 ) -> Result<(), Error> {
-    for r_asset in read_reserves(env) {
+    for r_asset in read_reserves(env).into_iter().flatten() {
         if r_asset.eq(asset) {
             continue;
         }
@@ -270,3 +379,36 @@ pub fn require_valid_pool_config(env: &Env, config: &PoolConfig) {
     );
     assert_with_error!(env, config.user_assets_limit > 0, Error::BellowMinValue);
 }
+
+pub fn require_valid_price_feeds(env: &Env, input: &PriceFeedConfigInput) {
+    require_lte_percentage_factor(env, input.max_feed_deviation);
+
+    assert_with_error!(env, input.min_feeds > 0, Error::BellowMinValue);
+    assert_with_error!(
+        env,
+        input.min_feeds <= input.feeds.len(),
+        Error::ExceededMaxValue
+    );
+    assert_with_error!(
+        env,
+        input.max_price_change == 0 || input.price_change_window > 0,
+        Error::BellowMinValue
+    );
+
+    for feed in input.feeds.iter() {
+        assert_with_error!(env, feed.weight != Some(0), Error::BellowMinValue);
+        assert_with_error!(
+            env,
+            feed.min_twap_span <= feed.twap_window,
+            Error::ExceededMaxValue
+        );
+
+        for leg in feed.path.iter() {
+            assert_with_error!(
+                env,
+                leg.min_twap_span <= leg.twap_window,
+                Error::ExceededMaxValue
+            );
+        }
+    }
+}
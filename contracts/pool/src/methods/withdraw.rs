@@ -4,8 +4,8 @@ use crate::methods::utils::get_collat_coeff::get_lp_amount;
 use crate::methods::utils::validation::require_gte_initial_health;
 use crate::read_pool_config;
 use crate::storage::{
-    read_reserve, read_token_balance, read_token_total_supply, write_token_balance,
-    write_token_total_supply,
+    add_token_total_supply, read_reserve, read_token_balance, read_token_total_supply,
+    read_wind_down_info, write_token_balance, write_token_total_supply,
 };
 use crate::types::calc_account_data_cache::CalcAccountDataCache;
 use crate::types::price_provider::PriceProvider;
@@ -13,15 +13,18 @@ use crate::types::user_configurator::UserConfigurator;
 use crate::{event, read_pause_info};
 use pool_interface::types::asset_balance::AssetBalance;
 use pool_interface::types::error::Error;
+use pool_interface::types::pause_action::PauseAction;
 use pool_interface::types::reserve_type::ReserveType;
+use pool_interface::types::wind_down_info::WindDownStage;
 use s_token_interface::STokenClient;
 use soroban_sdk::{assert_with_error, token, Address, Env};
 
 use super::account_position::calc_account_data;
 use super::utils::recalculate_reserve_data::recalculate_reserve_data;
 use super::utils::validation::{
-    require_active_reserve, require_min_position_amounts, require_not_in_grace_period,
-    require_not_paused, require_positive_amount,
+    require_active_reserve, require_migrated, require_min_position_amounts,
+    require_not_in_grace_period, require_not_paused, require_positive_amount,
+    require_price_breakers_not_tripped, require_reserve_action_not_paused,
 };
 
 pub fn withdraw(
@@ -33,8 +36,11 @@ pub fn withdraw(
 ) -> Result<(), Error> {
     who.require_auth();
 
+    require_migrated(env);
+
     let pause_info = read_pause_info(env);
     require_not_paused(env, &pause_info);
+    require_reserve_action_not_paused(env, asset, PauseAction::Withdraw);
     require_not_in_grace_period(env, &pause_info);
 
     require_positive_amount(env, amount);
@@ -47,6 +53,13 @@ pub fn withdraw(
 
     let withdraw_amount =
         if let ReserveType::Fungible(s_token_address, debt_token_address) = &reserve.reserve_type {
+            // Lenders get their pro rata share through `redeem` at the settlement
+            assert_with_error!(
+                env,
+                read_wind_down_info(env).stage != WindDownStage::Settlement,
+                Error::WindDown
+            );
+
             let s_token_supply = read_token_total_supply(env, s_token_address);
             let debt_token_supply = read_token_total_supply(env, debt_token_address);
 
@@ -103,6 +116,8 @@ pub fn withdraw(
             let is_borrowing_any = user_configurator.user_config()?.is_borrowing_any();
 
             if is_borrowing_any {
+                require_price_breakers_not_tripped(env, user_configurator.user_config()?);
+
                 let account_data = calc_account_data(
                     env,
                     who,
@@ -128,7 +143,9 @@ pub fn withdraw(
                     },
                     &pool_config,
                     user_configurator.user_config()?,
-                    &mut PriceProvider::new(env, &pool_config)?,
+                    &mut PriceProvider::new(env, &pool_config)?
+                        .conservative()
+                        .record_prices(),
                     false,
                 )?;
 
@@ -167,6 +184,8 @@ pub fn withdraw(
             let is_borrowing_any = user_configurator.user_config()?.is_borrowing_any();
 
             if is_borrowing_any {
+                require_price_breakers_not_tripped(env, user_configurator.user_config()?);
+
                 let account_data = calc_account_data(
                     env,
                     who,
@@ -180,7 +199,9 @@ pub fn withdraw(
                     },
                     &pool_config,
                     user_configurator.user_config()?,
-                    &mut PriceProvider::new(env, &pool_config)?,
+                    &mut PriceProvider::new(env, &pool_config)?
+                        .conservative()
+                        .record_prices(),
                     false,
                 )?;
 
@@ -195,6 +216,7 @@ pub fn withdraw(
             );
 
             write_token_balance(env, asset, who, rwa_balance_after)?;
+            add_token_total_supply(env, asset, -withdraw_amount)?;
 
             withdraw_amount
         };
@@ -0,0 +1,77 @@
+use debt_token_interface::DebtTokenClient;
+use pool_interface::types::error::Error;
+use pool_interface::types::role::Role;
+use pool_interface::types::wind_down_info::WindDownStage;
+use soroban_sdk::{assert_with_error, Address, Env};
+
+use crate::event;
+use crate::storage::{
+    read_pool_config, read_reserve, read_token_balance, read_token_total_supply,
+    read_wind_down_info, write_token_balance, write_token_total_supply,
+};
+use crate::types::user_configurator::UserConfigurator;
+
+use super::utils::rate::get_actual_borrower_accrued_rate;
+use super::utils::recalculate_reserve_data::recalculate_reserve_data;
+use super::utils::validation::{require_debt, require_migrated, require_role};
+
+/// Burns the debt of `who` left without collateral, so that the lenders of the reserve
+/// can redeem. Available only at the settlement stage of the wind-down.
+pub fn write_off_debt(env: &Env, who: &Address, asset: &Address) -> Result<(), Error> {
+    require_role(env, Role::PoolAdmin)?;
+
+    require_migrated(env);
+
+    assert_with_error!(
+        env,
+        read_wind_down_info(env).stage == WindDownStage::Settlement,
+        Error::InvalidWindDownStage
+    );
+
+    let reserve = read_reserve(env, asset)?;
+    let (s_token_address, debt_token_address) = reserve.get_fungible()?;
+
+    let mut user_configurator = UserConfigurator::new(env, who, false, None);
+    let user_config = user_configurator.user_config()?;
+    require_debt(env, user_config, reserve.get_id());
+    // Debt backed by collateral is closed by repayment or liquidation
+    assert_with_error!(
+        env,
+        !user_config.is_using_any_as_collateral(),
+        Error::CollateralNotLiquidated
+    );
+
+    let pool_config = read_pool_config(env)?;
+    let s_token_supply = read_token_total_supply(env, s_token_address);
+    let debt_token_supply = read_token_total_supply(env, debt_token_address);
+    let who_debt = read_token_balance(env, debt_token_address, who);
+
+    let debt_coeff = get_actual_borrower_accrued_rate(env, &reserve, &pool_config)?;
+    let written_off = debt_coeff
+        .mul_int(who_debt)
+        .ok_or(Error::MathOverflowError)?;
+
+    let debt_token_supply_after = debt_token_supply
+        .checked_sub(who_debt)
+        .ok_or(Error::MathOverflowError)?;
+
+    DebtTokenClient::new(env, debt_token_address).burn(who, &who_debt);
+
+    write_token_total_supply(env, debt_token_address, debt_token_supply_after)?;
+    write_token_balance(env, debt_token_address, who, 0)?;
+
+    user_configurator.repay(reserve.get_id(), true)?.write();
+
+    recalculate_reserve_data(
+        env,
+        asset,
+        &reserve,
+        &pool_config,
+        s_token_supply,
+        debt_token_supply_after,
+    )?;
+
+    event::debt_written_off(env, who, asset, written_off);
+
+    Ok(())
+}
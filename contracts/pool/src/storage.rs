@@ -1,11 +1,24 @@
 use pool_interface::types::error::Error;
+use pool_interface::types::oracle_asset::OracleAsset;
 use pool_interface::types::pause_info::PauseInfo;
 use pool_interface::types::pool_config::PoolConfig;
+use pool_interface::types::price_breaker::PriceBreaker;
+use pool_interface::types::price_feed::PriceFeed;
 use pool_interface::types::price_feed_config::PriceFeedConfig;
 use pool_interface::types::price_feed_config_input::PriceFeedConfigInput;
+use pool_interface::types::queued_operation::QueuedOperation;
+use pool_interface::types::reserve_configuration::ReserveConfiguration;
 use pool_interface::types::reserve_data::ReserveData;
+use pool_interface::types::reserve_type::ReserveType;
+use pool_interface::types::role::Role;
+use pool_interface::types::timestamp_precision::TimestampPrecision;
 use pool_interface::types::user_config::UserConfiguration;
-use soroban_sdk::{assert_with_error, contracttype, vec, Address, Env, Vec};
+use pool_interface::types::wind_down_info::{WindDownInfo, WindDownStage};
+use price_feed_interface::types::price_data::PriceData;
+use soroban_sdk::{
+    assert_with_error, contracttype, panic_with_error, symbol_short, vec, Address, BytesN, Env,
+    Map, Symbol, TryFromVal, Val, Vec,
+};
 
 pub(crate) const DAY_IN_LEDGERS: u32 = 17_280;
 
@@ -15,6 +28,9 @@ pub(crate) const HIGH_USER_DATA_BUMP_LEDGERS: u32 = 20 * DAY_IN_LEDGERS;
 pub(crate) const LOW_INSTANCE_BUMP_LEDGERS: u32 = DAY_IN_LEDGERS;
 pub(crate) const HIGH_INSTANCE_BUMP_LEDGERS: u32 = 7 * DAY_IN_LEDGERS;
 
+/// Version of the storage layout. Bumped on every change which requires a migration.
+pub(crate) const STORAGE_VERSION: u32 = 3;
+
 #[derive(Clone)]
 #[contracttype]
 pub enum DataKey {
@@ -28,6 +44,152 @@ pub enum DataKey {
     TokenBalance(Address, Address),
     PoolConfig,
     ProtocolFeeVault(Address),
+    Role(Role),
+    PendingAdmin,
+    TimelockDelay,
+    TimelockQueue,
+    TimelockNextId,
+    TimelockOperation(u32),
+    PausedActions,
+    ReservePausedActions(Address),
+    WindDown,
+    StorageVersion,
+    LastGoodPrice(Address),
+    PriceBreaker(Address),
+    ReserveIdHolders(u32),
+}
+
+/// Layout of `UserConfiguration` before the bitmap was extended beyond 64 reserves.
+#[contracttype(export = false)]
+pub struct LegacyUserConfiguration(pub u128, pub u32);
+
+/// Layout of `PauseInfo` before accrual suspension was introduced.
+#[contracttype(export = false)]
+pub struct LegacyPauseInfo {
+    pub paused: bool,
+    pub grace_period_secs: u64,
+    pub unpaused_at: u64,
+}
+
+/// Layout of `ReserveConfiguration` before reserves could be frozen.
+#[contracttype(export = false)]
+pub struct LegacyReserveConfiguration {
+    pub is_active: bool,
+    pub borrowing_enabled: bool,
+    pub liquidity_cap: i128,
+    pub pen_order: u32,
+    pub util_cap: u32,
+    pub discount: u32,
+}
+
+/// Layout of `ReserveData` before reserves could be frozen.
+#[contracttype(export = false)]
+pub struct LegacyReserveData {
+    pub configuration: LegacyReserveConfiguration,
+    pub lender_ar: i128,
+    pub lender_ir: i128,
+    pub borrower_ar: i128,
+    pub borrower_ir: i128,
+    pub last_update_timestamp: u64,
+    pub reserve_type: ReserveType,
+    pub id: BytesN<1>,
+}
+
+/// Layout of `PriceFeed` before price paths were introduced.
+#[derive(Clone)]
+#[contracttype(export = false)]
+pub struct LegacyPriceFeed {
+    pub feed: Address,
+    pub feed_asset: OracleAsset,
+    pub feed_decimals: u32,
+    pub twap_records: u32,
+    pub min_timestamp_delta: u64,
+    pub timestamp_precision: TimestampPrecision,
+}
+
+/// Layout of `PriceFeedConfig` before price fallbacks were introduced.
+#[contracttype(export = false)]
+pub struct LegacyPriceFeedConfig {
+    pub asset_decimals: u32,
+    pub min_sanity_price_in_base: i128,
+    pub max_sanity_price_in_base: i128,
+    pub feeds: Vec<LegacyPriceFeed>,
+}
+
+/// TWAP window in seconds of the migrated feeds which did not report their resolution
+pub const DEFAULT_TWAP_WINDOW: u64 = 3_600;
+
+impl LegacyPriceFeedConfig {
+    /// Converts to the current layout. The feeds were configured by TWAP record count,
+    /// so their window in seconds is derived from the resolutions they reported.
+    pub fn into_config(self, feed_resolutions: Map<Address, u32>) -> PriceFeedConfig {
+        let env = self.feeds.env();
+        let mut feeds = Vec::new(env);
+
+        for feed in self.feeds.iter() {
+            let twap_window =
+                feed_resolutions
+                    .get(feed.feed.clone())
+                    .map_or(DEFAULT_TWAP_WINDOW, |resolution| {
+                        let resolution_in_secs = match feed.timestamp_precision {
+                            TimestampPrecision::Msec => u64::from(resolution) / 1000,
+                            TimestampPrecision::Sec => u64::from(resolution),
+                        };
+
+                        u64::from(feed.twap_records) * resolution_in_secs
+                    });
+
+            feeds.push_back(PriceFeed {
+                feed: feed.feed,
+                feed_asset: feed.feed_asset,
+                feed_decimals: feed.feed_decimals,
+                twap_window,
+                min_twap_span: 0,
+                min_timestamp_delta: feed.min_timestamp_delta,
+                timestamp_precision: feed.timestamp_precision,
+                path: Vec::new(env),
+                weight: None,
+            });
+        }
+
+        PriceFeedConfig {
+            asset_decimals: self.asset_decimals,
+            min_sanity_price_in_base: self.min_sanity_price_in_base,
+            max_sanity_price_in_base: self.max_sanity_price_in_base,
+            feeds,
+            lastprice_fallback: false,
+            stored_price_max_age: 0,
+            max_feed_deviation: 0,
+            min_feeds: 1,
+            max_price_change: 0,
+            price_change_window: 0,
+            breaker_timeout: 0,
+            conservative_pricing: false,
+        }
+    }
+}
+
+impl From<LegacyReserveData> for ReserveData {
+    fn from(legacy: LegacyReserveData) -> Self {
+        Self {
+            configuration: ReserveConfiguration {
+                is_active: legacy.configuration.is_active,
+                is_frozen: false,
+                borrowing_enabled: legacy.configuration.borrowing_enabled,
+                liquidity_cap: legacy.configuration.liquidity_cap,
+                pen_order: legacy.configuration.pen_order,
+                util_cap: legacy.configuration.util_cap,
+                discount: legacy.configuration.discount,
+            },
+            lender_ar: legacy.lender_ar,
+            lender_ir: legacy.lender_ir,
+            borrower_ar: legacy.borrower_ar,
+            borrower_ir: legacy.borrower_ir,
+            last_update_timestamp: legacy.last_update_timestamp,
+            reserve_type: legacy.reserve_type,
+            id: legacy.id,
+        }
+    }
 }
 
 pub fn has_admin(env: &Env) -> bool {
@@ -51,13 +213,105 @@ pub fn read_admin(env: &Env) -> Result<Address, Error> {
         .ok_or(Error::Uninitialized)
 }
 
-pub fn read_reserve(env: &Env, asset: &Address) -> Result<ReserveData, Error> {
+/// Returns the version of the storage layout. Deployments made before
+/// the layout was versioned have no version stored.
+pub fn read_storage_version(env: &Env) -> u32 {
     bump_instance(env);
 
     env.storage()
+        .instance()
+        .get(&DataKey::StorageVersion)
+        .unwrap_or(0)
+}
+
+pub fn write_storage_version(env: &Env, version: u32) {
+    bump_instance(env);
+
+    env.storage()
+        .instance()
+        .set(&DataKey::StorageVersion, &version);
+}
+
+pub fn read_pending_admin(env: &Env) -> Option<Address> {
+    bump_instance(env);
+
+    env.storage().instance().get(&DataKey::PendingAdmin)
+}
+
+pub fn write_pending_admin(env: &Env, pending_admin: &Address) {
+    bump_instance(env);
+
+    env.storage()
+        .instance()
+        .set(&DataKey::PendingAdmin, pending_admin);
+}
+
+pub fn remove_pending_admin(env: &Env) {
+    bump_instance(env);
+
+    env.storage().instance().remove(&DataKey::PendingAdmin);
+}
+
+pub fn read_role(env: &Env, role: Role) -> Option<Address> {
+    bump_instance(env);
+
+    env.storage().instance().get(&DataKey::Role(role))
+}
+
+/// Returns the address holding the role. Roles which were never granted
+/// are held by the pool admin.
+pub fn read_role_holder(env: &Env, role: Role) -> Result<Address, Error> {
+    match role {
+        Role::PoolAdmin => read_admin(env),
+        _ => read_role(env, role).map_or_else(|| read_admin(env), Ok),
+    }
+}
+
+pub fn write_role(env: &Env, role: Role, who: &Address) {
+    bump_instance(env);
+
+    env.storage().instance().set(&DataKey::Role(role), who);
+}
+
+pub fn remove_role(env: &Env, role: Role) {
+    bump_instance(env);
+
+    env.storage().instance().remove(&DataKey::Role(role));
+}
+
+/// Entries in the legacy layout fail to decode until they are migrated
+pub fn read_reserve(env: &Env, asset: &Address) -> Result<ReserveData, Error> {
+    bump_instance(env);
+
+    let reserve: Val = env
+        .storage()
         .instance()
         .get(&DataKey::ReserveAssetKey(asset.clone()))
-        .ok_or(Error::Uninitialized)
+        .ok_or(Error::Uninitialized)?;
+
+    Ok(ReserveData::try_from_val(env, &reserve)
+        .unwrap_or_else(|_| panic_with_error!(env, Error::InvalidStorageVersion)))
+}
+
+/// Entries stored before reserves could be frozen, `None` for the current entries.
+/// They are stored in the current layout on migration.
+pub fn read_legacy_reserve(env: &Env, asset: &Address) -> Option<ReserveData> {
+    bump_instance(env);
+
+    let reserve: Val = env
+        .storage()
+        .instance()
+        .get(&DataKey::ReserveAssetKey(asset.clone()))?;
+
+    // Decoding an entry with a different set of fields traps, so the layout is told by its keys
+    let configuration = Map::<Symbol, Val>::try_from_val(env, &reserve)
+        .ok()?
+        .get(Symbol::new(env, "configuration"))?;
+
+    (!has_field(env, &configuration, symbol_short!("is_frozen")))
+        .then(|| LegacyReserveData::try_from_val(env, &reserve).ok())
+        .flatten()
+        .map(ReserveData::from)
 }
 
 pub fn write_reserve(env: &Env, asset: &Address, reserve_data: &ReserveData) {
@@ -67,7 +321,17 @@ pub fn write_reserve(env: &Env, asset: &Address, reserve_data: &ReserveData) {
     env.storage().instance().set(&asset_key, reserve_data);
 }
 
-pub fn read_reserves(env: &Env) -> Vec<Address> {
+pub fn remove_reserve(env: &Env, asset: &Address) {
+    bump_instance(env);
+
+    env.storage()
+        .instance()
+        .remove(&DataKey::ReserveAssetKey(asset.clone()));
+}
+
+/// Returns the list of reserves indexed by reserve id. Delisted reserves leave
+/// an empty slot which is reused by the next `init_reserve`.
+pub fn read_reserves(env: &Env) -> Vec<Option<Address>> {
     bump_instance(env);
 
     env.storage()
@@ -76,15 +340,35 @@ pub fn read_reserves(env: &Env) -> Vec<Address> {
         .unwrap_or(vec![env])
 }
 
-pub fn write_reserves(env: &Env, reserves: &Vec<Address>) {
+pub fn write_reserves(env: &Env, reserves: &Vec<Option<Address>>) {
     bump_instance(env);
 
     env.storage().instance().set(&DataKey::Reserves, reserves);
 }
 
+/// Returns the number of users holding a collateral or borrowing bit of the reserve id.
+/// Ids listed before the holders were counted have no count stored.
+pub fn read_reserve_id_holders(env: &Env, reserve_id: u8) -> Option<u32> {
+    bump_instance(env);
+
+    env.storage()
+        .instance()
+        .get(&DataKey::ReserveIdHolders(reserve_id.into()))
+}
+
+pub fn write_reserve_id_holders(env: &Env, reserve_id: u8, holders: u32) {
+    bump_instance(env);
+
+    env.storage()
+        .instance()
+        .set(&DataKey::ReserveIdHolders(reserve_id.into()), &holders);
+}
+
+/// Entries in the legacy layout are converted in memory and stored
+/// in the current layout by the next `write_user_config`
 pub fn read_user_config(env: &Env, user: &Address) -> Result<UserConfiguration, Error> {
     let key = DataKey::UserConfig(user.clone());
-    let user_config = env.storage().persistent().get(&key);
+    let user_config: Option<Val> = env.storage().persistent().get(&key);
 
     if user_config.is_some() {
         env.storage().persistent().extend_ttl(
@@ -94,7 +378,29 @@ pub fn read_user_config(env: &Env, user: &Address) -> Result<UserConfiguration,
         );
     }
 
-    user_config.ok_or(Error::Uninitialized)
+    let user_config = user_config.ok_or(Error::Uninitialized)?;
+
+    Ok(UserConfiguration::try_from_val(env, &user_config)
+        .or_else(|_| {
+            LegacyUserConfiguration::try_from_val(env, &user_config)
+                .map(|legacy| UserConfiguration::from_legacy(env, legacy.0, legacy.1))
+        })
+        .unwrap_or_else(|_| panic_with_error!(env, Error::InvalidStorageVersion)))
+}
+
+/// Entries stored before the bitmap was extended beyond 64 reserves,
+/// `None` for the current and missing entries.
+pub fn read_legacy_user_config(env: &Env, user: &Address) -> Option<UserConfiguration> {
+    let user_config: Val = env
+        .storage()
+        .persistent()
+        .get(&DataKey::UserConfig(user.clone()))?;
+
+    UserConfiguration::try_from_val(env, &user_config)
+        .is_err()
+        .then(|| LegacyUserConfiguration::try_from_val(env, &user_config).ok())
+        .flatten()
+        .map(|legacy| UserConfiguration::from_legacy(env, legacy.0, legacy.1))
 }
 
 pub fn write_user_config(env: &Env, user: &Address, config: &UserConfiguration) {
@@ -110,42 +416,150 @@ pub fn write_user_config(env: &Env, user: &Address, config: &UserConfiguration)
 pub fn read_price_feeds(env: &Env, asset: &Address) -> Result<PriceFeedConfig, Error> {
     bump_instance(env);
 
-    let data_key = DataKey::PriceFeed(asset.clone());
-
+    // Entries in the legacy layout fail to decode until they are migrated
     env.storage()
         .instance()
-        .get(&data_key)
+        .get(&DataKey::PriceFeed(asset.clone()))
         .ok_or(Error::Uninitialized)
 }
 
-pub fn write_price_feeds(env: &Env, inputs: &Vec<PriceFeedConfigInput>) {
+/// Entries stored before price fallbacks were introduced, `None` for the current entries.
+/// They are stored in the current layout on migration.
+pub fn read_legacy_price_feeds(env: &Env, asset: &Address) -> Option<LegacyPriceFeedConfig> {
     bump_instance(env);
 
-    for input in inputs.iter() {
-        let data_key = DataKey::PriceFeed(input.asset.clone());
+    let config: Val = env
+        .storage()
+        .instance()
+        .get(&DataKey::PriceFeed(asset.clone()))?;
 
-        env.storage().instance().set(
-            &data_key,
+    (!has_field(env, &config, Symbol::new(env, "lastprice_fallback")))
+        .then(|| LegacyPriceFeedConfig::try_from_val(env, &config).ok())
+        .flatten()
+}
+
+pub fn write_price_feeds(env: &Env, inputs: &Vec<PriceFeedConfigInput>) {
+    for input in inputs.iter() {
+        write_price_feed(
+            env,
+            &input.asset,
             &PriceFeedConfig {
                 asset_decimals: input.asset_decimals,
                 min_sanity_price_in_base: input.min_sanity_price_in_base,
                 max_sanity_price_in_base: input.max_sanity_price_in_base,
                 feeds: input.feeds,
+                lastprice_fallback: input.lastprice_fallback,
+                stored_price_max_age: input.stored_price_max_age,
+                max_feed_deviation: input.max_feed_deviation,
+                min_feeds: input.min_feeds,
+                max_price_change: input.max_price_change,
+                price_change_window: input.price_change_window,
+                breaker_timeout: input.breaker_timeout,
+                conservative_pricing: input.conservative_pricing,
             },
         );
     }
 }
 
-pub fn read_pause_info(env: &Env) -> PauseInfo {
+pub fn write_price_feed(env: &Env, asset: &Address, config: &PriceFeedConfig) {
+    bump_instance(env);
+
+    env.storage()
+        .instance()
+        .set(&DataKey::PriceFeed(asset.clone()), config);
+}
+
+pub fn remove_price_feeds(env: &Env, asset: &Address) {
+    bump_instance(env);
+
+    env.storage()
+        .instance()
+        .remove(&DataKey::PriceFeed(asset.clone()));
+    env.storage()
+        .instance()
+        .remove(&DataKey::LastGoodPrice(asset.clone()));
+    env.storage()
+        .instance()
+        .remove(&DataKey::PriceBreaker(asset.clone()));
+}
+
+/// Returns the last price accepted from the feeds in the base asset
+/// and the ledger timestamp it was accepted at
+pub fn read_last_good_price(env: &Env, asset: &Address) -> Option<PriceData> {
     bump_instance(env);
 
     env.storage()
         .instance()
-        .get(&DataKey::Pause)
-        .unwrap_or(PauseInfo {
+        .get(&DataKey::LastGoodPrice(asset.clone()))
+}
+
+pub fn write_last_good_price(env: &Env, asset: &Address, price: &PriceData) {
+    bump_instance(env);
+
+    env.storage()
+        .instance()
+        .set(&DataKey::LastGoodPrice(asset.clone()), price);
+}
+
+pub fn read_price_breaker(env: &Env, asset: &Address) -> Option<PriceBreaker> {
+    bump_instance(env);
+
+    env.storage()
+        .instance()
+        .get(&DataKey::PriceBreaker(asset.clone()))
+}
+
+pub fn write_price_breaker(env: &Env, asset: &Address, breaker: &PriceBreaker) {
+    bump_instance(env);
+
+    env.storage()
+        .instance()
+        .set(&DataKey::PriceBreaker(asset.clone()), breaker);
+}
+
+pub fn remove_price_breaker(env: &Env, asset: &Address) {
+    bump_instance(env);
+
+    env.storage()
+        .instance()
+        .remove(&DataKey::PriceBreaker(asset.clone()));
+}
+
+/// The entry in the legacy layout fails to decode until it is migrated
+pub fn read_pause_info(env: &Env) -> PauseInfo {
+    bump_instance(env);
+
+    let pause_info: Option<Val> = env.storage().instance().get(&DataKey::Pause);
+
+    match pause_info {
+        Some(pause_info) => PauseInfo::try_from_val(env, &pause_info)
+            .unwrap_or_else(|_| panic_with_error!(env, Error::InvalidStorageVersion)),
+        None => PauseInfo {
             paused: false,
             grace_period_secs: 0,
             unpaused_at: 0,
+            paused_at: 0,
+            suspend_accrual: false,
+        },
+    }
+}
+
+/// Entry stored before accrual suspension was introduced, `None` for the current entry.
+/// It is stored in the current layout on migration.
+pub fn read_legacy_pause_info(env: &Env) -> Option<PauseInfo> {
+    bump_instance(env);
+
+    let pause_info: Val = env.storage().instance().get(&DataKey::Pause)?;
+
+    (!has_field(env, &pause_info, symbol_short!("paused_at")))
+        .then(|| LegacyPauseInfo::try_from_val(env, &pause_info).ok())
+        .flatten()
+        .map(|legacy| PauseInfo {
+            paused: legacy.paused,
+            grace_period_secs: legacy.grace_period_secs,
+            unpaused_at: legacy.unpaused_at,
+            paused_at: 0,
+            suspend_accrual: false,
         })
 }
 
@@ -155,6 +569,68 @@ pub fn write_pause_info(env: &Env, value: PauseInfo) {
     env.storage().instance().set(&DataKey::Pause, &value);
 }
 
+/// Whether the entry stored as a map has the field, used to tell the legacy layouts apart
+fn has_field(env: &Env, entry: &Val, field: Symbol) -> bool {
+    Map::<Symbol, Val>::try_from_val(env, entry).is_ok_and(|entry| entry.contains_key(field))
+}
+
+pub fn read_wind_down_info(env: &Env) -> WindDownInfo {
+    bump_instance(env);
+
+    env.storage()
+        .instance()
+        .get(&DataKey::WindDown)
+        .unwrap_or(WindDownInfo {
+            stage: WindDownStage::Inactive,
+            started_at: 0,
+            rate_step: 0,
+            step_secs: 0,
+            settled_at: 0,
+        })
+}
+
+pub fn write_wind_down_info(env: &Env, value: &WindDownInfo) {
+    bump_instance(env);
+
+    env.storage().instance().set(&DataKey::WindDown, value);
+}
+
+pub fn read_paused_actions(env: &Env) -> u32 {
+    bump_instance(env);
+
+    env.storage()
+        .instance()
+        .get(&DataKey::PausedActions)
+        .unwrap_or(0)
+}
+
+pub fn write_paused_actions(env: &Env, mask: u32) {
+    bump_instance(env);
+
+    env.storage().instance().set(&DataKey::PausedActions, &mask);
+}
+
+pub fn read_reserve_paused_actions(env: &Env, asset: &Address) -> u32 {
+    bump_instance(env);
+
+    env.storage()
+        .instance()
+        .get(&DataKey::ReservePausedActions(asset.clone()))
+        .unwrap_or(0)
+}
+
+pub fn write_reserve_paused_actions(env: &Env, asset: &Address, mask: u32) {
+    bump_instance(env);
+
+    let key = DataKey::ReservePausedActions(asset.clone());
+
+    if mask == 0 {
+        env.storage().instance().remove(&key);
+    } else {
+        env.storage().instance().set(&key, &mask);
+    }
+}
+
 pub fn add_token_balance(
     env: &Env,
     token: &Address,
@@ -194,6 +670,24 @@ pub fn write_token_total_supply(
     Ok(())
 }
 
+pub fn add_token_total_supply(env: &Env, token: &Address, amount: i128) -> Result<i128, Error> {
+    let total_supply = read_token_total_supply(env, token)
+        .checked_add(amount)
+        .ok_or(Error::MathOverflowError)?;
+
+    write_token_total_supply(env, token, total_supply)?;
+
+    Ok(total_supply)
+}
+
+pub fn remove_token_total_supply(env: &Env, token: &Address) {
+    bump_instance(env);
+
+    env.storage()
+        .instance()
+        .remove(&DataKey::TokenSupply(token.clone()));
+}
+
 pub fn read_token_balance(env: &Env, token: &Address, account: &Address) -> i128 {
     let key = DataKey::TokenBalance(token.clone(), account.clone());
     let balance = env.storage().persistent().get(&key);
@@ -228,6 +722,12 @@ pub fn write_token_balance(
     Ok(())
 }
 
+pub fn remove_token_balance(env: &Env, token: &Address, account: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::TokenBalance(token.clone(), account.clone()));
+}
+
 pub fn read_protocol_fee_vault(env: &Env, asset: &Address) -> i128 {
     bump_instance(env);
 
@@ -256,6 +756,109 @@ pub fn add_protocol_fee_vault(env: &Env, asset: &Address, amount: i128) -> Resul
     Ok(())
 }
 
+pub fn remove_protocol_fee_vault(env: &Env, asset: &Address) {
+    bump_instance(env);
+
+    env.storage()
+        .instance()
+        .remove(&DataKey::ProtocolFeeVault(asset.clone()));
+}
+
+pub fn read_timelock_delay(env: &Env) -> u64 {
+    bump_instance(env);
+
+    env.storage()
+        .instance()
+        .get(&DataKey::TimelockDelay)
+        .unwrap_or(0)
+}
+
+pub fn write_timelock_delay(env: &Env, delay: u64) {
+    bump_instance(env);
+
+    env.storage()
+        .instance()
+        .set(&DataKey::TimelockDelay, &delay);
+}
+
+/// Returns the ids of the queued operations in the order they were scheduled
+pub fn read_timelock_queue(env: &Env) -> Vec<u32> {
+    bump_instance(env);
+
+    env.storage()
+        .instance()
+        .get(&DataKey::TimelockQueue)
+        .unwrap_or(vec![env])
+}
+
+fn write_timelock_queue(env: &Env, queue: &Vec<u32>) {
+    bump_instance(env);
+
+    env.storage().instance().set(&DataKey::TimelockQueue, queue);
+}
+
+pub fn read_queued_operation(env: &Env, id: u32) -> Option<QueuedOperation> {
+    let key = DataKey::TimelockOperation(id);
+    let operation = env.storage().persistent().get(&key);
+
+    if operation.is_some() {
+        env.storage().persistent().extend_ttl(
+            &key,
+            LOW_USER_DATA_BUMP_LEDGERS,
+            HIGH_USER_DATA_BUMP_LEDGERS,
+        );
+    }
+
+    operation
+}
+
+/// Operations are kept in persistent storage so that their payloads are not
+/// loaded with the instance storage on every pool invocation.
+pub fn write_queued_operation(env: &Env, operation: &QueuedOperation) {
+    let key = DataKey::TimelockOperation(operation.id);
+
+    env.storage().persistent().set(&key, operation);
+    env.storage().persistent().extend_ttl(
+        &key,
+        LOW_USER_DATA_BUMP_LEDGERS,
+        HIGH_USER_DATA_BUMP_LEDGERS,
+    );
+
+    let mut queue = read_timelock_queue(env);
+    queue.push_back(operation.id);
+    write_timelock_queue(env, &queue);
+}
+
+pub fn remove_queued_operation(env: &Env, id: u32) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::TimelockOperation(id));
+
+    let mut queue = read_timelock_queue(env);
+
+    if let Some(index) = queue.first_index_of(id) {
+        queue.remove(index);
+    }
+
+    write_timelock_queue(env, &queue);
+}
+
+pub fn next_timelock_id(env: &Env) -> u32 {
+    bump_instance(env);
+
+    let id: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey::TimelockNextId)
+        .unwrap_or(0);
+
+    env.storage()
+        .instance()
+        .set(&DataKey::TimelockNextId, &(id + 1));
+
+    id
+}
+
 pub fn write_pool_config(env: &Env, config: &PoolConfig) {
     bump_instance(env);
 
@@ -0,0 +1,102 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::tests::sut::init_pool;
+use crate::*;
+use pool_interface::types::role::Role;
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events};
+use soroban_sdk::{vec, IntoVal, Symbol};
+
+#[test]
+fn should_require_pending_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let new_admin = Address::generate(&env);
+
+    sut.pool.propose_admin(&new_admin);
+    sut.pool.accept_admin();
+
+    assert_eq!(
+        env.auths(),
+        [(
+            new_admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    sut.pool.address.clone(),
+                    Symbol::new(&env, "accept_admin"),
+                    ().into_val(&env)
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn should_fail_when_no_pending_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    sut.pool.accept_admin();
+}
+
+#[test]
+fn should_change_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let new_admin = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    sut.pool.propose_admin(&new_admin);
+    sut.pool.accept_admin();
+
+    assert_eq!(sut.pool.role(&Role::PoolAdmin), new_admin);
+    assert!(sut.pool.pending_admin().is_none());
+
+    sut.pool.set_reserve_status(&sut.token().address, &true);
+    assert_eq!(env.auths().first().unwrap().0, new_admin);
+
+    // tokens verify the pool only, so they keep working after the change
+    sut.token_admin().mint(&user, &1_000_000);
+    sut.pool.deposit(&user, &sut.token().address, &1_000_000);
+
+    assert_eq!(sut.s_token().balance(&user), 1_000_000);
+}
+
+#[test]
+fn should_emit_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let new_admin = Address::generate(&env);
+
+    sut.pool.propose_admin(&new_admin);
+    sut.pool.accept_admin();
+
+    let event = env.events().all().pop_back_unchecked();
+
+    assert_eq!(
+        vec![&env, event],
+        vec![
+            &env,
+            (
+                sut.pool.address.clone(),
+                (
+                    Symbol::new(&env, "admin_changed"),
+                    1u32,
+                    sut.pool_admin.clone()
+                )
+                    .into_val(&env),
+                new_admin.into_val(&env)
+            ),
+        ]
+    );
+}
@@ -1,5 +1,6 @@
 use super::sut::DAY;
 use crate::tests::sut::{fill_pool, init_pool, set_time};
+use common::FixedI128;
 use pool_interface::types::pool_config::PoolConfig;
 use soroban_sdk::testutils::{Address as _, AuthorizedFunction, Events};
 use soroban_sdk::{symbol_short, vec, Address, Env, IntoVal, Symbol};
@@ -35,7 +36,7 @@ fn should_fail_when_pool_paused() {
     let (_, borrower, debt_config) = fill_pool(&env, &sut, false);
     let token_address = debt_config.token.address.clone();
 
-    sut.pool.set_pause(&true);
+    sut.pool.set_pause(&sut.pool_admin, &true);
     sut.pool.borrow(&borrower, &token_address, &10_000_000);
 }
 
@@ -360,7 +361,34 @@ fn should_emit_events() {
     sut.pool.borrow(&borrower, &token_address, &20_000_000);
 
     let mut events = env.events().all();
+    let reserve_updated = events.pop_back_unchecked();
     let event = events.pop_back_unchecked();
+    let reserve = sut.pool.get_reserve(&token_address).unwrap();
+
+    assert_eq!(
+        vec![&env, reserve_updated],
+        vec![
+            &env,
+            (
+                sut.pool.address.clone(),
+                (Symbol::new(&env, "reserve_updated"), token_address.clone()).into_val(&env),
+                (
+                    reserve.lender_ar,
+                    reserve.borrower_ar,
+                    reserve.lender_ir,
+                    reserve.borrower_ir,
+                    FixedI128::from_rational(
+                        debt_config.debt_token().total_supply(),
+                        debt_config.s_token().total_supply()
+                    )
+                    .unwrap()
+                    .into_inner(),
+                    reserve.last_update_timestamp
+                )
+                    .into_val(&env)
+            ),
+        ]
+    );
 
     assert_eq!(
         vec![&env, event],
@@ -468,8 +496,8 @@ fn should_fail_in_grace_period() {
     let (_, borrower, debt_reserve) = fill_pool(&env, &sut, true);
     sut.pool.borrow(&borrower, &debt_reserve.token.address, &1);
 
-    sut.pool.set_pause(&true);
-    sut.pool.set_pause(&false);
+    sut.pool.set_pause(&sut.pool_admin, &true);
+    sut.pool.set_pause(&sut.pool_admin, &false);
     sut.pool.borrow(&borrower, &debt_reserve.token.address, &1);
 }
 
@@ -489,9 +517,9 @@ fn should_not_fail_after_grace_period() {
     let debt_token_after = debt_reserve.debt_token().balance(&borrower);
     assert!(debt_token_after > debt_token_before);
 
-    sut.pool.set_pause(&true);
+    sut.pool.set_pause(&sut.pool_admin, &true);
     set_time(&env, &sut, start + gap, false);
-    sut.pool.set_pause(&false);
+    sut.pool.set_pause(&sut.pool_admin, &false);
     set_time(
         &env,
         &sut,
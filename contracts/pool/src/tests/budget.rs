@@ -363,7 +363,7 @@ fn set_pause() {
     let sut = init_pool(&env, true);
 
     measure_budget(&env, function_name!(), || {
-        sut.pool.set_pause(&true);
+        sut.pool.set_pause(&sut.pool_admin, &true);
     });
 }
 
@@ -388,15 +388,26 @@ fn set_price_feed() {
                 asset_decimals: 7,
                 min_sanity_price_in_base: 5_000_000,
                 max_sanity_price_in_base: 50_000_000_000,
+                lastprice_fallback: false,
+                stored_price_max_age: 0,
+                max_feed_deviation: 0,
+                min_feeds: 1,
+                max_price_change: 0,
+                price_change_window: 0,
+                breaker_timeout: 0,
+                conservative_pricing: false,
                 feeds: vec![
                     &env,
                     PriceFeed {
                         feed: price_feed.address.clone(),
                         feed_asset: OracleAsset::Stellar(asset_1),
                         feed_decimals: 14,
-                        twap_records: 10,
+                        twap_window: 3600,
+                        min_twap_span: 0,
                         min_timestamp_delta: 100,
                         timestamp_precision: TimestampPrecision::Sec,
+                        path: vec![&env],
+                        weight: None,
                     },
                 ],
             },
@@ -405,15 +416,26 @@ fn set_price_feed() {
                 asset_decimals: 9,
                 min_sanity_price_in_base: 5_000_000,
                 max_sanity_price_in_base: 50_000_000_000,
+                lastprice_fallback: false,
+                stored_price_max_age: 0,
+                max_feed_deviation: 0,
+                min_feeds: 1,
+                max_price_change: 0,
+                price_change_window: 0,
+                breaker_timeout: 0,
+                conservative_pricing: false,
                 feeds: vec![
                     &env,
                     PriceFeed {
                         feed: price_feed.address.clone(),
                         feed_asset: OracleAsset::Stellar(asset_2),
                         feed_decimals: 16,
-                        twap_records: 10,
+                        twap_window: 3600,
+                        min_twap_span: 0,
                         min_timestamp_delta: 100,
                         timestamp_precision: TimestampPrecision::Sec,
+                        path: vec![&env],
+                        weight: None,
                     },
                 ],
             },
@@ -422,15 +444,26 @@ fn set_price_feed() {
                 asset_decimals: 9,
                 min_sanity_price_in_base: 5_000_000,
                 max_sanity_price_in_base: 50_000_000_000,
+                lastprice_fallback: false,
+                stored_price_max_age: 0,
+                max_feed_deviation: 0,
+                min_feeds: 1,
+                max_price_change: 0,
+                price_change_window: 0,
+                breaker_timeout: 0,
+                conservative_pricing: false,
                 feeds: vec![
                     &env,
                     PriceFeed {
                         feed: price_feed.address.clone(),
                         feed_asset: OracleAsset::Stellar(asset_3),
                         feed_decimals: 16,
-                        twap_records: 10,
+                        twap_window: 3600,
+                        min_twap_span: 0,
                         min_timestamp_delta: 100,
                         timestamp_precision: TimestampPrecision::Sec,
+                        path: vec![&env],
+                        weight: None,
                     },
                 ],
             },
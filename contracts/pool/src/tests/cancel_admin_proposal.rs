@@ -0,0 +1,91 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::tests::sut::init_pool;
+use crate::*;
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events};
+use soroban_sdk::{vec, IntoVal, Symbol};
+
+#[test]
+fn should_require_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    sut.pool.propose_admin(&Address::generate(&env));
+    sut.pool.cancel_admin_proposal();
+
+    assert_eq!(
+        env.auths(),
+        [(
+            sut.pool_admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    sut.pool.address.clone(),
+                    Symbol::new(&env, "cancel_admin_proposal"),
+                    ().into_val(&env)
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn should_fail_when_no_pending_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    sut.pool.cancel_admin_proposal();
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn should_not_allow_to_accept_cancelled_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    sut.pool.propose_admin(&Address::generate(&env));
+    sut.pool.cancel_admin_proposal();
+
+    assert!(sut.pool.pending_admin().is_none());
+
+    sut.pool.accept_admin();
+}
+
+#[test]
+fn should_emit_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let new_admin = Address::generate(&env);
+
+    sut.pool.propose_admin(&new_admin);
+    sut.pool.cancel_admin_proposal();
+
+    let event = env.events().all().pop_back_unchecked();
+
+    assert_eq!(
+        vec![&env, event],
+        vec![
+            &env,
+            (
+                sut.pool.address.clone(),
+                (
+                    Symbol::new(&env, "admin_proposal_cancelled"),
+                    1u32,
+                    sut.pool_admin.clone()
+                )
+                    .into_val(&env),
+                new_admin.into_val(&env)
+            ),
+        ]
+    );
+}
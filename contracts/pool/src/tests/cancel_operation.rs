@@ -0,0 +1,79 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::tests::sut::init_pool;
+use crate::*;
+use pool_interface::types::role::Role;
+use pool_interface::types::timelock_operation::TimelockOperation;
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{vec, IntoVal, Symbol};
+
+#[test]
+fn should_require_operation_role() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let risk_admin = Address::generate(&env);
+    let eta = env.ledger().timestamp();
+
+    sut.pool.grant_role(&Role::RiskAdmin, &risk_admin);
+
+    let id = sut.pool.schedule_operation(
+        &TimelockOperation::SetPoolConfiguration(sut.pool.pool_configuration()),
+        &eta,
+    );
+
+    sut.pool.cancel_operation(&id);
+
+    assert_eq!(env.auths().first().unwrap().0, risk_admin);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn should_remove_operation_from_queue() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let eta = env.ledger().timestamp();
+
+    let id = sut
+        .pool
+        .schedule_operation(&TimelockOperation::SetTimelockDelay(60), &eta);
+
+    sut.pool.cancel_operation(&id);
+
+    assert!(sut.pool.queued_operations().is_empty());
+
+    sut.pool.execute_operation(&id);
+}
+
+#[test]
+fn should_emit_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let eta = env.ledger().timestamp();
+
+    let id = sut
+        .pool
+        .schedule_operation(&TimelockOperation::SetTimelockDelay(60), &eta);
+
+    sut.pool.cancel_operation(&id);
+
+    let event = env.events().all().pop_back_unchecked();
+
+    assert_eq!(
+        vec![&env, event],
+        vec![
+            &env,
+            (
+                sut.pool.address.clone(),
+                (Symbol::new(&env, "operation_cancelled"), 1u32, id).into_val(&env),
+                ().into_val(&env)
+            ),
+        ]
+    );
+}
@@ -142,6 +142,8 @@ fn should_emit_events() {
         pen_order: 1,
     };
 
+    let prev_config = sut.pool.get_reserve(&asset_address).unwrap().configuration;
+
     assert_eq!(
         sut.pool
             .configure_as_collateral(&asset_address.clone(), &params.clone()),
@@ -156,8 +158,17 @@ fn should_emit_events() {
             &env,
             (
                 sut.pool.address.clone(),
-                (Symbol::new(&env, "collat_config_change"), &asset_address).into_val(&env),
                 (
+                    Symbol::new(&env, "collat_config_change"),
+                    1u32,
+                    &asset_address
+                )
+                    .into_val(&env),
+                (
+                    prev_config.liquidity_cap,
+                    prev_config.pen_order,
+                    prev_config.util_cap,
+                    prev_config.discount,
                     params.liq_cap,
                     params.pen_order,
                     params.util_cap,
@@ -0,0 +1,158 @@
+#![cfg(test)]
+extern crate std;
+
+use pool_interface::types::price_feed_config_input::PriceFeedConfigInput;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env};
+
+use crate::tests::sut::{default_price_feed_config, fill_pool, init_pool, set_lastprice, Sut};
+
+fn configure_pricing(env: &Env, sut: &Sut, conservative_pricing: bool) -> Address {
+    let asset = sut.reserves[1].token.address.clone();
+
+    sut.pool.set_price_feeds(&vec![
+        env,
+        PriceFeedConfigInput {
+            conservative_pricing,
+            ..default_price_feed_config(env, sut)
+        },
+    ]);
+
+    asset
+}
+
+/// Deposits the asset priced by the feed as collateral of a new user
+fn deposit_collateral(env: &Env, sut: &Sut, asset: &Address) -> Address {
+    let user = Address::generate(env);
+
+    sut.reserves[1].token_admin.mint(&user, &100_000_000);
+    sut.pool.deposit(&user, asset, &100_000_000);
+
+    user
+}
+
+#[test]
+fn should_use_twap_when_disabled() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, borrower, _) = fill_pool(&env, &sut, false);
+    let asset = configure_pricing(&env, &sut, false);
+
+    set_lastprice(&env, &sut, &asset, 20_000_000_000_000_000);
+    sut.pool.borrow(&borrower, &asset, &40_000_000);
+
+    assert_eq!(sut.pool.account_position(&borrower).debt, 400_045);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #3)")]
+fn should_value_debt_at_higher_spot_price() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, borrower, _) = fill_pool(&env, &sut, false);
+    let asset = configure_pricing(&env, &sut, true);
+
+    set_lastprice(&env, &sut, &asset, 20_000_000_000_000_000);
+    sut.pool.borrow(&borrower, &asset, &40_000_000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #3)")]
+fn should_value_debt_at_higher_twap() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, borrower, _) = fill_pool(&env, &sut, false);
+    let asset = configure_pricing(&env, &sut, true);
+
+    set_lastprice(&env, &sut, &asset, 5_000_000_000_000_000);
+    sut.pool.borrow(&borrower, &asset, &70_000_000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #3)")]
+fn should_value_collateral_at_lower_spot_price() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    fill_pool(&env, &sut, false);
+    let asset = configure_pricing(&env, &sut, true);
+    let user = deposit_collateral(&env, &sut, &asset);
+
+    set_lastprice(&env, &sut, &asset, 5_000_000_000_000_000);
+    sut.pool
+        .borrow(&user, &sut.reserves[0].token.address, &500_000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #3)")]
+fn should_value_collateral_at_lower_twap() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    fill_pool(&env, &sut, false);
+    let asset = configure_pricing(&env, &sut, true);
+    let user = deposit_collateral(&env, &sut, &asset);
+
+    set_lastprice(&env, &sut, &asset, 20_000_000_000_000_000);
+    sut.pool
+        .borrow(&user, &sut.reserves[0].token.address, &700_000);
+}
+
+#[test]
+fn should_borrow_within_both_prices() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    fill_pool(&env, &sut, false);
+    let asset = configure_pricing(&env, &sut, true);
+    let user = deposit_collateral(&env, &sut, &asset);
+
+    set_lastprice(&env, &sut, &asset, 9_000_000_000_000_000);
+    sut.pool
+        .borrow(&user, &sut.reserves[0].token.address, &500_000);
+
+    assert_eq!(sut.pool.account_position(&user).debt, 500_001);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #3)")]
+fn should_check_withdrawals() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, borrower, _) = fill_pool(&env, &sut, true);
+    let asset = configure_pricing(&env, &sut, true);
+
+    set_lastprice(&env, &sut, &asset, 20_000_000_000_000_000);
+    sut.pool.withdraw(
+        &borrower,
+        &sut.reserves[0].token.address,
+        &100_000,
+        &borrower,
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #301)")]
+fn should_keep_twap_for_liquidation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, borrower, _) = fill_pool(&env, &sut, true);
+    let asset = configure_pricing(&env, &sut, true);
+    let liquidator = Address::generate(&env);
+
+    set_lastprice(&env, &sut, &asset, 20_000_000_000_000_000);
+    sut.pool.liquidate(&liquidator, &borrower);
+}
@@ -0,0 +1,249 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::storage::DataKey;
+use crate::tests::sut::{create_token_contract, fill_pool, init_pool};
+use crate::*;
+use pool_interface::types::reserve_type::ReserveType;
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events};
+use soroban_sdk::{vec, IntoVal, Symbol};
+
+#[test]
+fn should_require_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = sut.reserves[2].token.address.clone();
+
+    sut.pool.delist_reserve(&asset);
+
+    assert_eq!(
+        env.auths(),
+        [(
+            sut.pool_admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    sut.pool.address.clone(),
+                    Symbol::new(&env, "delist_reserve"),
+                    (asset.clone(),).into_val(&env)
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn should_fail_when_reserve_not_listed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = sut.reserves[2].token.address.clone();
+
+    sut.pool.delist_reserve(&asset);
+    sut.pool.delist_reserve(&asset);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #106)")]
+fn should_fail_when_reserve_has_supply() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    fill_pool(&env, &sut, false);
+
+    sut.pool.delist_reserve(&sut.reserves[2].token.address);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #106)")]
+fn should_fail_when_reserve_has_debt() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, _, debt_config) = fill_pool(&env, &sut, true);
+
+    sut.pool.delist_reserve(&debt_config.token.address);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #106)")]
+fn should_fail_when_rwa_reserve_has_deposits() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let user = Address::generate(&env);
+    let rwa_address = sut.rwa_config().token.address.clone();
+
+    sut.rwa_config().token_admin.mint(&user, &1_000_000_000);
+    sut.pool.deposit(&user, &rwa_address, &1_000_000_000);
+
+    sut.pool.delist_reserve(&rwa_address);
+}
+
+#[test]
+fn should_ignore_tokens_sent_to_rwa_reserve() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let user = Address::generate(&env);
+    let rwa_address = sut.rwa_config().token.address.clone();
+
+    sut.rwa_config().token_admin.mint(&user, &2_000_000_000);
+    sut.pool.deposit(&user, &rwa_address, &1_000_000_000);
+    sut.pool.withdraw(&user, &rwa_address, &i128::MAX, &user);
+
+    assert_eq!(sut.pool.token_total_supply(&rwa_address), 0);
+
+    sut.rwa_config()
+        .token
+        .transfer(&user, &sut.pool.address, &1_000_000_000);
+
+    sut.pool.delist_reserve(&rwa_address);
+
+    assert!(sut.pool.get_reserve(&rwa_address).is_none());
+}
+
+#[test]
+fn should_remove_token_accounting() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let lender = Address::generate(&env);
+    let asset = sut.reserves[2].token.address.clone();
+    let s_token_address = sut.reserves[2].s_token().address.clone();
+
+    sut.reserves[2].token_admin.mint(&lender, &1_000_000_000);
+    sut.pool.deposit(&lender, &asset, &1_000_000_000);
+    sut.pool.withdraw(&lender, &asset, &i128::MAX, &lender);
+
+    sut.pool.delist_reserve(&asset);
+
+    env.as_contract(&sut.pool.address, || {
+        assert!(!env
+            .storage()
+            .instance()
+            .has(&DataKey::TokenSupply(s_token_address.clone())));
+        assert!(!env.storage().persistent().has(&DataKey::TokenBalance(
+            asset.clone(),
+            s_token_address.clone()
+        )));
+        assert!(!env
+            .storage()
+            .instance()
+            .has(&DataKey::ProtocolFeeVault(asset.clone())));
+    });
+}
+
+#[test]
+fn should_remove_reserve_and_price_feeds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = sut.reserves[2].token.address.clone();
+
+    sut.pool.delist_reserve(&asset);
+
+    assert!(sut.pool.get_reserve(&asset).is_none());
+    assert!(sut.pool.price_feeds(&asset).is_none());
+    assert!(sut
+        .pool
+        .get_reserve(&sut.reserves[1].token.address)
+        .is_some());
+}
+
+#[test]
+fn should_reuse_freed_id() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let delisted = sut.reserves[1].token.address.clone();
+    let delisted_id = sut.pool.get_reserve(&delisted).unwrap().get_id();
+
+    sut.pool.delist_reserve(&delisted);
+
+    let (first_token, _) = create_token_contract(&env, &sut.token_admin);
+    let (second_token, _) = create_token_contract(&env, &sut.token_admin);
+
+    sut.pool
+        .init_reserve(&first_token.address, &ReserveType::RWA);
+    sut.pool
+        .init_reserve(&second_token.address, &ReserveType::RWA);
+
+    let first_reserve = sut.pool.get_reserve(&first_token.address).unwrap();
+    let second_reserve = sut.pool.get_reserve(&second_token.address).unwrap();
+
+    assert_eq!(first_reserve.get_id(), delisted_id);
+    assert_eq!(second_reserve.get_id(), 4);
+
+    sut.pool.configure_as_collateral(
+        &first_token.address,
+        &CollateralParamsInput {
+            liq_cap: 100_000_000,
+            pen_order: 2,
+            util_cap: 9000,
+            discount: 6000,
+        },
+    );
+}
+
+#[test]
+fn should_not_reuse_id_held_by_users() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let user = Address::generate(&env);
+    let delisted = sut.reserves[2].token.address.clone();
+    let delisted_id = sut.pool.get_reserve(&delisted).unwrap().get_id();
+
+    sut.reserves[0].token_admin.mint(&user, &1_000_000_000);
+    sut.pool
+        .deposit(&user, &sut.reserves[0].token.address, &1_000_000_000);
+    sut.pool.set_as_collateral(&user, &delisted, &true);
+
+    sut.pool.delist_reserve(&delisted);
+
+    let (token, _) = create_token_contract(&env, &sut.token_admin);
+    sut.pool.init_reserve(&token.address, &ReserveType::RWA);
+
+    let reserve = sut.pool.get_reserve(&token.address).unwrap();
+
+    assert_ne!(reserve.get_id(), delisted_id);
+    assert_eq!(reserve.get_id(), 4);
+}
+
+#[test]
+fn should_emit_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = sut.reserves[2].token.address.clone();
+
+    sut.pool.delist_reserve(&asset);
+
+    let event = env.events().all().pop_back_unchecked();
+
+    assert_eq!(
+        vec![&env, event],
+        vec![
+            &env,
+            (
+                sut.pool.address.clone(),
+                (Symbol::new(&env, "reserve_delisted"), 1u32, asset.clone()).into_val(&env),
+                ().into_val(&env)
+            ),
+        ]
+    );
+}
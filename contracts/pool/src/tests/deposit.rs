@@ -36,7 +36,7 @@ fn should_fail_when_pool_paused() {
     let sut = init_pool(&env, false);
     let token_address = sut.token().address.clone();
 
-    sut.pool.set_pause(&true);
+    sut.pool.set_pause(&sut.pool_admin, &true);
     sut.pool.deposit(&user, &token_address, &1);
 }
 
@@ -365,8 +365,8 @@ fn should_not_fail_in_grace_period() {
 
     sut.pool.deposit(&user, &token_address, &3_000_000_000);
 
-    sut.pool.set_pause(&true);
-    sut.pool.set_pause(&false);
+    sut.pool.set_pause(&sut.pool_admin, &true);
+    sut.pool.set_pause(&sut.pool_admin, &false);
 
     sut.pool.deposit(&user, &token_address, &3_000_000_000);
 
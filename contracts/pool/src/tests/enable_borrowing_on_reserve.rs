@@ -84,7 +84,7 @@ fn should_emit_events() {
             &env,
             (
                 sut.pool.address.clone(),
-                (Symbol::new(&env, "borrowing_enabled"), &asset).into_val(&env),
+                (Symbol::new(&env, "borrowing_enabled"), 1u32, &asset).into_val(&env),
                 ().into_val(&env)
             ),
         ]
@@ -100,7 +100,7 @@ fn should_emit_events() {
             &env,
             (
                 sut.pool.address.clone(),
-                (Symbol::new(&env, "borrowing_disabled"), &asset).into_val(&env),
+                (Symbol::new(&env, "borrowing_disabled"), 1u32, &asset).into_val(&env),
                 ().into_val(&env)
             ),
         ]
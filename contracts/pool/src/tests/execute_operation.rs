@@ -0,0 +1,229 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::methods::execute_operation::TIMELOCK_GRACE_PERIOD;
+use crate::tests::sut::init_pool;
+use crate::*;
+use pool_interface::types::timelock_operation::TimelockOperation;
+use soroban_sdk::testutils::{Events, Ledger};
+use soroban_sdk::{vec, IntoVal, Symbol};
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #602)")]
+fn should_fail_before_eta() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let eta = env.ledger().timestamp() + 3600;
+
+    sut.pool.set_timelock_delay(&3600);
+
+    let id = sut
+        .pool
+        .schedule_operation(&TimelockOperation::SetTimelockDelay(0), &eta);
+
+    env.ledger().with_mut(|li| li.timestamp = eta - 1);
+
+    sut.pool.execute_operation(&id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #603)")]
+fn should_fail_after_grace_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let eta = env.ledger().timestamp() + 3600;
+
+    sut.pool.set_timelock_delay(&3600);
+
+    let id = sut
+        .pool
+        .schedule_operation(&TimelockOperation::SetTimelockDelay(0), &eta);
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = eta + TIMELOCK_GRACE_PERIOD + 1);
+
+    sut.pool.execute_operation(&id);
+}
+
+#[test]
+fn should_execute_at_end_of_grace_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let eta = env.ledger().timestamp() + 3600;
+
+    sut.pool.set_timelock_delay(&3600);
+
+    let id = sut
+        .pool
+        .schedule_operation(&TimelockOperation::SetTimelockDelay(0), &eta);
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = eta + TIMELOCK_GRACE_PERIOD);
+
+    sut.pool.execute_operation(&id);
+
+    assert_eq!(sut.pool.timelock_delay(), 0);
+}
+
+#[test]
+fn should_remove_expired_operations() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let eta = env.ledger().timestamp() + 3600;
+
+    sut.pool.set_timelock_delay(&3600);
+
+    sut.pool
+        .schedule_operation(&TimelockOperation::SetTimelockDelay(60), &eta);
+    let id = sut.pool.schedule_operation(
+        &TimelockOperation::SetTimelockDelay(0),
+        &(eta + TIMELOCK_GRACE_PERIOD),
+    );
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = eta + TIMELOCK_GRACE_PERIOD + 1);
+
+    sut.pool.execute_operation(&id);
+
+    assert!(sut.pool.queued_operations().is_empty());
+}
+
+#[test]
+fn should_remove_expired_operations_on_schedule() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let eta = env.ledger().timestamp() + 3600;
+
+    sut.pool.set_timelock_delay(&3600);
+
+    sut.pool
+        .schedule_operation(&TimelockOperation::SetTimelockDelay(60), &eta);
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = eta + TIMELOCK_GRACE_PERIOD + 1);
+
+    let id = sut.pool.schedule_operation(
+        &TimelockOperation::SetTimelockDelay(0),
+        &(env.ledger().timestamp() + 3600),
+    );
+
+    let queue = sut.pool.queued_operations();
+
+    assert_eq!(queue.len(), 1);
+    assert_eq!(queue.get_unchecked(0).id, id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn should_fail_when_operation_not_queued() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    sut.pool.execute_operation(&0);
+}
+
+#[test]
+fn should_not_require_auth() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let eta = env.ledger().timestamp();
+
+    let id = sut
+        .pool
+        .schedule_operation(&TimelockOperation::SetTimelockDelay(60), &eta);
+
+    sut.pool.execute_operation(&id);
+
+    assert!(env.auths().is_empty());
+}
+
+#[test]
+fn should_apply_operation_after_eta() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = sut.token().address.clone();
+    let eta = env.ledger().timestamp() + 3600;
+
+    sut.pool.set_timelock_delay(&3600);
+
+    let params = CollateralParamsInput {
+        liq_cap: 1_000,
+        pen_order: 10,
+        util_cap: 5_000,
+        discount: 4_000,
+    };
+
+    let collat_id = sut.pool.schedule_operation(
+        &TimelockOperation::ConfigureAsCollateral(asset.clone(), params),
+        &eta,
+    );
+    let delay_id = sut
+        .pool
+        .schedule_operation(&TimelockOperation::SetTimelockDelay(0), &eta);
+
+    env.ledger().with_mut(|li| li.timestamp = eta);
+
+    sut.pool.execute_operation(&collat_id);
+    sut.pool.execute_operation(&delay_id);
+
+    let configuration = sut.pool.get_reserve(&asset).unwrap().configuration;
+
+    assert_eq!(configuration.liquidity_cap, 1_000);
+    assert_eq!(configuration.pen_order, 10);
+    assert_eq!(configuration.util_cap, 5_000);
+    assert_eq!(configuration.discount, 4_000);
+    assert_eq!(sut.pool.timelock_delay(), 0);
+    assert!(sut.pool.queued_operations().is_empty());
+}
+
+#[test]
+fn should_emit_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let eta = env.ledger().timestamp();
+
+    let id = sut
+        .pool
+        .schedule_operation(&TimelockOperation::SetTimelockDelay(60), &eta);
+
+    sut.pool.execute_operation(&id);
+
+    let mut events = env.events().all();
+    let executed_event = events.pop_back_unchecked();
+    let delay_event = events.pop_back_unchecked();
+
+    assert_eq!(
+        vec![&env, delay_event, executed_event],
+        vec![
+            &env,
+            (
+                sut.pool.address.clone(),
+                (Symbol::new(&env, "timelock_delay_changed"), 1u32).into_val(&env),
+                60u64.into_val(&env)
+            ),
+            (
+                sut.pool.address.clone(),
+                (Symbol::new(&env, "operation_executed"), 1u32, id).into_val(&env),
+                ().into_val(&env)
+            ),
+        ]
+    );
+}
@@ -0,0 +1,152 @@
+#![cfg(test)]
+extern crate std;
+
+use pool_interface::types::price_feed_config_input::PriceFeedConfigInput;
+use price_feed_interface::types::asset::Asset;
+use price_feed_interface::types::price_data::PriceData;
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::{vec, Address, Env, Vec};
+
+use crate::tests::sut::{
+    create_price_feed_contract, default_price_feed, default_price_feed_config, init_pool, Sut,
+};
+
+const TIMESTAMP: u64 = 1704790800;
+
+/// Configures a feed per price, feed prices are already in the base asset decimals
+fn configure_feeds(env: &Env, sut: &Sut, prices: &[i128], max_feed_deviation: u32) -> Address {
+    let asset = sut.reserves[1].token.address.clone();
+    let mut feeds = Vec::new(env);
+
+    env.ledger().with_mut(|li| li.timestamp = TIMESTAMP);
+
+    for price in prices {
+        let feed = create_price_feed_contract(env);
+
+        feed.init(
+            &Asset::Stellar(asset.clone()),
+            &vec![
+                env,
+                PriceData {
+                    price: *price,
+                    timestamp: TIMESTAMP,
+                },
+            ],
+        );
+
+        feeds.push_back(default_price_feed(env, &feed.address, &asset, 7));
+    }
+
+    sut.pool.set_price_feeds(&vec![
+        env,
+        PriceFeedConfigInput {
+            max_feed_deviation,
+            feeds,
+            ..default_price_feed_config(env, sut)
+        },
+    ]);
+
+    asset
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #401)")]
+fn should_fail_when_deviation_exceeds_percentage_factor() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    configure_feeds(&env, &sut, &[10_000_000], 10_001);
+}
+
+#[test]
+fn should_not_check_deviation_when_disabled() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_feeds(&env, &sut, &[10_000_000, 15_000_000], 0);
+
+    let price = sut.pool.twap_median_price(&asset, &1_000_000_000);
+
+    assert_eq!(price, 12_500_000);
+}
+
+#[test]
+fn should_return_median_when_feeds_agree() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_feeds(&env, &sut, &[10_000_000, 10_100_000, 10_200_000], 200);
+
+    let price = sut.pool.twap_median_price(&asset, &1_000_000_000);
+
+    assert_eq!(price, 10_100_000);
+}
+
+#[test]
+fn should_drop_outlier_feeds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_feeds(&env, &sut, &[10_000_000, 15_000_000, 10_100_000], 500);
+
+    let price = sut.pool.twap_median_price(&asset, &1_000_000_000);
+
+    // median([1.0, 1.01])
+    assert_eq!(price, 10_050_000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #109)")]
+fn should_fail_when_feeds_disagree() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_feeds(&env, &sut, &[10_000_000, 15_000_000], 500);
+
+    sut.pool.twap_median_price(&asset, &1_000_000_000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #109)")]
+fn should_fail_when_outliers_are_majority() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_feeds(&env, &sut, &[10_000_000, 12_000_000, 14_000_000], 500);
+
+    sut.pool.twap_median_price(&asset, &1_000_000_000);
+}
+
+#[test]
+fn should_not_collapse_duplicate_prices() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_feeds(&env, &sut, &[10_000_000, 15_000_000, 10_000_000], 500);
+
+    let price = sut.pool.twap_median_price(&asset, &1_000_000_000);
+
+    assert_eq!(price, 10_000_000);
+}
+
+#[test]
+fn should_skip_insane_feeds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_feeds(&env, &sut, &[10_000_000, 200_000_000, 10_100_000], 500);
+
+    let price = sut.pool.twap_median_price(&asset, &1_000_000_000);
+
+    // median([1.0, 1.01]), 20.0 is above the max sanity price
+    assert_eq!(price, 10_050_000);
+}
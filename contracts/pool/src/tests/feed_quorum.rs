@@ -0,0 +1,165 @@
+#![cfg(test)]
+extern crate std;
+
+use pool_interface::types::price_feed_config_input::PriceFeedConfigInput;
+use price_feed_interface::types::asset::Asset;
+use price_feed_interface::types::price_data::PriceData;
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::{vec, Address, Env, Vec};
+
+use crate::tests::sut::{
+    create_price_feed_contract, default_price_feed, default_price_feed_config, init_pool, Sut,
+};
+
+const TIMESTAMP: u64 = 1704790800;
+const STALE: u64 = TIMESTAMP - 1_000;
+
+/// Configures a feed per (price, timestamp), feed prices are already in the base asset decimals
+fn configure_feeds(
+    env: &Env,
+    sut: &Sut,
+    prices: &[(i128, u64)],
+    min_feeds: u32,
+    max_feed_deviation: u32,
+) -> Address {
+    let asset = sut.reserves[1].token.address.clone();
+    let mut feeds = Vec::new(env);
+
+    env.ledger().with_mut(|li| li.timestamp = TIMESTAMP);
+
+    for (price, timestamp) in prices {
+        let feed = create_price_feed_contract(env);
+
+        feed.init(
+            &Asset::Stellar(asset.clone()),
+            &vec![
+                env,
+                PriceData {
+                    price: *price,
+                    timestamp: *timestamp,
+                },
+            ],
+        );
+
+        feeds.push_back(default_price_feed(env, &feed.address, &asset, 7));
+    }
+
+    sut.pool.set_price_feeds(&vec![
+        env,
+        PriceFeedConfigInput {
+            max_feed_deviation,
+            min_feeds,
+            feeds,
+            ..default_price_feed_config(env, sut)
+        },
+    ]);
+
+    asset
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #3)")]
+fn should_fail_when_min_feeds_is_zero() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    configure_feeds(&env, &sut, &[(10_000_000, TIMESTAMP)], 0, 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #4)")]
+fn should_fail_when_min_feeds_exceeds_feeds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    configure_feeds(&env, &sut, &[(10_000_000, TIMESTAMP)], 2, 0);
+}
+
+#[test]
+fn should_return_price_when_quorum_reached() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_feeds(
+        &env,
+        &sut,
+        &[
+            (10_000_000, TIMESTAMP),
+            (30_000_000, STALE),
+            (11_000_000, TIMESTAMP),
+        ],
+        2,
+        0,
+    );
+
+    let price = sut.pool.twap_median_price(&asset, &1_000_000_000);
+
+    assert_eq!(price, 10_500_000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #110)")]
+fn should_fail_when_quorum_not_reached() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_feeds(
+        &env,
+        &sut,
+        &[
+            (10_000_000, TIMESTAMP),
+            (10_000_000, STALE),
+            (10_000_000, STALE),
+        ],
+        2,
+        0,
+    );
+
+    sut.pool.twap_median_price(&asset, &1_000_000_000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #110)")]
+fn should_fail_when_all_feeds_are_stale() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_feeds(
+        &env,
+        &sut,
+        &[(10_000_000, STALE), (10_000_000, STALE)],
+        2,
+        0,
+    );
+
+    sut.pool.twap_median_price(&asset, &1_000_000_000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #109)")]
+fn should_fail_when_outliers_leave_less_than_min_feeds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_feeds(
+        &env,
+        &sut,
+        &[
+            (10_000_000, TIMESTAMP),
+            (10_100_000, TIMESTAMP),
+            (15_000_000, TIMESTAMP),
+        ],
+        3,
+        500,
+    );
+
+    sut.pool.twap_median_price(&asset, &1_000_000_000);
+}
@@ -0,0 +1,176 @@
+#![cfg(test)]
+extern crate std;
+
+use pool_interface::types::price_feed::PriceFeed;
+use pool_interface::types::price_feed_config_input::PriceFeedConfigInput;
+use price_feed_interface::types::asset::Asset;
+use price_feed_interface::types::price_data::PriceData;
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::{vec, Address, Env, Vec};
+
+use crate::tests::sut::{
+    create_price_feed_contract, default_price_feed, default_price_feed_config, init_pool, Sut,
+};
+
+const TIMESTAMP: u64 = 1704790800;
+
+/// Configures a feed per (price, weight), feed prices are already in the base asset decimals
+fn configure_feeds(
+    env: &Env,
+    sut: &Sut,
+    prices: &[(i128, Option<u32>)],
+    max_feed_deviation: u32,
+) -> Address {
+    let asset = sut.reserves[1].token.address.clone();
+    let mut feeds = Vec::new(env);
+
+    env.ledger().with_mut(|li| li.timestamp = TIMESTAMP);
+
+    for (price, weight) in prices {
+        let feed = create_price_feed_contract(env);
+
+        feed.init(
+            &Asset::Stellar(asset.clone()),
+            &vec![
+                env,
+                PriceData {
+                    price: *price,
+                    timestamp: TIMESTAMP,
+                },
+            ],
+        );
+
+        feeds.push_back(PriceFeed {
+            weight: *weight,
+            ..default_price_feed(env, &feed.address, &asset, 7)
+        });
+    }
+
+    sut.pool.set_price_feeds(&vec![
+        env,
+        PriceFeedConfigInput {
+            max_feed_deviation,
+            feeds,
+            ..default_price_feed_config(env, sut)
+        },
+    ]);
+
+    asset
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #3)")]
+fn should_fail_when_weight_is_zero() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    configure_feeds(&env, &sut, &[(10_000_000, Some(0))], 0);
+}
+
+#[test]
+fn should_keep_duplicate_prices() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_feeds(
+        &env,
+        &sut,
+        &[(16_000_000, None), (10_000_000, None), (10_000_000, None)],
+        0,
+    );
+
+    let price = sut.pool.twap_median_price(&asset, &1_000_000_000);
+
+    assert_eq!(price, 10_000_000);
+}
+
+#[test]
+fn should_return_weighted_median() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_feeds(
+        &env,
+        &sut,
+        &[
+            (10_000_000, None),
+            (16_000_000, Some(3)),
+            (12_000_000, Some(1)),
+        ],
+        0,
+    );
+
+    let price = sut.pool.twap_median_price(&asset, &1_000_000_000);
+
+    assert_eq!(price, 16_000_000);
+}
+
+#[test]
+fn should_average_when_weight_splits_in_half() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_feeds(
+        &env,
+        &sut,
+        &[
+            (14_000_000, Some(2)),
+            (10_000_000, Some(1)),
+            (11_000_000, Some(1)),
+        ],
+        0,
+    );
+
+    let price = sut.pool.twap_median_price(&asset, &1_000_000_000);
+
+    // median([1.0, 1.1, 1.4, 1.4])
+    assert_eq!(price, 12_500_000);
+}
+
+#[test]
+fn should_let_primary_feed_outweigh_outliers() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_feeds(
+        &env,
+        &sut,
+        &[
+            (15_000_000, None),
+            (10_000_000, Some(3)),
+            (15_000_000, None),
+        ],
+        500,
+    );
+
+    let price = sut.pool.twap_median_price(&asset, &1_000_000_000);
+
+    assert_eq!(price, 10_000_000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #109)")]
+fn should_fail_when_agreed_weight_is_minority() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_feeds(
+        &env,
+        &sut,
+        &[
+            (10_000_000, Some(2)),
+            (9_000_000, None),
+            (20_000_000, Some(2)),
+        ],
+        500,
+    );
+
+    sut.pool.twap_median_price(&asset, &1_000_000_000);
+}
@@ -44,7 +44,7 @@ fn finalize_transfer_should_fail_if_paused() {
     let (lender, borrower, _debt_token_reserve) = fill_pool(&env, &sut, true);
     let token_client = &sut.reserves[0].token;
     let s_token_client = sut.reserves[0].s_token();
-    sut.pool.set_pause(&true);
+    sut.pool.set_pause(&sut.pool_admin, &true);
 
     let lender_balance_before = s_token_client.balance(&lender);
     let borrower_balance_before = s_token_client.balance(&borrower);
@@ -335,8 +335,8 @@ fn should_fail_in_grace_period() {
     assert_eq!(lender_in_pool_before - lender_in_pool_after, 1);
     assert_eq!(borrower_in_pool_after - borrower_in_pool_before, 1);
 
-    sut.pool.set_pause(&true);
-    sut.pool.set_pause(&false);
+    sut.pool.set_pause(&sut.pool_admin, &true);
+    sut.pool.set_pause(&sut.pool_admin, &false);
     sut.pool.finalize_transfer(
         &token_client.address,
         &lender,
@@ -384,9 +384,9 @@ fn should_not_fail_after_grace_period() {
     assert_eq!(lender_in_pool_before - lender_in_pool_after, 1);
     assert_eq!(borrower_in_pool_after - borrower_in_pool_before, 1);
 
-    sut.pool.set_pause(&true);
+    sut.pool.set_pause(&sut.pool_admin, &true);
     set_time(&env, &sut, start + gap, false);
-    sut.pool.set_pause(&false);
+    sut.pool.set_pause(&sut.pool_admin, &false);
     set_time(
         &env,
         &sut,
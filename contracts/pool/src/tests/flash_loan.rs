@@ -380,8 +380,8 @@ fn should_fail_if_borrow_in_grace_period() {
         ],
     );
 
-    sut.pool.set_pause(&true);
-    sut.pool.set_pause(&false);
+    sut.pool.set_pause(&sut.pool_admin, &true);
+    sut.pool.set_pause(&sut.pool_admin, &false);
 
     sut.pool.flash_loan(
         &borrower,
@@ -426,8 +426,8 @@ fn should_not_fail_in_grace_period() {
         ],
     );
 
-    sut.pool.set_pause(&true);
-    sut.pool.set_pause(&false);
+    sut.pool.set_pause(&sut.pool_admin, &true);
+    sut.pool.set_pause(&sut.pool_admin, &false);
 
     sut.pool.flash_loan(
         &borrower,
@@ -0,0 +1,200 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::tests::sut::init_pool;
+use crate::*;
+use pool_interface::types::role::Role;
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events};
+use soroban_sdk::{vec, IntoVal, Symbol};
+
+#[test]
+fn should_require_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let risk_admin = Address::generate(&env);
+
+    sut.pool.grant_role(&Role::RiskAdmin, &risk_admin);
+
+    assert_eq!(
+        env.auths(),
+        [(
+            sut.pool_admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    sut.pool.address.clone(),
+                    Symbol::new(&env, "grant_role"),
+                    (Role::RiskAdmin, risk_admin.clone()).into_val(&env)
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn should_fail_when_granting_pool_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    sut.pool
+        .grant_role(&Role::PoolAdmin, &Address::generate(&env));
+}
+
+#[test]
+fn should_be_held_by_admin_when_not_granted() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    for role in [
+        Role::PoolAdmin,
+        Role::RiskAdmin,
+        Role::OracleAdmin,
+        Role::EmergencyGuardian,
+        Role::Treasury,
+    ] {
+        assert_eq!(sut.pool.role(&role), sut.pool_admin);
+    }
+}
+
+#[test]
+fn should_grant_role() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let treasury = Address::generate(&env);
+
+    sut.pool.grant_role(&Role::Treasury, &treasury);
+
+    assert_eq!(sut.pool.role(&Role::Treasury), treasury);
+    assert_eq!(sut.pool.role(&Role::RiskAdmin), sut.pool_admin);
+}
+
+#[test]
+fn should_require_role_holder() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = sut.token().address.clone();
+
+    let risk_admin = Address::generate(&env);
+    let oracle_admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    sut.pool.grant_role(&Role::RiskAdmin, &risk_admin);
+    sut.pool.grant_role(&Role::OracleAdmin, &oracle_admin);
+    sut.pool.grant_role(&Role::EmergencyGuardian, &guardian);
+    sut.pool.grant_role(&Role::Treasury, &treasury);
+
+    sut.pool.enable_borrowing_on_reserve(&asset, &true);
+    assert_eq!(authorizer(&env), risk_admin);
+
+    sut.pool.configure_as_collateral(
+        &asset,
+        &CollateralParamsInput {
+            liq_cap: 100_000_000,
+            pen_order: 1,
+            util_cap: 9000,
+            discount: 6000,
+        },
+    );
+    assert_eq!(authorizer(&env), risk_admin);
+
+    sut.pool
+        .set_pool_configuration(&sut.pool.pool_configuration());
+    assert_eq!(authorizer(&env), risk_admin);
+
+    sut.pool.set_price_feeds(&Vec::new(&env));
+    assert_eq!(authorizer(&env), oracle_admin);
+
+    sut.pool
+        .claim_protocol_fee(&sut.rwa_config().token.address, &treasury);
+    assert_eq!(authorizer(&env), treasury);
+
+    sut.pool.set_reserve_status(&asset, &true);
+    assert_eq!(authorizer(&env), sut.pool_admin);
+}
+
+#[test]
+fn should_allow_guardian_and_admin_to_pause() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let guardian = Address::generate(&env);
+
+    sut.pool.grant_role(&Role::EmergencyGuardian, &guardian);
+
+    sut.pool.set_pause(&guardian, &true);
+    assert_eq!(authorizer(&env), guardian);
+
+    sut.pool.set_pause(&sut.pool_admin, &false);
+    assert_eq!(authorizer(&env), sut.pool_admin);
+
+    sut.pool.set_pause(&sut.pool_admin, &true);
+    assert_eq!(authorizer(&env), sut.pool_admin);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn should_not_allow_guardian_to_unpause() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let guardian = Address::generate(&env);
+
+    sut.pool.grant_role(&Role::EmergencyGuardian, &guardian);
+    sut.pool.set_pause(&guardian, &true);
+
+    sut.pool.set_pause(&guardian, &false);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn should_not_allow_others_to_pause() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    sut.pool.set_pause(&Address::generate(&env), &true);
+}
+
+#[test]
+fn should_emit_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let oracle_admin = Address::generate(&env);
+
+    sut.pool.grant_role(&Role::OracleAdmin, &oracle_admin);
+
+    let event = env.events().all().pop_back_unchecked();
+
+    assert_eq!(
+        vec![&env, event],
+        vec![
+            &env,
+            (
+                sut.pool.address.clone(),
+                (Symbol::new(&env, "role_granted"), 1u32, Role::OracleAdmin).into_val(&env),
+                oracle_admin.into_val(&env)
+            ),
+        ]
+    );
+}
+
+fn authorizer(env: &Env) -> Address {
+    env.auths().first().unwrap().0.clone()
+}
@@ -6,8 +6,8 @@ use crate::tests::sut::{
 };
 use crate::*;
 use pool_interface::types::reserve_type::ReserveType;
-use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation};
-use soroban_sdk::{IntoVal, Symbol};
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events};
+use soroban_sdk::{vec, IntoVal, Symbol};
 
 #[test]
 fn should_require_admin() {
@@ -122,3 +122,61 @@ fn should_set_underlying_asset_s_token_and_debt_token_addresses() {
 
     assert_eq!(reserve.reserve_type, init_reserve_input);
 }
+
+#[test]
+fn should_emit_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+
+    let (underlying_token, _) = create_token_contract(&env, &token_admin);
+    let (debt_token, _) = create_token_contract(&env, &token_admin);
+
+    let pool: LendingPoolClient<'_> =
+        create_pool_contract(&env, &admin, false, &underlying_token.address);
+    let s_token = create_s_token_contract(&env, &pool.address, &underlying_token.address);
+    let reserve_type = ReserveType::Fungible(s_token.address.clone(), debt_token.address.clone());
+
+    pool.init_reserve(&underlying_token.address, &reserve_type);
+
+    let mut events = env.events().all();
+    let reserve_updated = events.pop_back_unchecked();
+    let reserve_initialized = events.pop_back_unchecked();
+    let reserve = pool.get_reserve(&underlying_token.address).unwrap();
+
+    assert_eq!(
+        vec![&env, reserve_initialized, reserve_updated],
+        vec![
+            &env,
+            (
+                pool.address.clone(),
+                (
+                    Symbol::new(&env, "reserve_initialized"),
+                    1u32,
+                    underlying_token.address.clone()
+                )
+                    .into_val(&env),
+                (reserve_type, 0u32).into_val(&env)
+            ),
+            (
+                pool.address.clone(),
+                (
+                    Symbol::new(&env, "reserve_updated"),
+                    underlying_token.address.clone()
+                )
+                    .into_val(&env),
+                (
+                    reserve.lender_ar,
+                    reserve.borrower_ar,
+                    reserve.lender_ir,
+                    reserve.borrower_ir,
+                    0i128,
+                    reserve.last_update_timestamp
+                )
+                    .into_val(&env)
+            ),
+        ]
+    );
+}
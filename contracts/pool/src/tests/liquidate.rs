@@ -54,7 +54,7 @@ fn should_fail_when_pool_paused() {
     let sut = init_pool(&env, false);
     let (_, borrower, liquidator, _) = fill_pool_three(&env, &sut);
 
-    sut.pool.set_pause(&true);
+    sut.pool.set_pause(&sut.pool_admin, &true);
     sut.pool.liquidate(&liquidator, &borrower);
 }
 
@@ -1009,8 +1009,8 @@ fn should_fail_in_grace_period() {
     let sut = init_pool(&env, false);
     let (_, borrower, liquidator, _) = fill_pool_three(&env, &sut);
 
-    sut.pool.set_pause(&true);
-    sut.pool.set_pause(&false);
+    sut.pool.set_pause(&sut.pool_admin, &true);
+    sut.pool.set_pause(&sut.pool_admin, &false);
     sut.pool.liquidate(&liquidator, &borrower);
 }
 
@@ -1026,9 +1026,9 @@ fn should_not_fail_after_grace_period() {
     let (_, borrower, liquidator, _) = fill_pool_three(&env, &sut);
     let borrower_pos_before = sut.pool.account_position(&borrower);
 
-    sut.pool.set_pause(&true);
+    sut.pool.set_pause(&sut.pool_admin, &true);
     set_time(&env, &sut, start + gap, false);
-    sut.pool.set_pause(&false);
+    sut.pool.set_pause(&sut.pool_admin, &false);
     set_time(
         &env,
         &sut,
@@ -0,0 +1,511 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::storage::{
+    DataKey, LegacyPauseInfo, LegacyPriceFeed, LegacyPriceFeedConfig, LegacyReserveConfiguration,
+    LegacyReserveData, DEFAULT_TWAP_WINDOW, STORAGE_VERSION,
+};
+use crate::tests::sut::{create_token_contract, empty_migration_input, init_pool, Sut};
+use crate::*;
+use pool_interface::types::oracle_asset::OracleAsset;
+use pool_interface::types::reserve_type::ReserveType;
+use pool_interface::types::timestamp_precision::TimestampPrecision;
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events};
+use soroban_sdk::{contract, contractimpl, vec, IntoVal, Map, Symbol, TryFromVal, Val};
+
+fn remove_storage_version(env: &Env, sut: &Sut) {
+    env.as_contract(&sut.pool.address, || {
+        env.storage().instance().remove(&DataKey::StorageVersion)
+    });
+}
+
+#[test]
+fn should_write_version_on_initialize() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    assert_eq!(sut.pool.storage_version(), STORAGE_VERSION);
+}
+
+#[test]
+fn should_require_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    remove_storage_version(&env, &sut);
+
+    let input = empty_migration_input(&env);
+
+    sut.pool.migrate(&0, &input);
+
+    assert_eq!(
+        env.auths(),
+        [(
+            sut.pool_admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    sut.pool.address.clone(),
+                    Symbol::new(&env, "migrate"),
+                    (0u32, input).into_val(&env)
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #10)")]
+fn should_fail_when_already_migrated() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    sut.pool.migrate(&0, &empty_migration_input(&env));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #10)")]
+fn should_fail_when_from_version_mismatches() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    remove_storage_version(&env, &sut);
+
+    sut.pool
+        .migrate(&STORAGE_VERSION, &empty_migration_input(&env));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #9)")]
+fn should_block_operations_until_migrated() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let user = Address::generate(&env);
+
+    sut.reserves[1].token_admin.mint(&user, &1_000_000);
+    remove_storage_version(&env, &sut);
+
+    sut.pool
+        .deposit(&user, &sut.reserves[1].token.address, &1_000_000);
+}
+
+#[test]
+fn should_migrate_unversioned_storage() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let user = Address::generate(&env);
+    let asset = sut.reserves[1].token.address.clone();
+    let reserve = sut.pool.get_reserve(&asset).unwrap();
+
+    env.as_contract(&sut.pool.address, || {
+        env.storage().instance().set(
+            &DataKey::ReserveAssetKey(asset.clone()),
+            &LegacyReserveData {
+                configuration: LegacyReserveConfiguration {
+                    is_active: reserve.configuration.is_active,
+                    borrowing_enabled: reserve.configuration.borrowing_enabled,
+                    liquidity_cap: reserve.configuration.liquidity_cap,
+                    pen_order: reserve.configuration.pen_order,
+                    util_cap: reserve.configuration.util_cap,
+                    discount: reserve.configuration.discount,
+                },
+                lender_ar: reserve.lender_ar,
+                lender_ir: reserve.lender_ir,
+                borrower_ar: reserve.borrower_ar,
+                borrower_ir: reserve.borrower_ir,
+                last_update_timestamp: reserve.last_update_timestamp,
+                reserve_type: reserve.reserve_type.clone(),
+                id: reserve.id.clone(),
+            },
+        );
+        env.storage().instance().set(
+            &DataKey::Pause,
+            &LegacyPauseInfo {
+                paused: false,
+                grace_period_secs: 1,
+                unpaused_at: 0,
+            },
+        );
+        env.storage().instance().remove(&DataKey::StorageVersion);
+    });
+
+    assert_eq!(sut.pool.storage_version(), 0);
+
+    sut.pool.migrate(&0, &empty_migration_input(&env));
+
+    assert_eq!(sut.pool.storage_version(), STORAGE_VERSION);
+
+    env.as_contract(&sut.pool.address, || {
+        let reserve: Val = env
+            .storage()
+            .instance()
+            .get(&DataKey::ReserveAssetKey(asset.clone()))
+            .unwrap();
+        let pause_info: Val = env.storage().instance().get(&DataKey::Pause).unwrap();
+
+        assert!(ReserveData::try_from_val(&env, &reserve).is_ok());
+        assert!(PauseInfo::try_from_val(&env, &pause_info).is_ok());
+    });
+
+    sut.reserves[1].token_admin.mint(&user, &1_000_000);
+    sut.pool.deposit(&user, &asset, &1_000_000);
+
+    assert_eq!(sut.reserves[1].s_token().balance(&user), 1_000_000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #10)")]
+fn should_fail_when_pause_info_is_malformed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    env.as_contract(&sut.pool.address, || {
+        env.storage().instance().set(&DataKey::Pause, &1u32);
+    });
+
+    sut.pool.pause_info();
+}
+
+#[test]
+fn should_migrate_price_feeds_from_v1() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = sut.reserves[1].token.address.clone();
+    let config = sut.pool.price_feeds(&asset).unwrap();
+
+    let mut legacy_feeds = Vec::new(&env);
+    for feed in config.feeds.iter() {
+        legacy_feeds.push_back(LegacyPriceFeed {
+            feed: feed.feed,
+            feed_asset: feed.feed_asset,
+            feed_decimals: feed.feed_decimals,
+            twap_records: 10,
+            min_timestamp_delta: feed.min_timestamp_delta,
+            timestamp_precision: feed.timestamp_precision,
+        });
+    }
+
+    env.as_contract(&sut.pool.address, || {
+        env.storage().instance().set(
+            &DataKey::PriceFeed(asset.clone()),
+            &LegacyPriceFeedConfig {
+                asset_decimals: config.asset_decimals,
+                min_sanity_price_in_base: config.min_sanity_price_in_base,
+                max_sanity_price_in_base: config.max_sanity_price_in_base,
+                feeds: legacy_feeds,
+            },
+        );
+        env.storage()
+            .instance()
+            .set(&DataKey::StorageVersion, &1u32);
+    });
+
+    sut.pool.migrate(&1, &empty_migration_input(&env));
+
+    assert_eq!(sut.pool.storage_version(), STORAGE_VERSION);
+
+    let migrated = sut.pool.price_feeds(&asset).unwrap();
+
+    assert_eq!(migrated.asset_decimals, config.asset_decimals);
+    assert!(!migrated.lastprice_fallback);
+    assert_eq!(migrated.stored_price_max_age, 0);
+    assert_eq!(migrated.max_feed_deviation, 0);
+    assert_eq!(migrated.min_feeds, 1);
+    assert_eq!(migrated.max_price_change, 0);
+    assert!(!migrated.conservative_pricing);
+    // 10 records of the 300 secs feed resolution
+    assert_eq!(migrated.feeds.get_unchecked(0).twap_window, 3_000);
+    assert_eq!(migrated.feeds.get_unchecked(0).min_twap_span, 0);
+    assert_eq!(migrated.feeds.get_unchecked(0).weight, None);
+
+    env.as_contract(&sut.pool.address, || {
+        let config: Val = env
+            .storage()
+            .instance()
+            .get(&DataKey::PriceFeed(asset.clone()))
+            .unwrap();
+
+        assert!(PriceFeedConfig::try_from_val(&env, &config).is_ok());
+    });
+
+    assert_eq!(
+        sut.pool.twap_median_price(&asset, &1_000_000_000),
+        10_000_000
+    );
+}
+
+/// Deposits into the RWA reserve, sends tokens to the pool directly and
+/// puts the storage back to v2, which had no total of the RWA deposits
+fn set_v2_rwa_deposits(env: &Env, sut: &Sut, deposit: i128, donation: i128) -> Address {
+    let user = Address::generate(env);
+    let rwa_address = sut.rwa_config().token.address.clone();
+
+    sut.rwa_config()
+        .token_admin
+        .mint(&user, &(deposit + donation));
+    sut.pool.deposit(&user, &rwa_address, &deposit);
+    sut.rwa_config()
+        .token
+        .transfer(&user, &sut.pool.address, &donation);
+
+    env.as_contract(&sut.pool.address, || {
+        env.storage()
+            .instance()
+            .remove(&DataKey::TokenSupply(rwa_address.clone()));
+        env.storage()
+            .instance()
+            .set(&DataKey::StorageVersion, &2u32);
+    });
+
+    rwa_address
+}
+
+#[test]
+fn should_store_rwa_total_supply() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let rwa_address = set_v2_rwa_deposits(&env, &sut, 1_000_000_000, 0);
+
+    sut.pool.migrate(&2, &empty_migration_input(&env));
+
+    assert_eq!(sut.pool.token_total_supply(&rwa_address), 1_000_000_000);
+}
+
+#[test]
+fn should_store_attested_rwa_total_supply() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let rwa_address = set_v2_rwa_deposits(&env, &sut, 1_000_000_000, 500_000_000);
+
+    sut.pool.migrate(
+        &2,
+        &MigrationInput {
+            rwa_total_supplies: Map::from_array(&env, [(rwa_address.clone(), 1_000_000_000)]),
+            ..empty_migration_input(&env)
+        },
+    );
+
+    assert_eq!(sut.pool.token_total_supply(&rwa_address), 1_000_000_000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #404)")]
+fn should_fail_when_attested_rwa_total_supply_is_negative() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let rwa_address = set_v2_rwa_deposits(&env, &sut, 1_000_000_000, 0);
+
+    sut.pool.migrate(
+        &2,
+        &MigrationInput {
+            rwa_total_supplies: Map::from_array(&env, [(rwa_address, -1)]),
+            ..empty_migration_input(&env)
+        },
+    );
+}
+
+/// Puts the storage back to v2, which didn't count the holders of the reserve ids
+fn set_v2_reserve_id_holders(env: &Env, sut: &Sut) {
+    env.as_contract(&sut.pool.address, || {
+        for id in 0..sut.reserves.len() as u32 {
+            env.storage()
+                .instance()
+                .remove(&DataKey::ReserveIdHolders(id));
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::StorageVersion, &2u32);
+    });
+}
+
+#[test]
+fn should_reuse_attested_free_reserve_id() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let delisted = sut.reserves[1].token.address.clone();
+    let delisted_id = sut.pool.get_reserve(&delisted).unwrap().get_id();
+    set_v2_reserve_id_holders(&env, &sut);
+
+    sut.pool.migrate(
+        &2,
+        &MigrationInput {
+            reserve_id_holders: Map::from_array(&env, [(u32::from(delisted_id), 0)]),
+            ..empty_migration_input(&env)
+        },
+    );
+    sut.pool.delist_reserve(&delisted);
+
+    let (token, _) = create_token_contract(&env, &sut.token_admin);
+    sut.pool.init_reserve(&token.address, &ReserveType::RWA);
+
+    assert_eq!(
+        sut.pool.get_reserve(&token.address).unwrap().get_id(),
+        delisted_id
+    );
+}
+
+#[test]
+fn should_not_reuse_unattested_reserve_id() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let delisted = sut.reserves[1].token.address.clone();
+    set_v2_reserve_id_holders(&env, &sut);
+
+    sut.pool.migrate(&2, &empty_migration_input(&env));
+    sut.pool.delist_reserve(&delisted);
+
+    let (token, _) = create_token_contract(&env, &sut.token_admin);
+    sut.pool.init_reserve(&token.address, &ReserveType::RWA);
+
+    assert_eq!(sut.pool.get_reserve(&token.address).unwrap().get_id(), 4);
+}
+
+/// Feed reporting only its resolution in milliseconds
+#[contract]
+struct MsecResolutionFeed;
+
+#[contractimpl]
+impl MsecResolutionFeed {
+    pub fn resolution(_env: Env) -> u32 {
+        60_000
+    }
+}
+
+/// Stores the price feed of the second reserve in the v1 layout with a single feed
+fn set_legacy_price_feed(env: &Env, sut: &Sut, feed: LegacyPriceFeed) -> Address {
+    let asset = sut.reserves[1].token.address.clone();
+
+    env.as_contract(&sut.pool.address, || {
+        env.storage().instance().set(
+            &DataKey::PriceFeed(asset.clone()),
+            &LegacyPriceFeedConfig {
+                asset_decimals: 9,
+                min_sanity_price_in_base: 5_000_000,
+                max_sanity_price_in_base: 100_000_000,
+                feeds: vec![env, feed],
+            },
+        );
+        env.storage()
+            .instance()
+            .set(&DataKey::StorageVersion, &1u32);
+    });
+
+    asset
+}
+
+#[test]
+fn should_derive_twap_window_from_feed_resolution() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = set_legacy_price_feed(
+        &env,
+        &sut,
+        LegacyPriceFeed {
+            feed: env.register_contract(None, MsecResolutionFeed),
+            feed_asset: OracleAsset::Stellar(sut.reserves[1].token.address.clone()),
+            feed_decimals: 16,
+            twap_records: 10,
+            min_timestamp_delta: 100_000,
+            timestamp_precision: TimestampPrecision::Msec,
+        },
+    );
+
+    sut.pool.migrate(&1, &empty_migration_input(&env));
+
+    let migrated = sut.pool.price_feeds(&asset).unwrap();
+
+    assert_eq!(migrated.feeds.get_unchecked(0).twap_window, 600);
+}
+
+#[test]
+fn should_default_twap_window_when_feed_has_no_resolution() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let feed = sut.reserves[1].token.address.clone();
+    let asset = set_legacy_price_feed(
+        &env,
+        &sut,
+        LegacyPriceFeed {
+            feed: feed.clone(),
+            feed_asset: OracleAsset::Stellar(sut.reserves[1].token.address.clone()),
+            feed_decimals: 16,
+            twap_records: 10,
+            min_timestamp_delta: 100,
+            timestamp_precision: TimestampPrecision::Sec,
+        },
+    );
+
+    sut.pool.migrate(&1, &empty_migration_input(&env));
+
+    let events = env.events().all();
+    let migrated = sut.pool.price_feeds(&asset).unwrap();
+
+    assert_eq!(sut.pool.storage_version(), STORAGE_VERSION);
+    assert_eq!(
+        migrated.feeds.get_unchecked(0).twap_window,
+        DEFAULT_TWAP_WINDOW
+    );
+    assert!(events.contains((
+        sut.pool.address.clone(),
+        (
+            Symbol::new(&env, "twap_window_defaulted"),
+            1u32,
+            asset.clone()
+        )
+            .into_val(&env),
+        (feed, DEFAULT_TWAP_WINDOW).into_val(&env)
+    )));
+}
+
+#[test]
+fn should_emit_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    remove_storage_version(&env, &sut);
+
+    sut.pool.migrate(&0, &empty_migration_input(&env));
+
+    let event = env.events().all().pop_back_unchecked();
+
+    assert_eq!(
+        vec![&env, event],
+        vec![
+            &env,
+            (
+                sut.pool.address.clone(),
+                (Symbol::new(&env, "storage_migrated"), 1u32).into_val(&env),
+                (0u32, STORAGE_VERSION).into_val(&env)
+            ),
+        ]
+    );
+}
@@ -1,32 +1,60 @@
+pub mod accept_admin;
 pub mod account_position;
 pub mod borrow;
 #[cfg(feature = "budget")]
 pub mod budget;
+pub mod cancel_admin_proposal;
+pub mod cancel_operation;
 pub mod collat_coeff;
 pub mod configure_as_collateral;
+pub mod conservative_pricing;
 pub mod debt_coeff;
+pub mod delist_reserve;
 pub mod deposit;
 pub mod enable_borrowing_on_reserve;
+pub mod execute_operation;
+pub mod feed_deviation;
+pub mod feed_quorum;
+pub mod feed_weights;
 pub mod finalize_transfer;
 pub mod flash_loan;
 pub mod get_reserve;
+pub mod grant_role;
 pub mod init_reserve;
 pub mod liquidate;
+pub mod migrate;
+pub mod pause_matrix;
 pub mod paused;
+pub mod price_breaker;
+pub mod price_fallback;
+pub mod price_path;
+pub mod propose_admin;
 pub mod protocol_fee;
 pub mod rates;
+pub mod redeem;
 pub mod repay;
+pub mod revoke_role;
 pub mod rounding;
+pub mod schedule_operation;
+pub mod set_accrual_suspension;
+pub mod set_action_pause;
 pub mod set_as_collateral;
 pub mod set_pause;
 pub mod set_pool_configuration;
 pub mod set_price_feeds;
+pub mod set_reserve_freeze;
+pub mod set_reserve_pause;
 pub mod set_reserve_status;
+pub mod set_timelock_delay;
 pub mod soroban_map;
+pub mod start_settlement;
+pub mod start_wind_down;
 pub mod stoken_underlying_balance;
 mod sut;
 pub mod treasury;
 pub mod twap_median_price;
+pub mod twap_window;
 pub mod upgrade;
 pub mod user_configuration;
 pub mod withdraw;
+pub mod write_off_debt;
@@ -0,0 +1,52 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::tests::sut::init_pool;
+use crate::*;
+use pool_interface::types::pause_action::PauseAction;
+use soroban_sdk::{map, vec};
+
+#[test]
+fn should_return_pause_matrix() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = sut.reserves[1].token.address.clone();
+
+    let matrix = sut.pool.pause_matrix();
+
+    assert!(!matrix.paused);
+    assert!(matrix.actions.is_empty());
+    assert!(matrix.reserves.is_empty());
+
+    sut.pool.set_pause(&sut.pool_admin, &true);
+    sut.pool
+        .set_action_pause(&sut.pool_admin, &PauseAction::FlashLoan, &true);
+    sut.pool
+        .set_reserve_pause(&sut.pool_admin, &asset, &PauseAction::Liquidate, &true);
+    sut.pool
+        .set_reserve_pause(&sut.pool_admin, &asset, &PauseAction::Borrow, &true);
+
+    let matrix = sut.pool.pause_matrix();
+
+    assert!(matrix.paused);
+    assert_eq!(matrix.actions, vec![&env, PauseAction::FlashLoan]);
+    assert_eq!(
+        matrix.reserves,
+        map![
+            &env,
+            (
+                asset.clone(),
+                vec![&env, PauseAction::Borrow, PauseAction::Liquidate]
+            )
+        ]
+    );
+
+    sut.pool
+        .set_reserve_pause(&sut.pool_admin, &asset, &PauseAction::Liquidate, &false);
+    sut.pool
+        .set_reserve_pause(&sut.pool_admin, &asset, &PauseAction::Borrow, &false);
+
+    assert!(sut.pool.pause_matrix().reserves.is_empty());
+}
@@ -0,0 +1,458 @@
+#![cfg(test)]
+extern crate std;
+
+use pool_interface::types::error::Error;
+use pool_interface::types::price_feed_config_input::PriceFeedConfigInput;
+use price_feed_interface::types::asset::Asset;
+use price_feed_interface::types::price_data::PriceData;
+use soroban_sdk::testutils::{AuthorizedFunction, AuthorizedInvocation, Events, Ledger};
+use soroban_sdk::{vec, Address, Env, IntoVal, Symbol};
+
+use crate::tests::sut::{
+    default_price_feed_config, fill_pool, fill_pool_three, init_pool, record_price, set_time, Sut,
+    DAY,
+};
+
+const WINDOW: u64 = 3_600;
+
+fn configure_breaker(
+    env: &Env,
+    sut: &Sut,
+    max_price_change: u32,
+    price_change_window: u64,
+    breaker_timeout: u64,
+) -> Address {
+    let asset = sut.reserves[1].token.address.clone();
+
+    sut.pool.set_price_feeds(&vec![
+        env,
+        PriceFeedConfigInput {
+            max_price_change,
+            price_change_window,
+            breaker_timeout,
+            ..default_price_feed_config(env, sut)
+        },
+    ]);
+
+    asset
+}
+
+/// Moves the time forward and sets a fresh price in the feed decimals
+fn set_price(env: &Env, sut: &Sut, asset: &Address, secs: u64, price: i128) {
+    env.ledger().with_mut(|li| li.timestamp += secs);
+
+    sut.price_feed.init(
+        &Asset::Stellar(asset.clone()),
+        &vec![
+            env,
+            PriceData {
+                price,
+                timestamp: env.ledger().timestamp(),
+            },
+        ],
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #3)")]
+fn should_fail_when_window_is_zero() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    configure_breaker(&env, &sut, 500, 0, 0);
+}
+
+#[test]
+fn should_accept_moves_within_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_breaker(&env, &sut, 500, WINDOW, 0);
+
+    set_time(&env, &sut, DAY, false);
+    assert_eq!(record_price(&env, &sut, &asset, 1_000_000_000), 10_000_000);
+
+    set_price(&env, &sut, &asset, 60, 10_400_000_000_000_000);
+    assert_eq!(record_price(&env, &sut, &asset, 1_000_000_000), 10_400_000);
+
+    let breaker = sut.pool.price_breaker(&asset).unwrap();
+
+    assert_eq!(breaker.reference_price, 10_000_000);
+    assert_eq!(breaker.reference_timestamp, DAY);
+    assert_eq!(breaker.tripped_at, None);
+}
+
+#[test]
+fn should_hold_reference_price_when_tripped() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_breaker(&env, &sut, 500, WINDOW, 0);
+
+    set_time(&env, &sut, DAY, false);
+    record_price(&env, &sut, &asset, 1_000_000_000);
+
+    set_price(&env, &sut, &asset, 60, 20_000_000_000_000_000);
+    assert_eq!(record_price(&env, &sut, &asset, 1_000_000_000), 10_000_000);
+    assert_eq!(
+        sut.pool.price_breaker(&asset).unwrap().tripped_at,
+        Some(DAY + 60)
+    );
+
+    // stays tripped after the window without a timeout
+    set_price(&env, &sut, &asset, 10 * WINDOW, 20_000_000_000_000_000);
+    assert_eq!(record_price(&env, &sut, &asset, 1_000_000_000), 10_000_000);
+}
+
+#[test]
+fn should_not_store_held_price_as_last_good_price() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = sut.reserves[1].token.address.clone();
+
+    sut.pool.set_price_feeds(&vec![
+        &env,
+        PriceFeedConfigInput {
+            max_price_change: 500,
+            price_change_window: WINDOW,
+            stored_price_max_age: WINDOW,
+            ..default_price_feed_config(&env, &sut)
+        },
+    ]);
+
+    set_time(&env, &sut, DAY, false);
+    record_price(&env, &sut, &asset, 1_000_000_000);
+
+    set_price(&env, &sut, &asset, 60, 20_000_000_000_000_000);
+    record_price(&env, &sut, &asset, 1_000_000_000);
+
+    // the held price would refresh the stored one once it is half its max age old
+    set_price(&env, &sut, &asset, WINDOW, 20_000_000_000_000_000);
+    assert_eq!(record_price(&env, &sut, &asset, 1_000_000_000), 10_000_000);
+
+    let last_good_price = sut.pool.last_good_price(&asset).unwrap();
+
+    assert_eq!(last_good_price.price, 10_000_000);
+    assert_eq!(last_good_price.timestamp, DAY);
+}
+
+#[test]
+fn should_not_record_breaker_in_views() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_breaker(&env, &sut, 500, WINDOW, 0);
+
+    set_time(&env, &sut, DAY, false);
+    sut.pool.twap_median_price(&asset, &1_000_000_000);
+
+    assert!(sut.pool.price_breaker(&asset).is_none());
+
+    record_price(&env, &sut, &asset, 1_000_000_000);
+
+    set_price(&env, &sut, &asset, 60, 20_000_000_000_000_000);
+    assert_eq!(
+        sut.pool.twap_median_price(&asset, &1_000_000_000),
+        10_000_000
+    );
+    assert_eq!(sut.pool.price_breaker(&asset).unwrap().tripped_at, None);
+}
+
+#[test]
+fn should_measure_moves_from_window_start() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_breaker(&env, &sut, 500, WINDOW, 0);
+
+    set_time(&env, &sut, DAY, false);
+    record_price(&env, &sut, &asset, 1_000_000_000);
+
+    set_price(&env, &sut, &asset, 60, 10_400_000_000_000_000);
+    assert_eq!(record_price(&env, &sut, &asset, 1_000_000_000), 10_400_000);
+
+    set_price(&env, &sut, &asset, 60, 10_800_000_000_000_000);
+    assert_eq!(record_price(&env, &sut, &asset, 1_000_000_000), 10_000_000);
+}
+
+#[test]
+fn should_roll_reference_after_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_breaker(&env, &sut, 500, WINDOW, 0);
+
+    set_time(&env, &sut, DAY, false);
+    record_price(&env, &sut, &asset, 1_000_000_000);
+
+    set_price(&env, &sut, &asset, WINDOW + 1, 10_400_000_000_000_000);
+    assert_eq!(record_price(&env, &sut, &asset, 1_000_000_000), 10_400_000);
+
+    let breaker = sut.pool.price_breaker(&asset).unwrap();
+
+    assert_eq!(breaker.reference_price, 10_400_000);
+    assert_eq!(breaker.reference_timestamp, DAY + WINDOW + 1);
+    assert_eq!(breaker.tripped_at, None);
+}
+
+#[test]
+fn should_trip_on_moves_after_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_breaker(&env, &sut, 500, WINDOW, 0);
+
+    set_time(&env, &sut, DAY, false);
+    record_price(&env, &sut, &asset, 1_000_000_000);
+
+    set_price(&env, &sut, &asset, WINDOW + 1, 20_000_000_000_000_000);
+    assert_eq!(record_price(&env, &sut, &asset, 1_000_000_000), 10_000_000);
+
+    let breaker = sut.pool.price_breaker(&asset).unwrap();
+
+    assert_eq!(breaker.reference_price, 10_000_000);
+    assert_eq!(breaker.tripped_at, Some(DAY + WINDOW + 1));
+}
+
+#[test]
+fn should_measure_moves_after_window_from_last_good_price() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = sut.reserves[1].token.address.clone();
+
+    sut.pool.set_price_feeds(&vec![
+        &env,
+        PriceFeedConfigInput {
+            stored_price_max_age: 100,
+            max_price_change: 500,
+            price_change_window: WINDOW,
+            ..default_price_feed_config(&env, &sut)
+        },
+    ]);
+
+    set_time(&env, &sut, DAY, false);
+    record_price(&env, &sut, &asset, 1_000_000_000);
+
+    set_price(&env, &sut, &asset, 60, 10_400_000_000_000_000);
+    record_price(&env, &sut, &asset, 1_000_000_000);
+
+    // 9% from the reference price, 4.8% from the last good price
+    set_price(&env, &sut, &asset, WINDOW, 10_900_000_000_000_000);
+    assert_eq!(record_price(&env, &sut, &asset, 1_000_000_000), 10_900_000);
+
+    let breaker = sut.pool.price_breaker(&asset).unwrap();
+
+    assert_eq!(breaker.reference_price, 10_900_000);
+    assert_eq!(breaker.reference_timestamp, DAY + WINDOW + 60);
+    assert_eq!(breaker.tripped_at, None);
+}
+
+#[test]
+fn should_reset_after_timeout() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_breaker(&env, &sut, 500, WINDOW, 600);
+
+    set_time(&env, &sut, DAY, false);
+    record_price(&env, &sut, &asset, 1_000_000_000);
+
+    set_price(&env, &sut, &asset, 60, 20_000_000_000_000_000);
+    record_price(&env, &sut, &asset, 1_000_000_000);
+
+    set_price(&env, &sut, &asset, 600, 20_000_000_000_000_000);
+    assert_eq!(record_price(&env, &sut, &asset, 1_000_000_000), 10_000_000);
+
+    set_price(&env, &sut, &asset, 1, 20_000_000_000_000_000);
+    assert_eq!(record_price(&env, &sut, &asset, 1_000_000_000), 20_000_000);
+}
+
+#[test]
+fn should_reset_by_oracle_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_breaker(&env, &sut, 500, WINDOW, 0);
+
+    set_time(&env, &sut, DAY, false);
+    record_price(&env, &sut, &asset, 1_000_000_000);
+
+    set_price(&env, &sut, &asset, 60, 20_000_000_000_000_000);
+    record_price(&env, &sut, &asset, 1_000_000_000);
+
+    sut.pool.reset_price_breaker(&asset);
+
+    assert_eq!(
+        env.auths(),
+        [(
+            sut.pool_admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    sut.pool.address.clone(),
+                    Symbol::new(&env, "reset_price_breaker"),
+                    vec![&env, asset.into_val(&env)]
+                )),
+                sub_invocations: std::vec![]
+            }
+        ),]
+    );
+    assert!(sut.pool.price_breaker(&asset).is_none());
+
+    set_price(&env, &sut, &asset, 1, 20_000_000_000_000_000);
+    assert_eq!(record_price(&env, &sut, &asset, 1_000_000_000), 20_000_000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #111)")]
+fn should_fail_borrow_when_tripped() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, borrower, _) = fill_pool(&env, &sut, false);
+    let asset = configure_breaker(&env, &sut, 500, WINDOW, 0);
+
+    record_price(&env, &sut, &asset, 1_000_000_000);
+
+    set_price(&env, &sut, &asset, 60, 20_000_000_000_000_000);
+    record_price(&env, &sut, &asset, 1_000_000_000);
+
+    sut.pool.borrow(&borrower, &asset, &10_000_000);
+}
+
+#[test]
+fn should_trip_breaker_through_borrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, borrower, _) = fill_pool(&env, &sut, false);
+    let asset = configure_breaker(&env, &sut, 500, WINDOW, 0);
+
+    sut.pool.borrow(&borrower, &asset, &10_000_000);
+
+    set_price(&env, &sut, &asset, 60, 20_000_000_000_000_000);
+    sut.pool.borrow(&borrower, &asset, &10_000_000);
+
+    assert_eq!(
+        sut.pool.price_breaker(&asset).unwrap().tripped_at,
+        Some(DAY + 60)
+    );
+    assert_eq!(
+        sut.pool.try_borrow(&borrower, &asset, &10_000_000),
+        Err(Ok(Error::PriceBreakerTripped))
+    );
+
+    // stays tripped after the window without a timeout
+    set_price(&env, &sut, &asset, 10 * WINDOW, 20_000_000_000_000_000);
+    assert_eq!(
+        sut.pool.try_borrow(&borrower, &asset, &10_000_000),
+        Err(Ok(Error::PriceBreakerTripped))
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #111)")]
+fn should_fail_withdraw_when_debt_is_tripped() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, borrower, _) = fill_pool(&env, &sut, true);
+    let asset = configure_breaker(&env, &sut, 500, WINDOW, 0);
+
+    record_price(&env, &sut, &asset, 1_000_000_000);
+
+    set_price(&env, &sut, &asset, 60, 20_000_000_000_000_000);
+    record_price(&env, &sut, &asset, 1_000_000_000);
+
+    sut.pool
+        .withdraw(&borrower, &sut.reserves[0].token.address, &100, &borrower);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #111)")]
+fn should_fail_liquidation_when_tripped() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, borrower, liquidator, _) = fill_pool_three(&env, &sut);
+    let asset = configure_breaker(&env, &sut, 500, WINDOW, 0);
+
+    record_price(&env, &sut, &asset, 1_000_000_000);
+
+    set_price(&env, &sut, &asset, 60, 20_000_000_000_000_000);
+    record_price(&env, &sut, &asset, 1_000_000_000);
+
+    sut.pool.liquidate(&liquidator, &borrower);
+}
+
+#[test]
+fn should_emit_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_breaker(&env, &sut, 500, WINDOW, 0);
+
+    set_time(&env, &sut, DAY, false);
+    record_price(&env, &sut, &asset, 1_000_000_000);
+
+    set_price(&env, &sut, &asset, 60, 20_000_000_000_000_000);
+    record_price(&env, &sut, &asset, 1_000_000_000);
+
+    let event = env.events().all().pop_back_unchecked();
+
+    assert_eq!(
+        vec![&env, event],
+        vec![
+            &env,
+            (
+                sut.pool.address.clone(),
+                (
+                    Symbol::new(&env, "price_breaker_tripped"),
+                    1u32,
+                    asset.clone()
+                )
+                    .into_val(&env),
+                (10_000_000i128, 20_000_000i128).into_val(&env)
+            ),
+        ]
+    );
+
+    sut.pool.reset_price_breaker(&asset);
+
+    let event = env.events().all().pop_back_unchecked();
+
+    assert_eq!(
+        vec![&env, event],
+        vec![
+            &env,
+            (
+                sut.pool.address.clone(),
+                (
+                    Symbol::new(&env, "price_breaker_reset"),
+                    1u32,
+                    asset.clone()
+                )
+                    .into_val(&env),
+                ().into_val(&env)
+            ),
+        ]
+    );
+}
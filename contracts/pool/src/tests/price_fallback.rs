@@ -0,0 +1,345 @@
+#![cfg(test)]
+extern crate std;
+
+use pool_interface::types::price_feed_config_input::PriceFeedConfigInput;
+use pool_interface::types::price_source::PriceSource;
+use price_feed_interface::types::asset::Asset;
+use price_feed_interface::types::price_data::PriceData;
+use soroban_sdk::testutils::{Events, Ledger};
+use soroban_sdk::{contract, contractimpl, vec, Address, Env, IntoVal, Symbol};
+
+use crate::tests::sut::{
+    default_price_feed, default_price_feed_config, init_pool, record_price, set_lastprice,
+    set_time, Sut, DAY,
+};
+
+fn configure_fallback(
+    env: &Env,
+    sut: &Sut,
+    lastprice_fallback: bool,
+    stored_price_max_age: u64,
+) -> Address {
+    let asset = sut.reserves[1].token.address.clone();
+
+    sut.pool.set_price_feeds(&vec![
+        env,
+        PriceFeedConfigInput {
+            lastprice_fallback,
+            stored_price_max_age,
+            ..default_price_feed_config(env, sut)
+        },
+    ]);
+
+    asset
+}
+
+fn make_prices_stale(env: &Env, secs: u64) {
+    env.ledger().with_mut(|li| li.timestamp += secs);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #102)")]
+fn should_fail_when_fallback_is_disabled() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_fallback(&env, &sut, false, 0);
+
+    set_time(&env, &sut, DAY, false);
+    make_prices_stale(&env, 1_000);
+    set_lastprice(&env, &sut, &asset, 20_000_000_000_000_000);
+
+    sut.pool.twap_median_price(&asset, &1_000_000_000);
+}
+
+#[test]
+fn should_fallback_to_lastprice() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_fallback(&env, &sut, true, 0);
+
+    set_time(&env, &sut, DAY, false);
+    make_prices_stale(&env, 1_000);
+    set_lastprice(&env, &sut, &asset, 20_000_000_000_000_000);
+
+    assert_eq!(
+        sut.pool.twap_median_price(&asset, &1_000_000_000),
+        20_000_000
+    );
+}
+
+/// Feed reporting only the last price, so the calls for the TWAP records fail
+#[contract]
+struct LastPriceFeed;
+
+#[contractimpl]
+impl LastPriceFeed {
+    pub fn lastprice(env: Env, _asset: Asset) -> Option<PriceData> {
+        Some(PriceData {
+            price: 20_000_000_000_000_000,
+            timestamp: env.ledger().timestamp(),
+        })
+    }
+}
+
+#[test]
+fn should_fallback_to_lastprice_when_twap_calls_fail() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = sut.reserves[1].token.address.clone();
+
+    sut.pool.set_price_feeds(&vec![
+        &env,
+        PriceFeedConfigInput {
+            lastprice_fallback: true,
+            feeds: vec![
+                &env,
+                default_price_feed(
+                    &env,
+                    &env.register_contract(None, LastPriceFeed),
+                    &asset,
+                    16,
+                ),
+            ],
+            ..default_price_feed_config(&env, &sut)
+        },
+    ]);
+
+    assert_eq!(
+        sut.pool.twap_median_price(&asset, &1_000_000_000),
+        20_000_000
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #102)")]
+fn should_fail_when_lastprice_is_stale() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_fallback(&env, &sut, true, 0);
+
+    set_time(&env, &sut, DAY, false);
+    set_lastprice(&env, &sut, &asset, 20_000_000_000_000_000);
+    make_prices_stale(&env, 1_000);
+
+    sut.pool.twap_median_price(&asset, &1_000_000_000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #103)")]
+fn should_fail_when_lastprice_is_insane() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_fallback(&env, &sut, true, 0);
+
+    set_time(&env, &sut, DAY, false);
+    make_prices_stale(&env, 1_000);
+    set_lastprice(&env, &sut, &asset, 1_000_000_000_000_000_000);
+
+    sut.pool.twap_median_price(&asset, &1_000_000_000);
+}
+
+#[test]
+fn should_store_last_good_price() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_fallback(&env, &sut, false, 1_000);
+
+    set_time(&env, &sut, DAY, false);
+
+    assert!(sut.pool.last_good_price(&asset).is_none());
+
+    record_price(&env, &sut, &asset, 1_000_000_000);
+
+    let stored = sut.pool.last_good_price(&asset).unwrap();
+
+    assert_eq!(stored.price, 10_000_000);
+    assert_eq!(stored.timestamp, DAY);
+}
+
+#[test]
+fn should_not_store_price_in_views() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_fallback(&env, &sut, false, 1_000);
+
+    set_time(&env, &sut, DAY, false);
+    sut.pool.twap_median_price(&asset, &1_000_000_000);
+
+    assert!(sut.pool.last_good_price(&asset).is_none());
+}
+
+#[test]
+fn should_not_rewrite_unchanged_price() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_fallback(&env, &sut, false, 1_000);
+
+    set_time(&env, &sut, DAY, false);
+    record_price(&env, &sut, &asset, 1_000_000_000);
+
+    set_time(&env, &sut, 499, true);
+    record_price(&env, &sut, &asset, 1_000_000_000);
+
+    assert_eq!(sut.pool.last_good_price(&asset).unwrap().timestamp, DAY);
+
+    set_time(&env, &sut, 1, true);
+    record_price(&env, &sut, &asset, 1_000_000_000);
+
+    assert_eq!(
+        sut.pool.last_good_price(&asset).unwrap().timestamp,
+        DAY + 500
+    );
+}
+
+#[test]
+fn should_not_rewrite_changed_price_within_half_max_age() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_fallback(&env, &sut, true, 1_000);
+
+    set_time(&env, &sut, DAY, false);
+    record_price(&env, &sut, &asset, 1_000_000_000);
+    make_prices_stale(&env, 499);
+    set_lastprice(&env, &sut, &asset, 20_000_000_000_000_000);
+
+    assert_eq!(record_price(&env, &sut, &asset, 1_000_000_000), 20_000_000);
+
+    let stored = sut.pool.last_good_price(&asset).unwrap();
+
+    assert_eq!(stored.price, 10_000_000);
+    assert_eq!(stored.timestamp, DAY);
+}
+
+#[test]
+fn should_not_store_price_when_fallback_is_disabled() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_fallback(&env, &sut, true, 0);
+
+    set_time(&env, &sut, DAY, false);
+    record_price(&env, &sut, &asset, 1_000_000_000);
+
+    assert!(sut.pool.last_good_price(&asset).is_none());
+}
+
+#[test]
+fn should_fallback_to_stored_price() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_fallback(&env, &sut, false, 1_000);
+
+    set_time(&env, &sut, DAY, false);
+    record_price(&env, &sut, &asset, 1_000_000_000);
+    make_prices_stale(&env, 1_000);
+
+    assert_eq!(
+        sut.pool.twap_median_price(&asset, &1_000_000_000),
+        10_000_000
+    );
+}
+
+#[test]
+fn should_prefer_lastprice_to_stored_price() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_fallback(&env, &sut, true, 1_000);
+
+    set_time(&env, &sut, DAY, false);
+    record_price(&env, &sut, &asset, 1_000_000_000);
+    make_prices_stale(&env, 500);
+    set_lastprice(&env, &sut, &asset, 20_000_000_000_000_000);
+
+    assert_eq!(record_price(&env, &sut, &asset, 1_000_000_000), 20_000_000);
+    assert_eq!(sut.pool.last_good_price(&asset).unwrap().price, 20_000_000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #102)")]
+fn should_fail_when_stored_price_is_too_old() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_fallback(&env, &sut, false, 1_000);
+
+    set_time(&env, &sut, DAY, false);
+    record_price(&env, &sut, &asset, 1_000_000_000);
+    make_prices_stale(&env, 1_001);
+
+    sut.pool.twap_median_price(&asset, &1_000_000_000);
+}
+
+#[test]
+fn should_emit_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_fallback(&env, &sut, true, 1_000);
+
+    set_time(&env, &sut, DAY, false);
+
+    let events_count = env.events().all().len();
+    record_price(&env, &sut, &asset, 1_000_000_000);
+
+    assert_eq!(env.events().all().len(), events_count);
+
+    make_prices_stale(&env, 500);
+    set_lastprice(&env, &sut, &asset, 20_000_000_000_000_000);
+    record_price(&env, &sut, &asset, 1_000_000_000);
+
+    let event = env.events().all().pop_back_unchecked();
+
+    assert_eq!(
+        vec![&env, event],
+        vec![
+            &env,
+            (
+                sut.pool.address.clone(),
+                (Symbol::new(&env, "price_fallback"), 1u32, asset.clone()).into_val(&env),
+                (PriceSource::LastPrice, 20_000_000i128).into_val(&env)
+            ),
+        ]
+    );
+
+    make_prices_stale(&env, 500);
+    record_price(&env, &sut, &asset, 1_000_000_000);
+
+    let event = env.events().all().pop_back_unchecked();
+
+    assert_eq!(
+        vec![&env, event],
+        vec![
+            &env,
+            (
+                sut.pool.address.clone(),
+                (Symbol::new(&env, "price_fallback"), 1u32, asset.clone()).into_val(&env),
+                (PriceSource::StoredPrice, 20_000_000i128).into_val(&env)
+            ),
+        ]
+    );
+}
@@ -0,0 +1,194 @@
+#![cfg(test)]
+extern crate std;
+
+use pool_interface::types::oracle_asset::OracleAsset;
+use pool_interface::types::price_feed::PriceFeed;
+use pool_interface::types::price_feed_config_input::PriceFeedConfigInput;
+use pool_interface::types::price_leg::PriceLeg;
+use pool_interface::types::timestamp_precision::TimestampPrecision;
+use price_feed_interface::types::asset::Asset;
+use price_feed_interface::types::price_data::PriceData;
+use price_feed_interface::PriceFeedClient;
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::{symbol_short, vec, Address, Env, Vec};
+
+use crate::tests::sut::{
+    create_price_feed_contract, default_price_feed, default_price_feed_config, init_pool, Sut,
+};
+
+const TIMESTAMP: u64 = 1704790800;
+const STALE: u64 = TIMESTAMP - 1_000;
+
+fn init_feed(env: &Env, feed: &PriceFeedClient, asset: Asset, price: i128, timestamp: u64) {
+    feed.init(&asset, &vec![env, PriceData { price, timestamp }]);
+}
+
+/// Feed quoting the asset in XLM with 14 decimals and a leg quoting XLM in base with 10 decimals
+fn xlm_path_feed(
+    env: &Env,
+    asset: &Address,
+    price_in_xlm: i128,
+    xlm_price: i128,
+    xlm_timestamp: u64,
+) -> PriceFeed {
+    let asset_feed = create_price_feed_contract(env);
+    let xlm_feed = create_price_feed_contract(env);
+
+    init_feed(
+        env,
+        &asset_feed,
+        Asset::Stellar(asset.clone()),
+        price_in_xlm,
+        TIMESTAMP,
+    );
+    init_feed(
+        env,
+        &xlm_feed,
+        Asset::Other(symbol_short!("XLM")),
+        xlm_price,
+        xlm_timestamp,
+    );
+
+    PriceFeed {
+        path: vec![
+            env,
+            PriceLeg {
+                feed: xlm_feed.address.clone(),
+                feed_asset: OracleAsset::Other(symbol_short!("XLM")),
+                feed_decimals: 10,
+                twap_window: 3600,
+                min_twap_span: 0,
+                min_timestamp_delta: 100,
+                timestamp_precision: TimestampPrecision::Sec,
+            },
+        ],
+        ..default_price_feed(env, &asset_feed.address, asset, 14)
+    }
+}
+
+/// Feed quoting the asset in base with 7 decimals
+fn direct_feed(env: &Env, asset: &Address, price: i128) -> PriceFeed {
+    let feed = create_price_feed_contract(env);
+
+    init_feed(env, &feed, Asset::Stellar(asset.clone()), price, TIMESTAMP);
+
+    default_price_feed(env, &feed.address, asset, 7)
+}
+
+fn configure_feeds(env: &Env, sut: &Sut, feeds: Vec<PriceFeed>) {
+    sut.pool.set_price_feeds(&vec![
+        env,
+        PriceFeedConfigInput {
+            feeds,
+            ..default_price_feed_config(env, sut)
+        },
+    ]);
+}
+
+#[test]
+fn should_compose_price_through_intermediate_asset() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = TIMESTAMP);
+
+    let sut = init_pool(&env, false);
+    let asset = sut.reserves[1].token.address.clone();
+
+    // 20 XLM, 0.1 base per XLM
+    let feed = xlm_path_feed(
+        &env,
+        &asset,
+        2_000_000_000_000_000,
+        1_000_000_000,
+        TIMESTAMP,
+    );
+    configure_feeds(&env, &sut, vec![&env, feed]);
+
+    assert_eq!(
+        sut.pool.twap_median_price(&asset, &1_000_000_000),
+        20_000_000
+    );
+}
+
+#[test]
+fn should_median_direct_and_path_feeds() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = TIMESTAMP);
+
+    let sut = init_pool(&env, false);
+    let asset = sut.reserves[1].token.address.clone();
+
+    let path_feed = xlm_path_feed(
+        &env,
+        &asset,
+        2_000_000_000_000_000,
+        1_000_000_000,
+        TIMESTAMP,
+    );
+    let direct_feed = direct_feed(&env, &asset, 22_000_000);
+    configure_feeds(&env, &sut, vec![&env, path_feed, direct_feed]);
+
+    assert_eq!(
+        sut.pool.twap_median_price(&asset, &1_000_000_000),
+        21_000_000
+    );
+}
+
+#[test]
+fn should_skip_feed_when_leg_is_stale() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = TIMESTAMP);
+
+    let sut = init_pool(&env, false);
+    let asset = sut.reserves[1].token.address.clone();
+
+    let path_feed = xlm_path_feed(&env, &asset, 2_000_000_000_000_000, 1_000_000_000, STALE);
+    let direct_feed = direct_feed(&env, &asset, 22_000_000);
+    configure_feeds(&env, &sut, vec![&env, path_feed, direct_feed]);
+
+    assert_eq!(
+        sut.pool.twap_median_price(&asset, &1_000_000_000),
+        22_000_000
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #102)")]
+fn should_fail_when_leg_is_stale() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = TIMESTAMP);
+
+    let sut = init_pool(&env, false);
+    let asset = sut.reserves[1].token.address.clone();
+
+    let feed = xlm_path_feed(&env, &asset, 2_000_000_000_000_000, 1_000_000_000, STALE);
+    configure_feeds(&env, &sut, vec![&env, feed]);
+
+    sut.pool.twap_median_price(&asset, &1_000_000_000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #103)")]
+fn should_check_sanity_of_composed_price() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = TIMESTAMP);
+
+    let sut = init_pool(&env, false);
+    let asset = sut.reserves[1].token.address.clone();
+
+    // 20 XLM, 10 base per XLM
+    let feed = xlm_path_feed(
+        &env,
+        &asset,
+        2_000_000_000_000_000,
+        100_000_000_000,
+        TIMESTAMP,
+    );
+    configure_feeds(&env, &sut, vec![&env, feed]);
+
+    sut.pool.twap_median_price(&asset, &1_000_000_000);
+}
@@ -0,0 +1,80 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::tests::sut::init_pool;
+use crate::*;
+use pool_interface::types::role::Role;
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events};
+use soroban_sdk::{vec, IntoVal, Symbol};
+
+#[test]
+fn should_require_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let new_admin = Address::generate(&env);
+
+    sut.pool.propose_admin(&new_admin);
+
+    assert_eq!(
+        env.auths(),
+        [(
+            sut.pool_admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    sut.pool.address.clone(),
+                    Symbol::new(&env, "propose_admin"),
+                    (new_admin.clone(),).into_val(&env)
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+}
+
+#[test]
+fn should_set_pending_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let new_admin = Address::generate(&env);
+
+    assert!(sut.pool.pending_admin().is_none());
+
+    sut.pool.propose_admin(&new_admin);
+
+    assert_eq!(sut.pool.pending_admin(), Some(new_admin));
+    assert_eq!(sut.pool.role(&Role::PoolAdmin), sut.pool_admin);
+}
+
+#[test]
+fn should_emit_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let new_admin = Address::generate(&env);
+
+    sut.pool.propose_admin(&new_admin);
+
+    let event = env.events().all().pop_back_unchecked();
+
+    assert_eq!(
+        vec![&env, event],
+        vec![
+            &env,
+            (
+                sut.pool.address.clone(),
+                (
+                    Symbol::new(&env, "admin_proposed"),
+                    1u32,
+                    sut.pool_admin.clone()
+                )
+                    .into_val(&env),
+                new_admin.into_val(&env)
+            ),
+        ]
+    );
+}
@@ -4,7 +4,7 @@ extern crate std;
 use pool_interface::types::pool_config::PoolConfig;
 use price_feed_interface::types::{asset::Asset, price_data::PriceData};
 use soroban_sdk::{
-    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation},
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events},
     vec, Address, Env, IntoVal, Symbol,
 };
 
@@ -209,3 +209,36 @@ fn should_claim_fee_rwa() {
     assert_eq!(pool_rwa_before - pool_rwa_after, fee_before);
     assert_eq!(fee_after, 0);
 }
+
+#[test]
+fn should_emit_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, borrower, debt_config) = fill_pool(&env, &sut, true);
+    let expected_fee = generate_protocol_fee(&env, &sut, &debt_config.token.address, &borrower);
+    let recipient = Address::generate(&env);
+
+    sut.pool
+        .claim_protocol_fee(&debt_config.token.address, &recipient);
+
+    let event = env.events().all().pop_back_unchecked();
+
+    assert_eq!(
+        vec![&env, event],
+        vec![
+            &env,
+            (
+                sut.pool.address.clone(),
+                (
+                    Symbol::new(&env, "protocol_fee_claimed"),
+                    1u32,
+                    debt_config.token.address.clone()
+                )
+                    .into_val(&env),
+                (recipient, expected_fee).into_val(&env)
+            ),
+        ]
+    );
+}
@@ -172,6 +172,7 @@ fn should_calc_borrower_and_lender_rates() {
         DAY,
         &pool_config,
         &reserve_data,
+        FixedI128::ZERO,
     )
     .unwrap();
 
@@ -186,6 +187,34 @@ fn should_calc_borrower_and_lender_rates() {
     assert_eq!(accrued_rates.lender_ar.into_inner(), 1000067805);
 }
 
+#[test]
+fn should_add_ir_premium_to_borrower_rate() {
+    let env = &Env::default();
+
+    let input = ReserveType::Fungible(Address::generate(env), Address::generate(env));
+    let reserve_data = ReserveData::new(env, input);
+    let pool_config = get_default_ir_params(env);
+
+    let accrued_rates =
+        calc_accrued_rates(100, 20, DAY, &pool_config, &reserve_data, FixedI128::ZERO).unwrap();
+    let accrued_rates_with_premium = calc_accrued_rates(
+        100,
+        20,
+        DAY,
+        &pool_config,
+        &reserve_data,
+        FixedI128::from_percentage(1_000).unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        accrued_rates_with_premium.borrower_ir.into_inner(),
+        accrued_rates.borrower_ir.into_inner() + 100_000_000
+    );
+    assert!(accrued_rates_with_premium.lender_ir > accrued_rates.lender_ir);
+    assert!(accrued_rates_with_premium.borrower_ar > accrued_rates.borrower_ar);
+}
+
 #[test]
 fn should_fail_when_collateral_is_zero() {
     let env = &Env::default();
@@ -203,6 +232,7 @@ fn should_fail_when_collateral_is_zero() {
         DAY,
         &pool_config,
         &reserve_data,
+        FixedI128::ZERO,
     );
     assert!(mb_accrued_rates.is_none());
 }
@@ -0,0 +1,162 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::tests::sut::{fill_pool, init_pool, remove_collateral, set_time, DAY};
+use crate::*;
+use soroban_sdk::testutils::{AuthorizedFunction, AuthorizedInvocation};
+use soroban_sdk::{IntoVal, Symbol};
+
+#[test]
+fn should_require_authorized_caller() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (lender, _, debt_config) = fill_pool(&env, &sut, false);
+    let asset = debt_config.token.address.clone();
+
+    sut.pool.start_wind_down(&1_000, &DAY);
+    sut.pool.start_settlement();
+    sut.pool.redeem(&lender, &asset, &lender);
+
+    assert_eq!(
+        env.auths()[0],
+        (
+            lender.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    sut.pool.address.clone(),
+                    Symbol::new(&env, "redeem"),
+                    (lender.clone(), asset.clone(), lender.clone()).into_val(&env)
+                )),
+                sub_invocations: std::vec![]
+            }
+        )
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #8)")]
+fn should_fail_before_settlement() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (lender, _, debt_config) = fill_pool(&env, &sut, true);
+
+    sut.pool.start_wind_down(&1_000, &DAY);
+    sut.pool
+        .redeem(&lender, &debt_config.token.address, &lender);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn should_disable_withdraw_at_settlement() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (lender, _, debt_config) = fill_pool(&env, &sut, false);
+
+    sut.pool.start_wind_down(&1_000, &DAY);
+    sut.pool.start_settlement();
+    sut.pool
+        .withdraw(&lender, &debt_config.token.address, &1_000, &lender);
+}
+
+#[test]
+fn should_redeem_pro_rata() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (lender, borrower, debt_config) = fill_pool(&env, &sut, true);
+    let asset = debt_config.token.address.clone();
+    let s_token = debt_config.s_token();
+
+    sut.pool.start_wind_down(&1_000, &DAY);
+    set_time(&env, &sut, 10 * DAY, false);
+    sut.pool.repay(&borrower, &asset, &i128::MAX);
+    sut.pool.start_settlement();
+
+    let s_token_supply = s_token.total_supply();
+    let lender_s_token_balance = s_token.balance(&lender);
+    // the protocol fee part of the repaid interest stays on the s-token but isn't redeemable
+    let s_token_cash = debt_config.token.balance(&s_token.address);
+    let s_token_underlying = sut.pool.token_balance(&asset, &s_token.address);
+    let lender_underlying_before = debt_config.token.balance(&lender);
+
+    sut.pool.redeem(&lender, &asset, &lender);
+
+    let redeemed = debt_config.token.balance(&lender) - lender_underlying_before;
+
+    // the debt is repaid with the interest, so the lender gets its share of all of it
+    assert_eq!(
+        redeemed,
+        lender_s_token_balance * s_token_underlying / s_token_supply
+    );
+    assert_eq!(s_token.balance(&lender), 0);
+    assert_eq!(
+        s_token.total_supply(),
+        s_token_supply - lender_s_token_balance
+    );
+    assert_eq!(
+        debt_config.token.balance(&s_token.address),
+        s_token_cash - redeemed
+    );
+    assert_eq!(
+        sut.pool.token_balance(&asset, &s_token.address),
+        s_token_underlying - redeemed
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #201)")]
+fn should_fail_with_outstanding_debt() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (lender, _, debt_config) = fill_pool(&env, &sut, true);
+
+    sut.pool.start_wind_down(&1_000, &DAY);
+    sut.pool.start_settlement();
+    sut.pool
+        .redeem(&lender, &debt_config.token.address, &lender);
+}
+
+#[test]
+fn should_redeem_after_debt_is_written_off() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (lender, borrower, debt_config) = fill_pool(&env, &sut, true);
+    let asset = debt_config.token.address.clone();
+    let s_token = debt_config.s_token();
+
+    sut.pool.start_wind_down(&1_000, &DAY);
+    sut.pool.start_settlement();
+    remove_collateral(&env, &sut, &borrower);
+
+    let collat_coeff = sut.pool.collat_coeff(&asset);
+
+    sut.pool.write_off_debt(&borrower, &asset);
+
+    let s_token_supply = s_token.total_supply();
+    let lender_s_token_balance = s_token.balance(&lender);
+    let s_token_underlying = sut.pool.token_balance(&asset, &s_token.address);
+    let lender_underlying_before = debt_config.token.balance(&lender);
+
+    sut.pool.redeem(&lender, &asset, &lender);
+
+    let redeemed = debt_config.token.balance(&lender) - lender_underlying_before;
+
+    // the lender bears its share of the written off debt
+    assert_eq!(
+        redeemed,
+        lender_s_token_balance * s_token_underlying / s_token_supply
+    );
+    assert!(redeemed < lender_s_token_balance * collat_coeff / 1_000_000_000);
+    assert_eq!(s_token.balance(&lender), 0);
+}
@@ -175,8 +175,14 @@ fn should_emit_events() {
 
     sut.pool.repay(&borrower, &debt_token.clone(), &i128::MAX);
 
-    let event = env.events().all().pop_back_unchecked();
+    let mut events = env.events().all();
+    let reserve_updated = events.pop_back_unchecked();
+    let event = events.pop_back_unchecked();
 
+    assert_eq!(
+        reserve_updated.1,
+        (Symbol::new(&env, "reserve_updated"), debt_token.clone()).into_val(&env)
+    );
     assert_eq!(
         vec![&env, event],
         vec![
@@ -259,8 +265,8 @@ fn should_not_fail_in_grace_period() {
     assert_eq!(treasury_balance, 0);
     assert_eq!(user_debt_balance, 40_000_001);
 
-    sut.pool.set_pause(&true);
-    sut.pool.set_pause(&false);
+    sut.pool.set_pause(&sut.pool_admin, &true);
+    sut.pool.set_pause(&sut.pool_admin, &false);
 
     sut.pool.repay(&borrower, &debt_token, &i128::MAX);
 
@@ -0,0 +1,106 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::tests::sut::init_pool;
+use crate::*;
+use pool_interface::types::role::Role;
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events};
+use soroban_sdk::{vec, IntoVal, Symbol};
+
+#[test]
+fn should_require_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let treasury = Address::generate(&env);
+
+    sut.pool.grant_role(&Role::Treasury, &treasury);
+    sut.pool.revoke_role(&Role::Treasury);
+
+    assert_eq!(
+        env.auths(),
+        [(
+            sut.pool_admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    sut.pool.address.clone(),
+                    Symbol::new(&env, "revoke_role"),
+                    (Role::Treasury,).into_val(&env)
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn should_fail_when_revoking_pool_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    sut.pool.revoke_role(&Role::PoolAdmin);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn should_fail_when_role_not_granted() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    sut.pool.revoke_role(&Role::RiskAdmin);
+}
+
+#[test]
+fn should_return_role_to_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let guardian = Address::generate(&env);
+
+    sut.pool.grant_role(&Role::EmergencyGuardian, &guardian);
+    sut.pool.revoke_role(&Role::EmergencyGuardian);
+
+    assert_eq!(sut.pool.role(&Role::EmergencyGuardian), sut.pool_admin);
+
+    sut.pool.set_pause(&sut.pool_admin, &true);
+
+    assert_eq!(env.auths().first().unwrap().0, sut.pool_admin);
+}
+
+#[test]
+fn should_emit_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let guardian = Address::generate(&env);
+
+    sut.pool.grant_role(&Role::EmergencyGuardian, &guardian);
+    sut.pool.revoke_role(&Role::EmergencyGuardian);
+
+    let event = env.events().all().pop_back_unchecked();
+
+    assert_eq!(
+        vec![&env, event],
+        vec![
+            &env,
+            (
+                sut.pool.address.clone(),
+                (
+                    Symbol::new(&env, "role_revoked"),
+                    1u32,
+                    Role::EmergencyGuardian
+                )
+                    .into_val(&env),
+                guardian.into_val(&env)
+            ),
+        ]
+    );
+}
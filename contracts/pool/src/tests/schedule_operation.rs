@@ -0,0 +1,141 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::storage::DataKey;
+use crate::tests::sut::init_pool;
+use crate::*;
+use pool_interface::types::role::Role;
+use pool_interface::types::timelock_operation::TimelockOperation;
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{vec, IntoVal, Symbol};
+
+#[test]
+fn should_require_operation_role() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let oracle_admin = Address::generate(&env);
+    let eta = env.ledger().timestamp();
+
+    sut.pool.grant_role(&Role::OracleAdmin, &oracle_admin);
+
+    sut.pool
+        .schedule_operation(&TimelockOperation::SetPriceFeeds(Vec::new(&env)), &eta);
+    assert_eq!(env.auths().first().unwrap().0, oracle_admin);
+
+    sut.pool.schedule_operation(
+        &TimelockOperation::SetPoolConfiguration(sut.pool.pool_configuration()),
+        &eta,
+    );
+    assert_eq!(env.auths().first().unwrap().0, sut.pool_admin);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #601)")]
+fn should_fail_when_eta_before_delay() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    sut.pool.set_timelock_delay(&3600);
+    sut.pool.schedule_operation(
+        &TimelockOperation::SetTimelockDelay(0),
+        &(env.ledger().timestamp() + 3599),
+    );
+}
+
+#[test]
+fn should_queue_operation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let eta = env.ledger().timestamp() + 3600;
+
+    sut.pool.set_timelock_delay(&3600);
+
+    let first_id = sut
+        .pool
+        .schedule_operation(&TimelockOperation::SetTimelockDelay(60), &eta);
+    let second_id = sut
+        .pool
+        .schedule_operation(&TimelockOperation::SetTimelockDelay(120), &(eta + 1));
+
+    assert_eq!(first_id, 0);
+    assert_eq!(second_id, 1);
+
+    let queue = sut.pool.queued_operations();
+
+    assert_eq!(queue.len(), 2);
+    assert_eq!(queue.get_unchecked(0).id, first_id);
+    assert_eq!(queue.get_unchecked(0).eta, eta);
+    assert_eq!(queue.get_unchecked(1).id, second_id);
+    assert_eq!(queue.get_unchecked(1).eta, eta + 1);
+}
+
+#[test]
+fn should_store_operations_outside_instance_storage() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let eta = env.ledger().timestamp();
+
+    let id = sut
+        .pool
+        .schedule_operation(&TimelockOperation::SetTimelockDelay(60), &eta);
+
+    env.as_contract(&sut.pool.address, || {
+        let queue: Vec<u32> = env
+            .storage()
+            .instance()
+            .get(&DataKey::TimelockQueue)
+            .unwrap();
+
+        assert_eq!(queue, vec![&env, id]);
+        assert!(env
+            .storage()
+            .persistent()
+            .has(&DataKey::TimelockOperation(id)));
+    });
+
+    sut.pool.execute_operation(&id);
+
+    env.as_contract(&sut.pool.address, || {
+        assert!(!env
+            .storage()
+            .persistent()
+            .has(&DataKey::TimelockOperation(id)));
+    });
+    assert!(sut.pool.queued_operations().is_empty());
+}
+
+#[test]
+fn should_emit_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+
+    let id = sut
+        .pool
+        .schedule_operation(&TimelockOperation::SetTimelockDelay(60), &2000);
+
+    let event = env.events().all().pop_back_unchecked();
+
+    assert_eq!(
+        vec![&env, event],
+        vec![
+            &env,
+            (
+                sut.pool.address.clone(),
+                (Symbol::new(&env, "operation_scheduled"), 1u32, id).into_val(&env),
+                2000u64.into_val(&env)
+            ),
+        ]
+    );
+}
@@ -0,0 +1,286 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::storage::{DataKey, LegacyPauseInfo};
+use crate::tests::sut::{empty_migration_input, fill_pool, init_pool, set_time, DAY};
+use crate::*;
+use soroban_sdk::testutils::{AuthorizedFunction, AuthorizedInvocation, Events};
+use soroban_sdk::{vec, IntoVal, Symbol, TryFromVal, Val};
+
+#[test]
+fn should_require_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    sut.pool.set_accrual_suspension(&true);
+
+    assert_eq!(
+        env.auths(),
+        [(
+            sut.pool_admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    sut.pool.address.clone(),
+                    Symbol::new(&env, "set_accrual_suspension"),
+                    (true,).into_val(&env)
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+}
+
+#[test]
+fn should_not_accrue_while_paused() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, _, debt_config) = fill_pool(&env, &sut, true);
+    let debt_asset = debt_config.token.address.clone();
+
+    sut.pool.set_accrual_suspension(&true);
+    set_time(&env, &sut, 2 * DAY, false);
+
+    let debt_coeff_before = sut.pool.debt_coeff(&debt_asset);
+    let collat_coeff_before = sut.pool.collat_coeff(&debt_asset);
+
+    sut.pool.set_pause(&sut.pool_admin, &true);
+    set_time(&env, &sut, 12 * DAY, false);
+
+    assert_eq!(sut.pool.debt_coeff(&debt_asset), debt_coeff_before);
+    assert_eq!(sut.pool.collat_coeff(&debt_asset), collat_coeff_before);
+
+    sut.pool.set_pause(&sut.pool_admin, &false);
+
+    assert_eq!(sut.pool.debt_coeff(&debt_asset), debt_coeff_before);
+    assert_eq!(sut.pool.collat_coeff(&debt_asset), collat_coeff_before);
+
+    set_time(&env, &sut, 13 * DAY, false);
+
+    assert!(sut.pool.debt_coeff(&debt_asset) > debt_coeff_before);
+}
+
+#[test]
+fn should_accrue_while_paused_when_disabled() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, _, debt_config) = fill_pool(&env, &sut, true);
+    let debt_asset = debt_config.token.address.clone();
+
+    set_time(&env, &sut, 2 * DAY, false);
+
+    let debt_coeff_before = sut.pool.debt_coeff(&debt_asset);
+
+    sut.pool.set_pause(&sut.pool_admin, &true);
+    set_time(&env, &sut, 12 * DAY, false);
+
+    assert!(sut.pool.debt_coeff(&debt_asset) > debt_coeff_before);
+}
+
+#[test]
+fn should_exclude_every_pause() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, _, debt_config) = fill_pool(&env, &sut, true);
+    let debt_asset = debt_config.token.address.clone();
+
+    sut.pool.set_accrual_suspension(&true);
+    set_time(&env, &sut, 2 * DAY, false);
+
+    let debt_coeff_before = sut.pool.debt_coeff(&debt_asset);
+
+    for i in 0..3 {
+        sut.pool.set_pause(&sut.pool_admin, &true);
+        set_time(&env, &sut, (3 + i) * DAY, false);
+        sut.pool.set_pause(&sut.pool_admin, &false);
+    }
+
+    assert_eq!(sut.pool.debt_coeff(&debt_asset), debt_coeff_before);
+}
+
+#[test]
+fn should_accrue_until_enabled_while_paused() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, _, debt_config) = fill_pool(&env, &sut, true);
+    let debt_asset = debt_config.token.address.clone();
+    let last_update_before = sut
+        .pool
+        .get_reserve(&debt_asset)
+        .unwrap()
+        .last_update_timestamp;
+
+    set_time(&env, &sut, 2 * DAY, false);
+    sut.pool.set_pause(&sut.pool_admin, &true);
+
+    set_time(&env, &sut, 6 * DAY, false);
+    sut.pool.set_accrual_suspension(&true);
+
+    assert_eq!(sut.pool.pause_info().paused_at, 6 * DAY);
+
+    set_time(&env, &sut, 12 * DAY, false);
+    sut.pool.set_pause(&sut.pool_admin, &false);
+
+    assert_eq!(
+        sut.pool
+            .get_reserve(&debt_asset)
+            .unwrap()
+            .last_update_timestamp,
+        last_update_before + 6 * DAY
+    );
+}
+
+#[test]
+fn should_skip_suspended_time_when_disabled_while_paused() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, _, debt_config) = fill_pool(&env, &sut, true);
+    let debt_asset = debt_config.token.address.clone();
+    let last_update_before = sut
+        .pool
+        .get_reserve(&debt_asset)
+        .unwrap()
+        .last_update_timestamp;
+
+    sut.pool.set_accrual_suspension(&true);
+
+    set_time(&env, &sut, 2 * DAY, false);
+    sut.pool.set_pause(&sut.pool_admin, &true);
+
+    set_time(&env, &sut, 6 * DAY, false);
+    sut.pool.set_accrual_suspension(&false);
+
+    assert_eq!(
+        sut.pool
+            .get_reserve(&debt_asset)
+            .unwrap()
+            .last_update_timestamp,
+        last_update_before + 4 * DAY
+    );
+
+    set_time(&env, &sut, 12 * DAY, false);
+    sut.pool.set_pause(&sut.pool_admin, &false);
+
+    assert_eq!(
+        sut.pool
+            .get_reserve(&debt_asset)
+            .unwrap()
+            .last_update_timestamp,
+        last_update_before + 4 * DAY
+    );
+}
+
+#[test]
+fn should_emit_reserve_updated_on_unpause() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, _, debt_config) = fill_pool(&env, &sut, true);
+    let debt_asset = debt_config.token.address.clone();
+
+    sut.pool.set_accrual_suspension(&true);
+    sut.pool.set_pause(&sut.pool_admin, &true);
+    set_time(&env, &sut, 12 * DAY, false);
+    sut.pool.set_pause(&sut.pool_admin, &false);
+
+    let reserve = sut.pool.get_reserve(&debt_asset).unwrap();
+    let topics: Vec<Val> = (Symbol::new(&env, "reserve_updated"), debt_asset).into_val(&env);
+    let (_, _, data) = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, event_topics, _)| event_topics == &topics)
+        .last()
+        .unwrap();
+    let (lender_ar, borrower_ar, _, _, _, last_update_timestamp) =
+        <(i128, i128, i128, i128, i128, u64)>::try_from_val(&env, &data).unwrap();
+
+    assert_eq!(lender_ar, reserve.lender_ar);
+    assert_eq!(borrower_ar, reserve.borrower_ar);
+    assert_eq!(last_update_timestamp, reserve.last_update_timestamp);
+}
+
+fn set_legacy_pause_info(env: &Env, pool: &Address) {
+    env.as_contract(pool, || {
+        env.storage().instance().set(
+            &DataKey::Pause,
+            &LegacyPauseInfo {
+                paused: true,
+                grace_period_secs: 1,
+                unpaused_at: 10,
+            },
+        );
+        env.storage().instance().remove(&DataKey::StorageVersion);
+    });
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(WasmVm, InvalidAction)")]
+fn should_fail_to_read_legacy_pause_info() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    set_legacy_pause_info(&env, &sut.pool.address);
+
+    sut.pool.pause_info();
+}
+
+#[test]
+fn should_read_migrated_legacy_pause_info() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    set_legacy_pause_info(&env, &sut.pool.address);
+
+    sut.pool.migrate(&0, &empty_migration_input(&env));
+
+    let pause_info = sut.pool.pause_info();
+
+    assert!(pause_info.paused);
+    assert_eq!(pause_info.grace_period_secs, 1);
+    assert_eq!(pause_info.unpaused_at, 10);
+    assert_eq!(pause_info.paused_at, 0);
+    assert!(!pause_info.suspend_accrual);
+
+    sut.pool.set_accrual_suspension(&true);
+
+    assert!(sut.pool.pause_info().suspend_accrual);
+}
+
+#[test]
+fn should_emit_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    sut.pool.set_accrual_suspension(&true);
+
+    let event = env.events().all().pop_back_unchecked();
+
+    assert_eq!(
+        vec![&env, event],
+        vec![
+            &env,
+            (
+                sut.pool.address.clone(),
+                (Symbol::new(&env, "accrual_suspension_changed"), 1u32).into_val(&env),
+                true.into_val(&env)
+            ),
+        ]
+    );
+}
@@ -0,0 +1,158 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::tests::sut::{fill_pool, init_pool};
+use crate::*;
+use pool_interface::types::pause_action::PauseAction;
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events};
+use soroban_sdk::{vec, IntoVal, Symbol};
+
+#[test]
+fn should_require_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    sut.pool
+        .set_action_pause(&sut.pool_admin, &PauseAction::Borrow, &true);
+
+    assert_eq!(
+        env.auths(),
+        [(
+            sut.pool_admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    sut.pool.address.clone(),
+                    Symbol::new(&env, "set_action_pause"),
+                    (sut.pool_admin.clone(), PauseAction::Borrow, true).into_val(&env)
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+}
+
+#[test]
+fn should_allow_guardian_to_pause() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let guardian = Address::generate(&env);
+
+    sut.pool.grant_role(&Role::EmergencyGuardian, &guardian);
+    sut.pool
+        .set_action_pause(&guardian, &PauseAction::Deposit, &true);
+
+    assert_eq!(env.auths()[0].0, guardian);
+
+    sut.pool
+        .set_action_pause(&sut.pool_admin, &PauseAction::Deposit, &false);
+
+    assert_eq!(env.auths()[0].0, sut.pool_admin);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #2)")]
+fn should_fail_deposit_when_paused() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let user = Address::generate(&env);
+
+    sut.reserves[1].token_admin.mint(&user, &1_000_000);
+    sut.pool
+        .set_action_pause(&sut.pool_admin, &PauseAction::Deposit, &true);
+
+    sut.pool
+        .deposit(&user, &sut.reserves[1].token.address, &1_000_000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #2)")]
+fn should_fail_liquidation_when_paused() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, borrower, _) = fill_pool(&env, &sut, true);
+    let liquidator = Address::generate(&env);
+
+    sut.pool
+        .set_action_pause(&sut.pool_admin, &PauseAction::Liquidate, &true);
+
+    sut.pool.liquidate(&liquidator, &borrower);
+}
+
+#[test]
+fn should_not_block_other_actions() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, borrower, debt_config) = fill_pool(&env, &sut, true);
+
+    sut.pool
+        .set_action_pause(&sut.pool_admin, &PauseAction::Borrow, &true);
+
+    sut.pool
+        .repay(&borrower, &debt_config.token.address, &i128::MAX);
+    sut.pool.withdraw(
+        &borrower,
+        &sut.reserves[0].token.address,
+        &100_000,
+        &borrower,
+    );
+}
+
+#[test]
+fn should_unpause() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let user = Address::generate(&env);
+
+    sut.reserves[1].token_admin.mint(&user, &1_000_000);
+    sut.pool
+        .set_action_pause(&sut.pool_admin, &PauseAction::Deposit, &true);
+    sut.pool
+        .set_action_pause(&sut.pool_admin, &PauseAction::Deposit, &false);
+
+    sut.pool
+        .deposit(&user, &sut.reserves[1].token.address, &1_000_000);
+
+    assert_eq!(sut.reserves[1].s_token().balance(&user), 1_000_000);
+}
+
+#[test]
+fn should_emit_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    sut.pool
+        .set_action_pause(&sut.pool_admin, &PauseAction::FlashLoan, &true);
+
+    let event = env.events().all().pop_back_unchecked();
+
+    assert_eq!(
+        vec![&env, event],
+        vec![
+            &env,
+            (
+                sut.pool.address.clone(),
+                (
+                    Symbol::new(&env, "action_pause_changed"),
+                    1u32,
+                    PauseAction::FlashLoan
+                )
+                    .into_val(&env),
+                true.into_val(&env)
+            ),
+        ]
+    );
+}
@@ -3,7 +3,7 @@ extern crate std;
 
 use crate::tests::sut::init_pool;
 use crate::*;
-use soroban_sdk::testutils::{AuthorizedFunction, AuthorizedInvocation};
+use soroban_sdk::testutils::{AuthorizedFunction, AuthorizedInvocation, Events};
 use soroban_sdk::{vec, IntoVal, Symbol};
 use tests::sut::{set_time, DAY};
 
@@ -14,17 +14,17 @@ fn should_require_admin() {
 
     let sut = init_pool(&env, false);
 
-    sut.pool.set_pause(&true);
+    sut.pool.set_pause(&sut.pool_admin, &true);
 
     assert_eq!(
         env.auths(),
         [(
-            sut.pool_admin,
+            sut.pool_admin.clone(),
             AuthorizedInvocation {
                 function: AuthorizedFunction::Contract((
                     sut.pool.address.clone(),
                     Symbol::new(&env, "set_pause"),
-                    vec![&env, true.into_val(&env)]
+                    (sut.pool_admin.clone(), true).into_val(&env)
                 )),
                 sub_invocations: std::vec![]
             }
@@ -40,7 +40,7 @@ fn should_set_pause() {
     let sut = init_pool(&env, false);
     let prev_pause_info = sut.pool.pause_info();
 
-    sut.pool.set_pause(&true);
+    sut.pool.set_pause(&sut.pool_admin, &true);
     let pause_info = sut.pool.pause_info();
     assert!(pause_info.paused);
     assert_eq!(
@@ -52,7 +52,7 @@ fn should_set_pause() {
     // change current time and check unpaused_at
     set_time(&env, &sut, 2 * DAY, false);
     let expected_unpaused_at = env.ledger().timestamp();
-    sut.pool.set_pause(&false);
+    sut.pool.set_pause(&sut.pool_admin, &false);
     let pause_info = sut.pool.pause_info();
     assert!(!pause_info.paused);
     assert_eq!(
@@ -62,3 +62,43 @@ fn should_set_pause() {
     assert_eq!(expected_unpaused_at, pause_info.unpaused_at);
     assert!(prev_pause_info.unpaused_at < pause_info.unpaused_at);
 }
+
+#[test]
+fn should_emit_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    sut.pool.set_pause(&sut.pool_admin, &true);
+
+    let event = env.events().all().pop_back_unchecked();
+
+    assert_eq!(
+        vec![&env, event],
+        vec![
+            &env,
+            (
+                sut.pool.address.clone(),
+                (Symbol::new(&env, "pause_changed"), 1u32).into_val(&env),
+                (false, true).into_val(&env)
+            ),
+        ]
+    );
+
+    sut.pool.set_pause(&sut.pool_admin, &false);
+
+    let event = env.events().all().pop_back_unchecked();
+
+    assert_eq!(
+        vec![&env, event],
+        vec![
+            &env,
+            (
+                sut.pool.address.clone(),
+                (Symbol::new(&env, "pause_changed"), 1u32).into_val(&env),
+                (true, false).into_val(&env)
+            ),
+        ]
+    );
+}
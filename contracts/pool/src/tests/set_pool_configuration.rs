@@ -1,7 +1,7 @@
 #![cfg(test)]
 extern crate std;
 
-use soroban_sdk::testutils::{AuthorizedFunction, AuthorizedInvocation};
+use soroban_sdk::testutils::{AuthorizedFunction, AuthorizedInvocation, Events};
 use soroban_sdk::{vec, IntoVal, Symbol};
 
 use crate::tests::sut::init_pool;
@@ -154,3 +154,34 @@ fn should_set_pool_configuration() {
 //     assert_eq!(prev_pause_info.paused, pause_info.paused);
 //     assert_eq!(prev_pause_info.unpaused_at, pause_info.unpaused_at);
 // }
+
+#[test]
+fn should_emit_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    let prev_config = sut.pool.pool_configuration();
+    let config = PoolConfig {
+        flash_loan_fee: 12,
+        grace_period: 3,
+        ..prev_config.clone()
+    };
+
+    sut.pool.set_pool_configuration(&config);
+
+    let event = env.events().all().pop_back_unchecked();
+
+    assert_eq!(
+        vec![&env, event],
+        vec![
+            &env,
+            (
+                sut.pool.address.clone(),
+                (Symbol::new(&env, "pool_configuration_changed"), 1u32).into_val(&env),
+                (Some(prev_config), config).into_val(&env)
+            ),
+        ]
+    );
+}
@@ -5,9 +5,10 @@ use crate::tests::sut::{create_pool_contract, create_price_feed_contract};
 use crate::*;
 use pool_interface::types::oracle_asset::OracleAsset;
 use pool_interface::types::price_feed::PriceFeed;
+use pool_interface::types::price_feed_config::PriceFeedConfig;
 use pool_interface::types::timestamp_precision::TimestampPrecision;
 use price_feed_interface::PriceFeedClient;
-use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation};
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events};
 use soroban_sdk::{symbol_short, vec, IntoVal, Symbol};
 
 #[test]
@@ -33,15 +34,26 @@ fn should_require_admin() {
                 asset_decimals: 7,
                 min_sanity_price_in_base: 5_000_000,
                 max_sanity_price_in_base: 100_000_000,
+                lastprice_fallback: false,
+                stored_price_max_age: 0,
+                max_feed_deviation: 0,
+                min_feeds: 1,
+                max_price_change: 0,
+                price_change_window: 0,
+                breaker_timeout: 0,
+                conservative_pricing: false,
                 feeds: vec![
                     &env,
                     PriceFeed {
                         feed: price_feed.address.clone(),
                         feed_asset: OracleAsset::Stellar(asset_1),
                         feed_decimals: 14,
-                        twap_records: 10,
+                        twap_window: 3600,
+                        min_twap_span: 0,
                         min_timestamp_delta: 100,
                         timestamp_precision: TimestampPrecision::Sec,
+                        path: vec![&env],
+                        weight: None,
                     },
                 ],
             },
@@ -50,15 +62,26 @@ fn should_require_admin() {
                 asset_decimals: 9,
                 min_sanity_price_in_base: 5_000_000,
                 max_sanity_price_in_base: 100_000_000,
+                lastprice_fallback: false,
+                stored_price_max_age: 0,
+                max_feed_deviation: 0,
+                min_feeds: 1,
+                max_price_change: 0,
+                price_change_window: 0,
+                breaker_timeout: 0,
+                conservative_pricing: false,
                 feeds: vec![
                     &env,
                     PriceFeed {
                         feed: price_feed.address.clone(),
                         feed_asset: OracleAsset::Stellar(asset_2),
                         feed_decimals: 16,
-                        twap_records: 10,
+                        twap_window: 3600,
+                        min_twap_span: 0,
                         min_timestamp_delta: 100,
                         timestamp_precision: TimestampPrecision::Sec,
+                        path: vec![&env],
+                        weight: None,
                     },
                 ],
             },
@@ -107,15 +130,26 @@ fn should_set_price_feed() {
                 asset_decimals: 7,
                 min_sanity_price_in_base: 5_000_000,
                 max_sanity_price_in_base: 100_000_000,
+                lastprice_fallback: false,
+                stored_price_max_age: 0,
+                max_feed_deviation: 0,
+                min_feeds: 1,
+                max_price_change: 0,
+                price_change_window: 0,
+                breaker_timeout: 0,
+                conservative_pricing: false,
                 feeds: vec![
                     &env,
                     PriceFeed {
                         feed: price_feed_1.address.clone(),
                         feed_asset: OracleAsset::Stellar(asset_1.clone()),
                         feed_decimals: 14,
-                        twap_records: 10,
+                        twap_window: 3600,
+                        min_twap_span: 0,
                         min_timestamp_delta: 100,
                         timestamp_precision: TimestampPrecision::Sec,
+                        path: vec![&env],
+                        weight: None,
                     },
                 ],
             },
@@ -124,15 +158,26 @@ fn should_set_price_feed() {
                 asset_decimals: 9,
                 min_sanity_price_in_base: 5_000_000,
                 max_sanity_price_in_base: 100_000_000,
+                lastprice_fallback: false,
+                stored_price_max_age: 0,
+                max_feed_deviation: 0,
+                min_feeds: 1,
+                max_price_change: 0,
+                price_change_window: 0,
+                breaker_timeout: 0,
+                conservative_pricing: false,
                 feeds: vec![
                     &env,
                     PriceFeed {
                         feed: price_feed_2.address.clone(),
                         feed_asset: OracleAsset::Other(symbol_short!("XRP")),
                         feed_decimals: 16,
-                        twap_records: 9,
+                        twap_window: 2700,
+                        min_twap_span: 0,
                         min_timestamp_delta: 100,
                         timestamp_precision: TimestampPrecision::Sec,
+                        path: vec![&env],
+                        weight: None,
                     },
                 ],
             },
@@ -154,7 +199,7 @@ fn should_set_price_feed() {
         _ => false,
     });
     assert_eq!(feed_1.feeds.get_unchecked(0).feed_decimals, 14);
-    assert_eq!(feed_1.feeds.get_unchecked(0).twap_records, 10);
+    assert_eq!(feed_1.feeds.get_unchecked(0).twap_window, 3600);
 
     assert_eq!(feed_2.asset_decimals, 9);
     assert_eq!(
@@ -166,5 +211,92 @@ fn should_set_price_feed() {
         _ => false,
     });
     assert_eq!(feed_2.feeds.get_unchecked(0).feed_decimals, 16);
-    assert_eq!(feed_2.feeds.get_unchecked(0).twap_records, 9);
+    assert_eq!(feed_2.feeds.get_unchecked(0).twap_window, 2700);
+}
+
+#[test]
+fn should_emit_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let asset = Address::generate(&env);
+
+    let pool: LendingPoolClient<'_> = create_pool_contract(&env, &admin, false, &asset);
+    let price_feed: PriceFeedClient<'_> = create_price_feed_contract(&env);
+
+    let input = PriceFeedConfigInput {
+        asset: asset.clone(),
+        asset_decimals: 7,
+        min_sanity_price_in_base: 5_000_000,
+        max_sanity_price_in_base: 100_000_000,
+        lastprice_fallback: false,
+        stored_price_max_age: 0,
+        max_feed_deviation: 0,
+        min_feeds: 1,
+        max_price_change: 0,
+        price_change_window: 0,
+        breaker_timeout: 0,
+        conservative_pricing: false,
+        feeds: vec![
+            &env,
+            PriceFeed {
+                feed: price_feed.address.clone(),
+                feed_asset: OracleAsset::Stellar(asset.clone()),
+                feed_decimals: 14,
+                twap_window: 3600,
+                min_twap_span: 0,
+                min_timestamp_delta: 100,
+                timestamp_precision: TimestampPrecision::Sec,
+                path: vec![&env],
+                weight: None,
+            },
+        ],
+    };
+
+    pool.set_price_feeds(&vec![&env, input.clone()]);
+
+    let prev_config = pool.price_feeds(&asset).unwrap();
+    let event = env.events().all().pop_back_unchecked();
+
+    assert_eq!(
+        vec![&env, event],
+        vec![
+            &env,
+            (
+                pool.address.clone(),
+                (
+                    Symbol::new(&env, "price_feeds_changed"),
+                    1u32,
+                    asset.clone()
+                )
+                    .into_val(&env),
+                (Option::<PriceFeedConfig>::None, prev_config.clone()).into_val(&env)
+            ),
+        ]
+    );
+
+    pool.set_price_feeds(&vec![
+        &env,
+        PriceFeedConfigInput {
+            asset_decimals: 9,
+            ..input
+        },
+    ]);
+
+    let config = pool.price_feeds(&asset).unwrap();
+    let event = env.events().all().pop_back_unchecked();
+
+    assert_eq!(config.asset_decimals, 9);
+    assert_eq!(
+        vec![&env, event],
+        vec![
+            &env,
+            (
+                pool.address.clone(),
+                (Symbol::new(&env, "price_feeds_changed"), 1u32, asset).into_val(&env),
+                (Some(prev_config), config).into_val(&env)
+            ),
+        ]
+    );
 }
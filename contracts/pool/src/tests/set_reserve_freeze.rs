@@ -0,0 +1,270 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::storage::{DataKey, LegacyReserveConfiguration, LegacyReserveData};
+use crate::tests::sut::{empty_migration_input, fill_pool, fill_pool_three, init_pool, Sut};
+use crate::*;
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events};
+use soroban_sdk::{vec, IntoVal, Symbol};
+
+#[test]
+fn should_require_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = sut.reserves[1].token.address.clone();
+
+    sut.pool.set_reserve_freeze(&asset, &true);
+
+    assert_eq!(
+        env.auths(),
+        [(
+            sut.pool_admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    sut.pool.address.clone(),
+                    Symbol::new(&env, "set_reserve_freeze"),
+                    (asset.clone(), true).into_val(&env)
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+}
+
+#[test]
+fn should_set_frozen_flag() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = sut.reserves[1].token.address.clone();
+
+    sut.pool.set_reserve_freeze(&asset, &true);
+
+    let configuration = sut.pool.get_reserve(&asset).unwrap().configuration;
+    assert!(configuration.is_frozen);
+    assert!(configuration.is_active);
+
+    sut.pool.set_reserve_freeze(&asset, &false);
+
+    assert!(
+        !sut.pool
+            .get_reserve(&asset)
+            .unwrap()
+            .configuration
+            .is_frozen
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #107)")]
+fn should_fail_deposit_when_frozen() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let user = Address::generate(&env);
+    let asset = sut.reserves[1].token.address.clone();
+
+    sut.reserves[1].token_admin.mint(&user, &1_000_000);
+    sut.pool.set_reserve_freeze(&asset, &true);
+
+    sut.pool.deposit(&user, &asset, &1_000_000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #107)")]
+fn should_fail_borrow_when_frozen() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, borrower, debt_config) = fill_pool(&env, &sut, false);
+    let debt_asset = debt_config.token.address.clone();
+
+    sut.pool.set_reserve_freeze(&debt_asset, &true);
+
+    sut.pool.borrow(&borrower, &debt_asset, &1_000_000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #107)")]
+fn should_fail_enabling_collateral_when_frozen() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (lender, _, _) = fill_pool(&env, &sut, false);
+    let asset = sut.reserves[2].token.address.clone();
+
+    sut.pool.set_as_collateral(&lender, &asset, &false);
+    sut.pool.set_reserve_freeze(&asset, &true);
+
+    sut.pool.set_as_collateral(&lender, &asset, &true);
+}
+
+#[test]
+fn should_allow_repay_and_withdraw_when_frozen() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (lender, borrower, debt_config) = fill_pool(&env, &sut, true);
+    let debt_asset = debt_config.token.address.clone();
+    let collat_asset = sut.reserves[0].token.address.clone();
+
+    sut.pool.set_reserve_freeze(&debt_asset, &true);
+    sut.pool.set_reserve_freeze(&collat_asset, &true);
+
+    sut.pool.repay(&borrower, &debt_asset, &i128::MAX);
+    sut.pool
+        .withdraw(&borrower, &collat_asset, &i128::MAX, &borrower);
+    sut.pool.withdraw(&lender, &debt_asset, &1_000_000, &lender);
+
+    assert_eq!(sut.reserves[0].s_token().balance(&borrower), 0);
+    assert!(sut.pool.user_configuration(&borrower).is_empty());
+}
+
+#[test]
+fn should_allow_liquidation_when_frozen() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, borrower, liquidator, debt_config) = fill_pool_three(&env, &sut);
+    let debt_before = debt_config.debt_token().balance(&borrower);
+
+    sut.pool
+        .set_reserve_freeze(&sut.reserves[0].token.address, &true);
+    sut.pool
+        .set_reserve_freeze(&debt_config.token.address, &true);
+
+    sut.pool.liquidate(&liquidator, &borrower);
+
+    assert!(debt_config.debt_token().balance(&borrower) < debt_before);
+}
+
+#[test]
+fn should_not_enable_collateral_on_transfer_when_frozen() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (lender, _, _) = fill_pool(&env, &sut, false);
+    let receiver = Address::generate(&env);
+    let asset = sut.reserves[2].token.address.clone();
+
+    sut.pool.set_reserve_freeze(&asset, &true);
+    sut.reserves[2]
+        .s_token()
+        .transfer(&lender, &receiver, &1_000_000);
+
+    assert_eq!(sut.reserves[2].s_token().balance(&receiver), 1_000_000);
+    assert!(sut.pool.try_user_configuration(&receiver).is_err());
+}
+
+fn set_legacy_reserve(env: &Env, sut: &Sut, asset: &Address) -> ReserveData {
+    let reserve = sut.pool.get_reserve(asset).unwrap();
+
+    env.as_contract(&sut.pool.address, || {
+        env.storage().instance().set(
+            &DataKey::ReserveAssetKey(asset.clone()),
+            &LegacyReserveData {
+                configuration: LegacyReserveConfiguration {
+                    is_active: reserve.configuration.is_active,
+                    borrowing_enabled: reserve.configuration.borrowing_enabled,
+                    liquidity_cap: reserve.configuration.liquidity_cap,
+                    pen_order: reserve.configuration.pen_order,
+                    util_cap: reserve.configuration.util_cap,
+                    discount: reserve.configuration.discount,
+                },
+                lender_ar: reserve.lender_ar,
+                lender_ir: reserve.lender_ir,
+                borrower_ar: reserve.borrower_ar,
+                borrower_ir: reserve.borrower_ir,
+                last_update_timestamp: reserve.last_update_timestamp,
+                reserve_type: reserve.reserve_type.clone(),
+                id: reserve.id.clone(),
+            },
+        );
+        env.storage().instance().remove(&DataKey::StorageVersion);
+    });
+
+    reserve
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(WasmVm, InvalidAction)")]
+fn should_fail_to_read_legacy_reserve() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = sut.reserves[1].token.address.clone();
+    set_legacy_reserve(&env, &sut, &asset);
+
+    sut.pool.get_reserve(&asset);
+}
+
+#[test]
+fn should_read_migrated_legacy_reserve() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = sut.reserves[1].token.address.clone();
+    let reserve = set_legacy_reserve(&env, &sut, &asset);
+
+    sut.pool.migrate(&0, &empty_migration_input(&env));
+
+    let legacy_reserve = sut.pool.get_reserve(&asset).unwrap();
+
+    assert!(!legacy_reserve.configuration.is_frozen);
+    assert_eq!(legacy_reserve.get_id(), reserve.get_id());
+    assert_eq!(
+        legacy_reserve.configuration.liquidity_cap,
+        reserve.configuration.liquidity_cap
+    );
+
+    sut.pool.set_reserve_freeze(&asset, &true);
+
+    assert!(
+        sut.pool
+            .get_reserve(&asset)
+            .unwrap()
+            .configuration
+            .is_frozen
+    );
+}
+
+#[test]
+fn should_emit_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = sut.reserves[1].token.address.clone();
+
+    sut.pool.set_reserve_freeze(&asset, &true);
+
+    let event = env.events().all().pop_back_unchecked();
+
+    assert_eq!(
+        vec![&env, event],
+        vec![
+            &env,
+            (
+                sut.pool.address.clone(),
+                (
+                    Symbol::new(&env, "reserve_freeze_changed"),
+                    1u32,
+                    asset.clone()
+                )
+                    .into_val(&env),
+                true.into_val(&env)
+            ),
+        ]
+    );
+}
@@ -0,0 +1,190 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::tests::sut::{create_token_contract, fill_pool, fill_pool_three, init_pool};
+use crate::*;
+use pool_interface::types::pause_action::PauseAction;
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events};
+use soroban_sdk::{vec, IntoVal, Symbol};
+
+#[test]
+fn should_require_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = sut.reserves[1].token.address.clone();
+
+    sut.pool
+        .set_reserve_pause(&sut.pool_admin, &asset, &PauseAction::Repay, &true);
+
+    assert_eq!(
+        env.auths(),
+        [(
+            sut.pool_admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    sut.pool.address.clone(),
+                    Symbol::new(&env, "set_reserve_pause"),
+                    (
+                        sut.pool_admin.clone(),
+                        asset.clone(),
+                        PauseAction::Repay,
+                        true
+                    )
+                        .into_val(&env)
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn should_fail_when_reserve_not_listed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (token, _) = create_token_contract(&env, &sut.token_admin);
+
+    sut.pool.set_reserve_pause(
+        &sut.pool_admin,
+        &token.address,
+        &PauseAction::Deposit,
+        &true,
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #2)")]
+fn should_fail_repay_when_paused() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, borrower, debt_config) = fill_pool(&env, &sut, true);
+    let debt_asset = debt_config.token.address.clone();
+
+    sut.pool
+        .set_reserve_pause(&sut.pool_admin, &debt_asset, &PauseAction::Repay, &true);
+
+    sut.pool.repay(&borrower, &debt_asset, &i128::MAX);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #2)")]
+fn should_fail_transfer_when_paused() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (lender, _, _) = fill_pool(&env, &sut, false);
+    let receiver = Address::generate(&env);
+
+    sut.pool.set_reserve_pause(
+        &sut.pool_admin,
+        &sut.reserves[2].token.address,
+        &PauseAction::Transfer,
+        &true,
+    );
+
+    sut.reserves[2]
+        .s_token()
+        .transfer(&lender, &receiver, &1_000);
+}
+
+#[test]
+fn should_not_affect_other_reserves() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let user = Address::generate(&env);
+
+    sut.reserves[2].token_admin.mint(&user, &1_000_000);
+    sut.pool.set_reserve_pause(
+        &sut.pool_admin,
+        &sut.reserves[1].token.address,
+        &PauseAction::Deposit,
+        &true,
+    );
+
+    sut.pool
+        .deposit(&user, &sut.reserves[2].token.address, &1_000_000);
+
+    assert_eq!(sut.reserves[2].s_token().balance(&user), 1_000_000);
+}
+
+#[test]
+fn should_emit_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = sut.reserves[1].token.address.clone();
+
+    sut.pool
+        .set_reserve_pause(&sut.pool_admin, &asset, &PauseAction::Withdraw, &true);
+
+    let event = env.events().all().pop_back_unchecked();
+
+    assert_eq!(
+        vec![&env, event],
+        vec![
+            &env,
+            (
+                sut.pool.address.clone(),
+                (
+                    Symbol::new(&env, "reserve_pause_changed"),
+                    1u32,
+                    asset.clone(),
+                    PauseAction::Withdraw
+                )
+                    .into_val(&env),
+                true.into_val(&env)
+            ),
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #2)")]
+fn should_fail_liquidation_when_debt_reserve_paused() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, borrower, liquidator, debt_config) = fill_pool_three(&env, &sut);
+
+    sut.pool.set_reserve_pause(
+        &sut.pool_admin,
+        &debt_config.token.address,
+        &PauseAction::Liquidate,
+        &true,
+    );
+
+    sut.pool.liquidate(&liquidator, &borrower);
+}
+
+#[test]
+fn should_not_block_liquidation_when_collateral_paused() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, borrower, liquidator, _) = fill_pool_three(&env, &sut);
+    let borrower_pos_before = sut.pool.account_position(&borrower);
+
+    sut.pool.set_reserve_pause(
+        &sut.pool_admin,
+        &sut.reserves[0].token.address,
+        &PauseAction::Liquidate,
+        &true,
+    );
+
+    sut.pool.liquidate(&liquidator, &borrower);
+
+    assert!(sut.pool.account_position(&borrower).npv > borrower_pos_before.npv);
+}
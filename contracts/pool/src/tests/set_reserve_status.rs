@@ -81,8 +81,13 @@ fn should_emit_events() {
             &env,
             (
                 sut.pool.address.clone(),
-                (&asset,).into_val(&env),
-                (true).into_val(&env)
+                (
+                    Symbol::new(&env, "reserve_status_changed"),
+                    1u32,
+                    asset.clone()
+                )
+                    .into_val(&env),
+                (true, true).into_val(&env)
             ),
         ]
     );
@@ -97,8 +102,13 @@ fn should_emit_events() {
             &env,
             (
                 sut.pool.address.clone(),
-                (&asset,).into_val(&env),
-                (false).into_val(&env)
+                (
+                    Symbol::new(&env, "reserve_status_changed"),
+                    1u32,
+                    asset.clone()
+                )
+                    .into_val(&env),
+                (true, false).into_val(&env)
             ),
         ]
     );
@@ -0,0 +1,106 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::tests::sut::init_pool;
+use crate::*;
+use soroban_sdk::testutils::{AuthorizedFunction, AuthorizedInvocation, Events};
+use soroban_sdk::{vec, IntoVal, Symbol};
+
+#[test]
+fn should_require_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    sut.pool.set_timelock_delay(&3600);
+
+    assert_eq!(
+        env.auths(),
+        [(
+            sut.pool_admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    sut.pool.address.clone(),
+                    Symbol::new(&env, "set_timelock_delay"),
+                    (3600u64,).into_val(&env)
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+}
+
+#[test]
+fn should_set_timelock_delay() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    assert_eq!(sut.pool.timelock_delay(), 0);
+
+    sut.pool.set_timelock_delay(&3600);
+
+    assert_eq!(sut.pool.timelock_delay(), 3600);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #600)")]
+fn should_fail_when_lowering_delay_directly() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    sut.pool.set_timelock_delay(&3600);
+    sut.pool.set_timelock_delay(&60);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #4)")]
+fn should_fail_when_delay_exceeds_max() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    sut.pool.set_timelock_delay(&(31 * 86_400));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #600)")]
+fn should_require_scheduling_when_timelock_enabled() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    sut.pool.set_timelock_delay(&3600);
+    sut.pool
+        .set_pool_configuration(&sut.pool.pool_configuration());
+}
+
+#[test]
+fn should_emit_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    sut.pool.set_timelock_delay(&3600);
+
+    let event = env.events().all().pop_back_unchecked();
+
+    assert_eq!(
+        vec![&env, event],
+        vec![
+            &env,
+            (
+                sut.pool.address.clone(),
+                (Symbol::new(&env, "timelock_delay_changed"), 1u32).into_val(&env),
+                3600u64.into_val(&env)
+            ),
+        ]
+    );
+}
@@ -0,0 +1,156 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::tests::sut::{fill_pool, init_pool, set_time, DAY};
+use crate::*;
+use pool_interface::types::wind_down_info::WindDownStage;
+use soroban_sdk::testutils::{AuthorizedFunction, AuthorizedInvocation, Events};
+use soroban_sdk::{vec, IntoVal, Symbol};
+
+#[test]
+fn should_require_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    sut.pool.start_wind_down(&1_000, &DAY);
+    sut.pool.start_settlement();
+
+    assert_eq!(
+        env.auths(),
+        [(
+            sut.pool_admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    sut.pool.address.clone(),
+                    Symbol::new(&env, "start_settlement"),
+                    ().into_val(&env)
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #8)")]
+fn should_fail_when_not_wound_down() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    sut.pool.start_settlement();
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #8)")]
+fn should_fail_when_already_settling() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    sut.pool.start_wind_down(&1_000, &DAY);
+    sut.pool.start_settlement();
+    sut.pool.start_settlement();
+}
+
+#[test]
+fn should_start_with_outstanding_debt() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    fill_pool(&env, &sut, true);
+
+    sut.pool.start_wind_down(&1_000, &DAY);
+    sut.pool.start_settlement();
+
+    assert_eq!(sut.pool.wind_down_info().stage, WindDownStage::Settlement);
+}
+
+#[test]
+fn should_start_after_debt_is_repaid() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, borrower, debt_config) = fill_pool(&env, &sut, true);
+
+    sut.pool.start_wind_down(&1_000, &DAY);
+    sut.pool
+        .repay(&borrower, &debt_config.token.address, &i128::MAX);
+    sut.pool.start_settlement();
+
+    assert_eq!(sut.pool.wind_down_info().stage, WindDownStage::Settlement);
+}
+
+#[test]
+fn should_start_settlement() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    sut.pool.start_wind_down(&1_000, &DAY);
+    sut.pool.start_settlement();
+
+    let wind_down_info = sut.pool.wind_down_info();
+
+    assert_eq!(wind_down_info.stage, WindDownStage::Settlement);
+    assert_eq!(wind_down_info.rate_step, 1_000);
+    assert_eq!(wind_down_info.settled_at, env.ledger().timestamp());
+}
+
+#[test]
+fn should_stop_rate_premium_growth() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, borrower, debt_config) = fill_pool(&env, &sut, true);
+    let debt_asset = debt_config.token.address.clone();
+
+    let borrower_ir_before = sut.pool.get_reserve(&debt_asset).unwrap().borrower_ir;
+
+    // 10% on every day of the wind-down
+    sut.pool.start_wind_down(&1_000, &DAY);
+    set_time(&env, &sut, 3 * DAY + 1, true);
+    sut.pool.start_settlement();
+    set_time(&env, &sut, 10 * DAY, true);
+
+    sut.pool.repay(&borrower, &debt_asset, &1_000);
+
+    let borrower_ir_after = sut.pool.get_reserve(&debt_asset).unwrap().borrower_ir;
+
+    // only the three steps elapsed before the settlement count
+    assert!(borrower_ir_after - borrower_ir_before > 290_000_000);
+    assert!(borrower_ir_after - borrower_ir_before < 310_000_000);
+}
+
+#[test]
+fn should_emit_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    sut.pool.start_wind_down(&1_000, &DAY);
+    sut.pool.start_settlement();
+
+    let event = env.events().all().pop_back_unchecked();
+
+    assert_eq!(
+        vec![&env, event],
+        vec![
+            &env,
+            (
+                sut.pool.address.clone(),
+                (Symbol::new(&env, "settlement_started"), 1u32).into_val(&env),
+                ().into_val(&env)
+            ),
+        ]
+    );
+}
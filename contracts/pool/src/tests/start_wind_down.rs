@@ -0,0 +1,229 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::methods::utils::rate::WIND_DOWN_MAX_RATE_PREMIUM;
+use crate::tests::sut::{fill_pool, init_pool, set_time, DAY};
+use crate::*;
+use pool_interface::types::wind_down_info::WindDownStage;
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events};
+use soroban_sdk::{vec, IntoVal, Symbol};
+
+#[test]
+fn should_require_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    sut.pool.start_wind_down(&1_000, &DAY);
+
+    assert_eq!(
+        env.auths(),
+        [(
+            sut.pool_admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    sut.pool.address.clone(),
+                    Symbol::new(&env, "start_wind_down"),
+                    (1_000u32, DAY).into_val(&env)
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #600)")]
+fn should_fail_when_timelocked() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    sut.pool.set_timelock_delay(&DAY);
+    sut.pool.start_wind_down(&1_000, &DAY);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #8)")]
+fn should_fail_when_already_started() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    sut.pool.start_wind_down(&1_000, &DAY);
+    sut.pool.start_wind_down(&1_000, &DAY);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #3)")]
+fn should_fail_when_step_is_zero() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    sut.pool.start_wind_down(&1_000, &0);
+}
+
+#[test]
+fn should_start_wind_down() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    assert_eq!(sut.pool.wind_down_info().stage, WindDownStage::Inactive);
+
+    set_time(&env, &sut, DAY, false);
+    sut.pool.start_wind_down(&1_000, &DAY);
+
+    let wind_down_info = sut.pool.wind_down_info();
+
+    assert_eq!(wind_down_info.stage, WindDownStage::WindDown);
+    assert_eq!(wind_down_info.started_at, DAY);
+    assert_eq!(wind_down_info.rate_step, 1_000);
+    assert_eq!(wind_down_info.step_secs, DAY);
+}
+
+#[test]
+fn should_start_through_timelock() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    sut.pool.set_timelock_delay(&DAY);
+
+    let eta = env.ledger().timestamp() + DAY;
+    let id = sut
+        .pool
+        .schedule_operation(&TimelockOperation::StartWindDown(1_000, DAY), &eta);
+
+    set_time(&env, &sut, eta, false);
+    sut.pool.execute_operation(&id);
+
+    assert_eq!(sut.pool.wind_down_info().stage, WindDownStage::WindDown);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn should_disable_deposit() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let user = Address::generate(&env);
+
+    sut.reserves[1].token_admin.mint(&user, &1_000_000);
+    sut.pool.start_wind_down(&1_000, &DAY);
+
+    sut.pool
+        .deposit(&user, &sut.reserves[1].token.address, &1_000_000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn should_disable_borrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, borrower, debt_config) = fill_pool(&env, &sut, false);
+
+    sut.pool.start_wind_down(&1_000, &DAY);
+
+    sut.pool
+        .borrow(&borrower, &debt_config.token.address, &1_000_000);
+}
+
+#[test]
+fn should_allow_repay_and_withdraw() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (lender, borrower, debt_config) = fill_pool(&env, &sut, true);
+    let debt_asset = debt_config.token.address.clone();
+
+    sut.pool.start_wind_down(&1_000, &DAY);
+    set_time(&env, &sut, 2 * DAY, false);
+
+    sut.pool.repay(&borrower, &debt_asset, &i128::MAX);
+    sut.pool.withdraw(&lender, &debt_asset, &1_000_000, &lender);
+
+    assert_eq!(debt_config.debt_token().balance(&borrower), 0);
+}
+
+#[test]
+fn should_step_up_borrower_rate() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, borrower, debt_config) = fill_pool(&env, &sut, true);
+    let debt_asset = debt_config.token.address.clone();
+
+    let borrower_ir_before = sut.pool.get_reserve(&debt_asset).unwrap().borrower_ir;
+
+    // 10% on every day of the wind-down
+    sut.pool.start_wind_down(&1_000, &DAY);
+    set_time(&env, &sut, 3 * DAY + 1, true);
+
+    sut.pool.repay(&borrower, &debt_asset, &1_000);
+
+    let borrower_ir_after = sut.pool.get_reserve(&debt_asset).unwrap().borrower_ir;
+
+    assert!(borrower_ir_after - borrower_ir_before > 290_000_000);
+    assert!(borrower_ir_after - borrower_ir_before < 310_000_000);
+}
+
+#[test]
+fn should_cap_borrower_rate_in_long_wind_down() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, borrower, debt_config) = fill_pool(&env, &sut, true);
+    let debt_asset = debt_config.token.address.clone();
+
+    // 100% on every second of the wind-down
+    sut.pool.start_wind_down(&10_000, &1);
+    set_time(&env, &sut, 10 * 365 * DAY, true);
+
+    sut.pool.repay(&borrower, &debt_asset, &1_000);
+
+    let ir_max_rate = i128::from(sut.pool.pool_configuration().ir_max_rate) * 100_000;
+    let max_premium = i128::from(WIND_DOWN_MAX_RATE_PREMIUM) * 100_000;
+    let borrower_ir = sut.pool.get_reserve(&debt_asset).unwrap().borrower_ir;
+
+    // the capped premium is added on top of the base rate capped by itself
+    assert!(borrower_ir > max_premium);
+    assert!(borrower_ir <= max_premium + ir_max_rate);
+}
+
+#[test]
+fn should_emit_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    sut.pool.start_wind_down(&1_000, &DAY);
+
+    let event = env.events().all().pop_back_unchecked();
+
+    assert_eq!(
+        vec![&env, event],
+        vec![
+            &env,
+            (
+                sut.pool.address.clone(),
+                (Symbol::new(&env, "wind_down_started"), 1u32).into_val(&env),
+                (1_000u32, DAY).into_val(&env)
+            ),
+        ]
+    );
+}
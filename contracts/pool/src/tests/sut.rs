@@ -15,7 +15,7 @@ use soroban_sdk::testutils::{Address as _, Ledger};
 use soroban_sdk::token::Client as TokenClient;
 use soroban_sdk::token::StellarAssetClient as TokenAdminClient;
 use soroban_sdk::IntoVal;
-use soroban_sdk::{vec, Env};
+use soroban_sdk::{vec, Env, Map};
 
 mod pool {
     soroban_sdk::contractimport!(file = "../../target/wasm32-unknown-unknown/release/pool.wasm");
@@ -283,74 +283,10 @@ pub(crate) fn init_pool<'a>(env: &Env, use_pool_wasm: bool) -> Sut<'a> {
     let feed_inputs = Vec::from_array(
         &env,
         [
-            PriceFeedConfigInput {
-                asset: reserves[0].token.address.clone(),
-                asset_decimals: 7,
-                min_sanity_price_in_base: 5_000_000,
-                max_sanity_price_in_base: 100_000_000,
-                feeds: vec![
-                    &env,
-                    PriceFeed {
-                        feed: price_feed.address.clone(),
-                        feed_asset: OracleAsset::Stellar(reserves[0].token.address.clone()),
-                        feed_decimals: 14,
-                        twap_records: 10,
-                        min_timestamp_delta: 100,
-                        timestamp_precision: TimestampPrecision::Sec,
-                    },
-                ],
-            },
-            PriceFeedConfigInput {
-                asset: reserves[1].token.address.clone(),
-                asset_decimals: 9,
-                min_sanity_price_in_base: 5_000_000,
-                max_sanity_price_in_base: 100_000_000,
-                feeds: vec![
-                    &env,
-                    PriceFeed {
-                        feed: price_feed.address.clone(),
-                        feed_asset: OracleAsset::Stellar(reserves[1].token.address.clone()),
-                        feed_decimals: 16,
-                        twap_records: 10,
-                        min_timestamp_delta: 100,
-                        timestamp_precision: TimestampPrecision::Sec,
-                    },
-                ],
-            },
-            PriceFeedConfigInput {
-                asset: reserves[2].token.address.clone(),
-                asset_decimals: 9,
-                min_sanity_price_in_base: 5_000_000,
-                max_sanity_price_in_base: 100_000_000,
-                feeds: vec![
-                    &env,
-                    PriceFeed {
-                        feed: price_feed.address.clone(),
-                        feed_asset: OracleAsset::Stellar(reserves[2].token.address.clone()),
-                        feed_decimals: 16,
-                        twap_records: 10,
-                        min_timestamp_delta: 100,
-                        timestamp_precision: TimestampPrecision::Sec,
-                    },
-                ],
-            },
-            PriceFeedConfigInput {
-                asset: reserves[3].token.address.clone(),
-                asset_decimals: 9,
-                min_sanity_price_in_base: 5_000_000,
-                max_sanity_price_in_base: 100_000_000,
-                feeds: vec![
-                    &env,
-                    PriceFeed {
-                        feed: price_feed.address.clone(),
-                        feed_asset: OracleAsset::Stellar(reserves[3].token.address.clone()),
-                        feed_decimals: 15,
-                        twap_records: 10,
-                        min_timestamp_delta: 100,
-                        timestamp_precision: TimestampPrecision::Sec,
-                    },
-                ],
-            },
+            price_feed_config(&env, &price_feed.address, &reserves[0].token.address, 7, 14),
+            price_feed_config(&env, &price_feed.address, &reserves[1].token.address, 9, 16),
+            price_feed_config(&env, &price_feed.address, &reserves[2].token.address, 9, 16),
+            price_feed_config(&env, &price_feed.address, &reserves[3].token.address, 9, 15),
         ],
     );
 
@@ -419,6 +355,30 @@ pub(crate) fn fill_pool<'a, 'b>(
     (lender, borrower, &sut.reserves[1])
 }
 
+/// Clears the collateral flags of `who` as if the collateral was liquidated to the last unit
+pub(crate) fn remove_collateral(env: &Env, sut: &Sut, who: &Address) {
+    let reserve_ids: std::vec::Vec<u8> = sut
+        .reserves
+        .iter()
+        .map(|reserve| {
+            sut.pool
+                .get_reserve(&reserve.token.address)
+                .unwrap()
+                .get_id()
+        })
+        .collect();
+
+    env.as_contract(&sut.pool.address, || {
+        let mut user_config = crate::storage::read_user_config(env, who).unwrap();
+
+        for reserve_id in reserve_ids {
+            user_config.set_using_as_collateral(env, reserve_id, false);
+        }
+
+        crate::storage::write_user_config(env, who, &user_config);
+    });
+}
+
 pub(crate) fn set_time<'a, 'b>(env: &'b Env, sut: &'a Sut, time: u64, append: bool) {
     let new_time = append
         .then(|| env.ledger().timestamp() + time)
@@ -447,6 +407,96 @@ pub(crate) fn set_time<'a, 'b>(env: &'b Env, sut: &'a Sut, time: u64, append: bo
     env.ledger().with_mut(|li| li.timestamp = new_time);
 }
 
+/// Sets the price returned by `lastprice` of the price feed keeping the prices history
+pub(crate) fn set_lastprice(env: &Env, sut: &Sut, asset: &Address, price: i128) {
+    price_feed::Client::new(env, &sut.price_feed.address).set_lastprice(
+        &price_feed::Asset::Stellar(asset.clone()),
+        &price_feed::PriceData {
+            price,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+}
+
+/// Feed quoting the asset directly in base, the fields a test covers are overridden with `..`
+pub(crate) fn default_price_feed(
+    env: &Env,
+    feed: &Address,
+    asset: &Address,
+    feed_decimals: u32,
+) -> PriceFeed {
+    PriceFeed {
+        feed: feed.clone(),
+        feed_asset: OracleAsset::Stellar(asset.clone()),
+        feed_decimals,
+        twap_window: 3600,
+        min_twap_span: 0,
+        min_timestamp_delta: 100,
+        timestamp_precision: TimestampPrecision::Sec,
+        path: vec![env],
+        weight: None,
+    }
+}
+
+/// Config of the second reserve priced by the sut feed with the fallbacks, deviation checks,
+/// circuit breaker and conservative pricing off, the fields a test covers are overridden with `..`
+pub(crate) fn default_price_feed_config(env: &Env, sut: &Sut) -> PriceFeedConfigInput {
+    price_feed_config(
+        env,
+        &sut.price_feed.address,
+        &sut.reserves[1].token.address,
+        9,
+        16,
+    )
+}
+
+/// Config of the asset priced by a single feed with the optional checks off
+pub(crate) fn price_feed_config(
+    env: &Env,
+    feed: &Address,
+    asset: &Address,
+    asset_decimals: u32,
+    feed_decimals: u32,
+) -> PriceFeedConfigInput {
+    PriceFeedConfigInput {
+        asset: asset.clone(),
+        asset_decimals,
+        min_sanity_price_in_base: 5_000_000,
+        max_sanity_price_in_base: 100_000_000,
+        lastprice_fallback: false,
+        stored_price_max_age: 0,
+        max_feed_deviation: 0,
+        min_feeds: 1,
+        max_price_change: 0,
+        price_change_window: 0,
+        breaker_timeout: 0,
+        conservative_pricing: false,
+        feeds: vec![env, default_price_feed(env, feed, asset, feed_decimals)],
+    }
+}
+
+/// Reads the price of the amount in base the way the pool operations do, storing the
+/// last good price and the circuit breaker state unlike the `twap_median_price` view
+pub(crate) fn record_price(env: &Env, sut: &Sut, asset: &Address, amount: i128) -> i128 {
+    env.as_contract(&sut.pool.address, || {
+        let pool_config = crate::storage::read_pool_config(env).unwrap();
+
+        crate::types::price_provider::PriceProvider::new(env, &pool_config)
+            .unwrap()
+            .record_prices()
+            .convert_to_base(asset, amount)
+            .unwrap()
+    })
+}
+
+/// Migration input attesting no accounting, so the migration falls back to its estimates
+pub(crate) fn empty_migration_input(env: &Env) -> MigrationInput {
+    MigrationInput {
+        rwa_total_supplies: Map::new(env),
+        reserve_id_holders: Map::new(env),
+    }
+}
+
 /// Fill lending pool with two lenders and one borrower
 pub(crate) fn fill_pool_two<'a, 'b>(
     env: &'b Env,
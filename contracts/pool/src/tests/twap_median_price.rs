@@ -42,6 +42,14 @@ fn should_fail_when_feed_is_missing_for_asset() {
             asset_decimals: 9,
             min_sanity_price_in_base: 5_000_000,
             max_sanity_price_in_base: 50_000_000_000,
+            lastprice_fallback: false,
+            stored_price_max_age: 0,
+            max_feed_deviation: 0,
+            min_feeds: 1,
+            max_price_change: 0,
+            price_change_window: 0,
+            breaker_timeout: 0,
+            conservative_pricing: false,
             feeds: vec![
                 &env,
                 // price feed with Stellar asset
@@ -49,9 +57,12 @@ fn should_fail_when_feed_is_missing_for_asset() {
                     feed: asset_1_feed_1.address.clone(),
                     feed_asset: OracleAsset::Stellar(asset_1.clone()),
                     feed_decimals: 14,
-                    twap_records: 10,
+                    twap_window: 3600,
+                    min_twap_span: 0,
                     min_timestamp_delta: 100,
                     timestamp_precision: TimestampPrecision::Sec,
+                    path: vec![&env],
+                    weight: None,
                 },
             ],
         },
@@ -98,6 +109,14 @@ fn should_fail_when_price_is_missing_in_feed() {
             asset_decimals: 9,
             min_sanity_price_in_base: 5_000_000,
             max_sanity_price_in_base: 50_000_000_000,
+            lastprice_fallback: false,
+            stored_price_max_age: 0,
+            max_feed_deviation: 0,
+            min_feeds: 1,
+            max_price_change: 0,
+            price_change_window: 0,
+            breaker_timeout: 0,
+            conservative_pricing: false,
             feeds: vec![
                 &env,
                 // price feed with Stellar asset
@@ -105,18 +124,24 @@ fn should_fail_when_price_is_missing_in_feed() {
                     feed: asset_1_feed_1.address.clone(),
                     feed_asset: OracleAsset::Stellar(asset_1.clone()),
                     feed_decimals: 14,
-                    twap_records: 10,
+                    twap_window: 3600,
+                    min_twap_span: 0,
                     min_timestamp_delta: 100,
                     timestamp_precision: TimestampPrecision::Sec,
+                    path: vec![&env],
+                    weight: None,
                 },
                 // price feed with Other asset
                 PriceFeed {
                     feed: asset_1_feed_2.address.clone(),
                     feed_asset: OracleAsset::Other(symbol_short!("XRP")),
                     feed_decimals: 10,
-                    twap_records: 10,
+                    twap_window: 3600,
+                    min_twap_span: 0,
                     min_timestamp_delta: 100,
                     timestamp_precision: TimestampPrecision::Sec,
+                    path: vec![&env],
+                    weight: None,
                 },
             ],
         },
@@ -154,31 +179,48 @@ fn should_fail_when_all_price_feeds_throws_error() {
             asset_decimals: 9,
             min_sanity_price_in_base: 5_000_000,
             max_sanity_price_in_base: 50_000_000_000,
+            lastprice_fallback: false,
+            stored_price_max_age: 0,
+            max_feed_deviation: 0,
+            min_feeds: 1,
+            max_price_change: 0,
+            price_change_window: 0,
+            breaker_timeout: 0,
+            conservative_pricing: false,
             feeds: vec![
                 &env,
                 PriceFeed {
                     feed: asset_1_feed_1.address.clone(),
                     feed_asset: OracleAsset::Stellar(asset_1.clone()),
                     feed_decimals: 14,
-                    twap_records: 10,
+                    twap_window: 3600,
+                    min_twap_span: 0,
                     min_timestamp_delta: 1_000_000,
                     timestamp_precision: TimestampPrecision::Sec,
+                    path: vec![&env],
+                    weight: None,
                 },
                 PriceFeed {
                     feed: asset_1_feed_2.address.clone(),
                     feed_asset: OracleAsset::Other(symbol_short!("XRP")),
                     feed_decimals: 10,
-                    twap_records: 10,
+                    twap_window: 3600,
+                    min_twap_span: 0,
                     min_timestamp_delta: 1_000_000,
                     timestamp_precision: TimestampPrecision::Msec,
+                    path: vec![&env],
+                    weight: None,
                 },
                 PriceFeed {
                     feed: asset_1_feed_3.address.clone(),
                     feed_asset: OracleAsset::Other(symbol_short!("XRP")),
                     feed_decimals: 10,
-                    twap_records: 10,
+                    twap_window: 3600,
+                    min_twap_span: 0,
                     min_timestamp_delta: 1_000_000,
                     timestamp_precision: TimestampPrecision::Msec,
+                    path: vec![&env],
+                    weight: None,
                 },
             ],
         },
@@ -231,15 +273,26 @@ fn should_fail_when_price_is_not_stale() {
             asset_decimals: 9,
             min_sanity_price_in_base: 5_000_000,
             max_sanity_price_in_base: 50_000_000_000,
+            lastprice_fallback: false,
+            stored_price_max_age: 0,
+            max_feed_deviation: 0,
+            min_feeds: 1,
+            max_price_change: 0,
+            price_change_window: 0,
+            breaker_timeout: 0,
+            conservative_pricing: false,
             feeds: vec![
                 &env,
                 PriceFeed {
                     feed: asset_1_feed_1.address.clone(),
                     feed_asset: OracleAsset::Stellar(asset_1.clone()),
                     feed_decimals: 10,
-                    twap_records: 10,
+                    twap_window: 3600,
+                    min_twap_span: 0,
                     min_timestamp_delta: 200,
                     timestamp_precision: TimestampPrecision::Sec,
+                    path: vec![&env],
+                    weight: None,
                 },
             ],
         },
@@ -295,15 +348,26 @@ fn should_fail_when_price_is_below_min_sanity() {
             asset_decimals: 9,
             min_sanity_price_in_base: 50_000_000,
             max_sanity_price_in_base: 50_000_000_000,
+            lastprice_fallback: false,
+            stored_price_max_age: 0,
+            max_feed_deviation: 0,
+            min_feeds: 1,
+            max_price_change: 0,
+            price_change_window: 0,
+            breaker_timeout: 0,
+            conservative_pricing: false,
             feeds: vec![
                 &env,
                 PriceFeed {
                     feed: asset_1_feed_1.address.clone(),
                     feed_asset: OracleAsset::Stellar(asset_1.clone()),
                     feed_decimals: 10,
-                    twap_records: 10,
+                    twap_window: 3600,
+                    min_twap_span: 0,
                     min_timestamp_delta: 1_000_000,
                     timestamp_precision: TimestampPrecision::Sec,
+                    path: vec![&env],
+                    weight: None,
                 },
             ],
         },
@@ -359,15 +423,26 @@ fn should_fail_when_price_is_below_max_sanity() {
             asset_decimals: 9,
             min_sanity_price_in_base: 5_000_000,
             max_sanity_price_in_base: 1_000_000_000,
+            lastprice_fallback: false,
+            stored_price_max_age: 0,
+            max_feed_deviation: 0,
+            min_feeds: 1,
+            max_price_change: 0,
+            price_change_window: 0,
+            breaker_timeout: 0,
+            conservative_pricing: false,
             feeds: vec![
                 &env,
                 PriceFeed {
                     feed: asset_1_feed_1.address.clone(),
                     feed_asset: OracleAsset::Stellar(asset_1.clone()),
                     feed_decimals: 10,
-                    twap_records: 10,
+                    twap_window: 3600,
+                    min_twap_span: 0,
                     min_timestamp_delta: 1_000_000,
                     timestamp_precision: TimestampPrecision::Sec,
+                    path: vec![&env],
+                    weight: None,
                 },
             ],
         },
@@ -435,6 +510,14 @@ fn should_return_twap_median_price() {
             asset_decimals: 9,
             min_sanity_price_in_base: 5_000_000,
             max_sanity_price_in_base: 50_000_000_000,
+            lastprice_fallback: false,
+            stored_price_max_age: 0,
+            max_feed_deviation: 0,
+            min_feeds: 1,
+            max_price_change: 0,
+            price_change_window: 0,
+            breaker_timeout: 0,
+            conservative_pricing: false,
             feeds: vec![
                 &env,
                 // price feed with Stellar asset
@@ -442,18 +525,24 @@ fn should_return_twap_median_price() {
                     feed: asset_1_feed_1.address.clone(),
                     feed_asset: OracleAsset::Stellar(asset_1.clone()),
                     feed_decimals: 14,
-                    twap_records: 10,
+                    twap_window: 3600,
+                    min_twap_span: 0,
                     min_timestamp_delta: 1_000_000,
                     timestamp_precision: TimestampPrecision::Sec,
+                    path: vec![&env],
+                    weight: None,
                 },
                 // price feed with Other asset
                 PriceFeed {
                     feed: asset_1_feed_2.address.clone(),
                     feed_asset: OracleAsset::Other(symbol_short!("XRP")),
                     feed_decimals: 10,
-                    twap_records: 10,
+                    twap_window: 3600,
+                    min_twap_span: 0,
                     min_timestamp_delta: 1_000_000,
                     timestamp_precision: TimestampPrecision::Msec,
+                    path: vec![&env],
+                    weight: None,
                 },
             ],
         },
@@ -487,6 +576,14 @@ fn should_return_twap_median_price() {
             asset_decimals: 9,
             min_sanity_price_in_base: 5_000_000,
             max_sanity_price_in_base: 50_000_000_000,
+            lastprice_fallback: false,
+            stored_price_max_age: 0,
+            max_feed_deviation: 0,
+            min_feeds: 1,
+            max_price_change: 0,
+            price_change_window: 0,
+            breaker_timeout: 0,
+            conservative_pricing: false,
             feeds: vec![
                 &env,
                 // price feed with Stellar asset
@@ -494,26 +591,35 @@ fn should_return_twap_median_price() {
                     feed: asset_1_feed_1.address.clone(),
                     feed_asset: OracleAsset::Stellar(asset_1.clone()),
                     feed_decimals: 14,
-                    twap_records: 10,
+                    twap_window: 3600,
+                    min_twap_span: 0,
                     min_timestamp_delta: 1_000_000,
                     timestamp_precision: TimestampPrecision::Sec,
+                    path: vec![&env],
+                    weight: None,
                 },
                 // price feed with Other asset
                 PriceFeed {
                     feed: asset_1_feed_2.address.clone(),
                     feed_asset: OracleAsset::Other(symbol_short!("XRP")),
                     feed_decimals: 10,
-                    twap_records: 10,
+                    twap_window: 3600,
+                    min_twap_span: 0,
                     min_timestamp_delta: 1_000_000,
                     timestamp_precision: TimestampPrecision::Msec,
+                    path: vec![&env],
+                    weight: None,
                 },
                 PriceFeed {
                     feed: asset_1_feed_3.address.clone(),
                     feed_asset: OracleAsset::Other(symbol_short!("XRP")),
                     feed_decimals: 10,
-                    twap_records: 10,
+                    twap_window: 3600,
+                    min_twap_span: 0,
                     min_timestamp_delta: 1_000_000,
                     timestamp_precision: TimestampPrecision::Msec,
+                    path: vec![&env],
+                    weight: None,
                 },
             ],
         },
@@ -580,6 +686,14 @@ fn should_return_twap_median_price_when_unsorted_prices() {
             asset_decimals: 9,
             min_sanity_price_in_base: 5_000_000,
             max_sanity_price_in_base: 50_000_000_000,
+            lastprice_fallback: false,
+            stored_price_max_age: 0,
+            max_feed_deviation: 0,
+            min_feeds: 1,
+            max_price_change: 0,
+            price_change_window: 0,
+            breaker_timeout: 0,
+            conservative_pricing: false,
             feeds: vec![
                 &env,
                 // price feed with Stellar asset
@@ -587,18 +701,24 @@ fn should_return_twap_median_price_when_unsorted_prices() {
                     feed: asset_1_feed_1.address.clone(),
                     feed_asset: OracleAsset::Stellar(asset_1.clone()),
                     feed_decimals: 14,
-                    twap_records: 10,
+                    twap_window: 3600,
+                    min_twap_span: 0,
                     min_timestamp_delta: 1_000_000,
                     timestamp_precision: TimestampPrecision::Sec,
+                    path: vec![&env],
+                    weight: None,
                 },
                 // price feed with Other asset
                 PriceFeed {
                     feed: asset_1_feed_2.address.clone(),
                     feed_asset: OracleAsset::Other(symbol_short!("XRP")),
                     feed_decimals: 10,
-                    twap_records: 10,
+                    twap_window: 3600,
+                    min_twap_span: 0,
                     min_timestamp_delta: 1_000_000,
                     timestamp_precision: TimestampPrecision::Msec,
+                    path: vec![&env],
+                    weight: None,
                 },
             ],
         },
@@ -670,32 +790,49 @@ fn should_use_backup_price_feed() {
             asset_decimals: 9,
             min_sanity_price_in_base: 5_000_000,
             max_sanity_price_in_base: 50_000_000_000,
+            lastprice_fallback: false,
+            stored_price_max_age: 0,
+            max_feed_deviation: 0,
+            min_feeds: 1,
+            max_price_change: 0,
+            price_change_window: 0,
+            breaker_timeout: 0,
+            conservative_pricing: false,
             feeds: vec![
                 &env,
                 PriceFeed {
                     feed: asset_1_feed_1.address.clone(),
                     feed_asset: OracleAsset::Other(symbol_short!("XRP")),
                     feed_decimals: 10,
-                    twap_records: 10,
+                    twap_window: 3600,
+                    min_twap_span: 0,
                     min_timestamp_delta: 600_000,
                     timestamp_precision: TimestampPrecision::Msec,
+                    path: vec![&env],
+                    weight: None,
                 },
                 PriceFeed {
                     feed: asset_1_feed_2.address.clone(),
                     feed_asset: OracleAsset::Other(symbol_short!("XRP")),
                     feed_decimals: 10,
-                    twap_records: 10,
+                    twap_window: 3600,
+                    min_twap_span: 0,
                     min_timestamp_delta: 600_000,
                     timestamp_precision: TimestampPrecision::Msec,
+                    path: vec![&env],
+                    weight: None,
                 },
                 // backup price oracle
                 PriceFeed {
                     feed: asset_1_feed_3.address.clone(),
                     feed_asset: OracleAsset::Stellar(asset_1.clone()),
                     feed_decimals: 14,
-                    twap_records: 10,
+                    twap_window: 3600,
+                    min_twap_span: 0,
                     min_timestamp_delta: 600_000,
                     timestamp_precision: TimestampPrecision::Sec,
+                    path: vec![&env],
+                    weight: None,
                 },
             ],
         },
@@ -0,0 +1,225 @@
+#![cfg(test)]
+extern crate std;
+
+use pool_interface::types::price_feed::PriceFeed;
+use pool_interface::types::price_feed_config_input::PriceFeedConfigInput;
+use pool_interface::types::timestamp_precision::TimestampPrecision;
+use price_feed_interface::types::asset::Asset;
+use price_feed_interface::types::price_data::PriceData;
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::{vec, Address, Env, Vec};
+
+use crate::tests::sut::{
+    create_price_feed_contract, default_price_feed, default_price_feed_config, init_pool, Sut,
+};
+
+const TIMESTAMP: u64 = 1704790800;
+
+/// Configures a single feed with the (price, secs ago) records, feed prices are already in the base asset decimals
+fn configure_feed(
+    env: &Env,
+    sut: &Sut,
+    records: &[(i128, u64)],
+    twap_window: u64,
+    min_twap_span: u64,
+    timestamp_precision: TimestampPrecision,
+) -> Address {
+    let asset = sut.reserves[1].token.address.clone();
+    let feed = create_price_feed_contract(env);
+    let mut prices = Vec::new(env);
+
+    env.ledger().with_mut(|li| li.timestamp = TIMESTAMP);
+
+    for (price, secs_ago) in records {
+        let timestamp = TIMESTAMP - secs_ago;
+
+        prices.push_back(PriceData {
+            price: *price,
+            timestamp: match timestamp_precision {
+                TimestampPrecision::Msec => timestamp * 1000,
+                TimestampPrecision::Sec => timestamp,
+            },
+        });
+    }
+
+    feed.init(&Asset::Stellar(asset.clone()), &prices);
+
+    sut.pool.set_price_feeds(&vec![
+        env,
+        PriceFeedConfigInput {
+            feeds: vec![
+                env,
+                PriceFeed {
+                    twap_window,
+                    min_twap_span,
+                    min_timestamp_delta: match timestamp_precision {
+                        TimestampPrecision::Msec => 100_000,
+                        TimestampPrecision::Sec => 100,
+                    },
+                    timestamp_precision,
+                    ..default_price_feed(env, &feed.address, &asset, 7)
+                },
+            ],
+            ..default_price_feed_config(env, sut)
+        },
+    ]);
+
+    asset
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #4)")]
+fn should_fail_when_min_span_exceeds_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+
+    configure_feed(
+        &env,
+        &sut,
+        &[(10_000_000, 0)],
+        900,
+        901,
+        TimestampPrecision::Sec,
+    );
+}
+
+#[test]
+fn should_average_over_window_only() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_feed(
+        &env,
+        &sut,
+        &[(14_000_000, 60), (50_000_000, 3_000), (12_000_000, 600)],
+        900,
+        0,
+        TimestampPrecision::Sec,
+    );
+
+    // the record before the window start only counts from the window start
+    // (50 * 300 + 12 * 540 + 14 * 60) / 900
+    assert_eq!(
+        sut.pool.twap_median_price(&asset, &1_000_000_000),
+        24_800_000
+    );
+}
+
+#[test]
+fn should_exclude_records_older_than_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_feed(
+        &env,
+        &sut,
+        &[
+            (14_000_000, 60),
+            (50_000_000, 3_000),
+            (10_000_000, 900),
+            (12_000_000, 600),
+        ],
+        900,
+        0,
+        TimestampPrecision::Sec,
+    );
+
+    // (10 * 300 + 12 * 540 + 14 * 60) / 900
+    assert_eq!(
+        sut.pool.twap_median_price(&asset, &1_000_000_000),
+        11_466_666
+    );
+}
+
+#[test]
+fn should_scale_window_to_msec_feeds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_feed(
+        &env,
+        &sut,
+        &[
+            (14_000_000, 60),
+            (50_000_000, 3_000),
+            (10_000_000, 900),
+            (12_000_000, 600),
+        ],
+        900,
+        900,
+        TimestampPrecision::Msec,
+    );
+
+    // (10 * 300 + 12 * 540 + 14 * 60) / 900
+    assert_eq!(
+        sut.pool.twap_median_price(&asset, &1_000_000_000),
+        11_466_666
+    );
+}
+
+#[test]
+fn should_return_last_record_for_zero_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_feed(
+        &env,
+        &sut,
+        &[(14_000_000, 60), (10_000_000, 600)],
+        0,
+        0,
+        TimestampPrecision::Sec,
+    );
+
+    assert_eq!(
+        sut.pool.twap_median_price(&asset, &1_000_000_000),
+        14_000_000
+    );
+}
+
+#[test]
+fn should_accept_window_with_enough_coverage() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_feed(
+        &env,
+        &sut,
+        &[(14_000_000, 60), (10_000_000, 600)],
+        900,
+        600,
+        TimestampPrecision::Sec,
+    );
+
+    // (10 * 540 + 14 * 60) / 600
+    assert_eq!(
+        sut.pool.twap_median_price(&asset, &1_000_000_000),
+        10_400_000
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #102)")]
+fn should_fail_when_window_coverage_is_too_short() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let asset = configure_feed(
+        &env,
+        &sut,
+        &[(14_000_000, 60), (10_000_000, 300)],
+        900,
+        600,
+        TimestampPrecision::Sec,
+    );
+
+    sut.pool.twap_median_price(&asset, &1_000_000_000);
+}
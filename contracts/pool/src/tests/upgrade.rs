@@ -1,9 +1,11 @@
 #![cfg(test)]
 extern crate std;
 
+use soroban_sdk::testutils::Events;
 use soroban_sdk::testutils::{AuthorizedFunction, AuthorizedInvocation};
-use soroban_sdk::{symbol_short, vec, IntoVal};
+use soroban_sdk::{contract, contractimpl, symbol_short, vec, IntoVal, Symbol};
 
+use crate::methods::utils::validation::require_compatible_s_token;
 use crate::tests::sut::init_pool;
 use crate::*;
 
@@ -11,6 +13,20 @@ pub mod pool_v2 {
     soroban_sdk::contractimport!(file = "../../mocks/pool_v2_mock.wasm");
 }
 
+pub mod s_token_v1 {
+    soroban_sdk::contractimport!(file = "../../target/wasm32-unknown-unknown/release/s_token.wasm");
+}
+
+pub mod debt_token_v1 {
+    soroban_sdk::contractimport!(
+        file = "../../target/wasm32-unknown-unknown/release/debt_token.wasm"
+    );
+}
+
+pub mod token {
+    soroban_sdk::contractimport!(file = "../../mocks/soroban_token_contract.wasm");
+}
+
 pub mod s_token_v2 {
     soroban_sdk::contractimport!(file = "../../mocks/s_token_v2_mock.wasm");
 }
@@ -54,25 +70,266 @@ fn should_upgrade_contracts() {
     let asset = sut.reserves[0].token.address.clone();
 
     let pool_v2_wasm = env.deployer().upload_contract_wasm(pool_v2::WASM);
-    let s_token_v2_wasm = env.deployer().upload_contract_wasm(s_token_v2::WASM);
-    let debt_token_v2_wasm = env.deployer().upload_contract_wasm(debt_token_v2::WASM);
+    let s_token_v1_wasm = env.deployer().upload_contract_wasm(s_token_v1::WASM);
+    let debt_token_v1_wasm = env.deployer().upload_contract_wasm(debt_token_v1::WASM);
 
     let pool_version_before = sut.pool.version();
-    let s_token_version_before = sut.s_token().version();
-    let debt_token_version_before = sut.debt_token().version();
 
-    sut.pool.upgrade_token(&asset, &true, &s_token_v2_wasm);
-    sut.pool.upgrade_token(&asset, &false, &debt_token_v2_wasm);
+    sut.pool.upgrade_token(&asset, &true, &s_token_v1_wasm);
+    sut.pool.upgrade_token(&asset, &false, &debt_token_v1_wasm);
     sut.pool.upgrade(&pool_v2_wasm);
 
     let pool_version_after = sut.pool.version();
-    let s_token_version_after = sut.s_token().version();
-    let debt_token_version_after = sut.debt_token().version();
 
     assert_eq!(pool_version_before, 1);
     assert_eq!(pool_version_after, 2);
-    assert_eq!(s_token_version_before, 1);
-    assert_eq!(s_token_version_after, 2);
-    assert_eq!(debt_token_version_before, 1);
-    assert_eq!(debt_token_version_after, 2);
+    assert_eq!(sut.s_token().version(), s_token_interface::VERSION);
+    assert_eq!(sut.debt_token().version(), debt_token_interface::VERSION);
+}
+
+#[test]
+fn should_require_admin_to_upgrade_tokens() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, true);
+    let s_token_v1_wasm = env.deployer().upload_contract_wasm(s_token_v1::WASM);
+    let debt_token_v1_wasm = env.deployer().upload_contract_wasm(debt_token_v1::WASM);
+
+    sut.pool
+        .upgrade_tokens(&s_token_v1_wasm, &debt_token_v1_wasm);
+
+    assert_eq!(
+        env.auths(),
+        [(
+            sut.pool_admin,
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    sut.pool.address.clone(),
+                    Symbol::new(&env, "upgrade_tokens"),
+                    vec![
+                        &env,
+                        s_token_v1_wasm.into_val(&env),
+                        debt_token_v1_wasm.into_val(&env)
+                    ]
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+}
+
+#[test]
+fn should_upgrade_tokens_of_all_reserves() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, true);
+    let s_token_v1_wasm = env.deployer().upload_contract_wasm(s_token_v1::WASM);
+    let debt_token_v1_wasm = env.deployer().upload_contract_wasm(debt_token_v1::WASM);
+
+    sut.pool
+        .upgrade_tokens(&s_token_v1_wasm, &debt_token_v1_wasm);
+
+    for reserve in sut.reserves.iter().filter(|r| r.s_token.is_some()) {
+        assert_eq!(reserve.s_token().version(), s_token_interface::VERSION);
+        assert_eq!(
+            reserve.debt_token().version(),
+            debt_token_interface::VERSION
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #108)")]
+fn should_fail_when_token_version_is_not_released() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, true);
+    let asset = sut.reserves[0].token.address.clone();
+    let s_token_v2_wasm = env.deployer().upload_contract_wasm(s_token_v2::WASM);
+
+    sut.pool.upgrade_token(&asset, &true, &s_token_v2_wasm);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #108)")]
+fn should_fail_when_debt_token_version_is_not_released() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, true);
+    let asset = sut.reserves[0].token.address.clone();
+    let debt_token_v2_wasm = env.deployer().upload_contract_wasm(debt_token_v2::WASM);
+
+    sut.pool.upgrade_token(&asset, &false, &debt_token_v2_wasm);
+}
+
+#[test]
+fn should_emit_events_per_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, true);
+    let s_token_v1_wasm = env.deployer().upload_contract_wasm(s_token_v1::WASM);
+    let debt_token_v1_wasm = env.deployer().upload_contract_wasm(debt_token_v1::WASM);
+
+    sut.pool
+        .upgrade_tokens(&s_token_v1_wasm, &debt_token_v1_wasm);
+
+    let mut expected = vec![&env];
+    for reserve in sut.reserves.iter().filter(|r| r.s_token.is_some()) {
+        for token in [&reserve.s_token().address, &reserve.debt_token().address] {
+            expected.push_back((
+                sut.pool.address.clone(),
+                (
+                    Symbol::new(&env, "token_upgraded"),
+                    1u32,
+                    reserve.token.address.clone(),
+                    token.clone(),
+                )
+                    .into_val(&env),
+                (1u32, 1u32).into_val(&env),
+            ));
+        }
+    }
+
+    let events = env.events().all();
+    let events = events.slice(events.len() - expected.len()..);
+
+    assert_eq!(events, expected);
+}
+
+#[test]
+fn should_roll_back_incompatible_token_upgrade() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, true);
+    let asset = sut.reserves[0].token.address.clone();
+    let token_wasm = env.deployer().upload_contract_wasm(token::WASM);
+
+    let result = sut.pool.try_upgrade_token(&asset, &true, &token_wasm);
+
+    assert!(result.is_err());
+    assert_eq!(sut.s_token().version(), s_token_interface::VERSION);
+}
+
+#[test]
+fn should_roll_back_incompatible_tokens_upgrade() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, true);
+    let s_token_v1_wasm = env.deployer().upload_contract_wasm(s_token_v1::WASM);
+    let token_wasm = env.deployer().upload_contract_wasm(token::WASM);
+
+    let result = sut.pool.try_upgrade_tokens(&s_token_v1_wasm, &token_wasm);
+
+    assert!(result.is_err());
+    assert_eq!(sut.s_token().version(), s_token_interface::VERSION);
+    assert_eq!(sut.debt_token().version(), debt_token_interface::VERSION);
+}
+
+#[contract]
+struct VersionedToken;
+
+#[contractimpl]
+impl VersionedToken {
+    pub fn set_version(env: Env, version: u32) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("version"), &version);
+    }
+
+    pub fn version(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("version"))
+            .unwrap()
+    }
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #108)")]
+fn should_fail_when_token_version_is_not_supported() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let outdated_token = env.register_contract(None, VersionedToken);
+
+    VersionedTokenClient::new(&env, &outdated_token)
+        .set_version(&(s_token_interface::MIN_COMPATIBLE_VERSION - 1));
+
+    env.as_contract(&sut.pool.address, || {
+        require_compatible_s_token(&env, &outdated_token)
+    });
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #108)")]
+fn should_fail_when_token_version_is_too_new() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let unknown_token = env.register_contract(None, VersionedToken);
+
+    VersionedTokenClient::new(&env, &unknown_token)
+        .set_version(&(s_token_interface::MAX_COMPATIBLE_VERSION + 1));
+
+    env.as_contract(&sut.pool.address, || {
+        require_compatible_s_token(&env, &unknown_token)
+    });
+}
+
+#[test]
+fn should_emit_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, true);
+    let asset = sut.reserves[0].token.address.clone();
+    let pool_v2_wasm = env.deployer().upload_contract_wasm(pool_v2::WASM);
+    let s_token_v1_wasm = env.deployer().upload_contract_wasm(s_token_v1::WASM);
+
+    sut.pool.upgrade_token(&asset, &true, &s_token_v1_wasm);
+
+    let event = env.events().all().pop_back_unchecked();
+
+    assert_eq!(
+        vec![&env, event],
+        vec![
+            &env,
+            (
+                sut.pool.address.clone(),
+                (
+                    Symbol::new(&env, "token_upgraded"),
+                    1u32,
+                    asset,
+                    sut.s_token().address.clone()
+                )
+                    .into_val(&env),
+                (1u32, 1u32).into_val(&env)
+            ),
+        ]
+    );
+
+    sut.pool.upgrade(&pool_v2_wasm);
+
+    let event = env.events().all().pop_back_unchecked();
+
+    assert_eq!(
+        vec![&env, event],
+        vec![
+            &env,
+            (
+                sut.pool.address.clone(),
+                (symbol_short!("upgraded"), 1u32).into_val(&env),
+                (1u32, pool_v2_wasm).into_val(&env)
+            ),
+        ]
+    );
 }
@@ -1,6 +1,9 @@
 use super::sut::fill_pool_three;
+use crate::storage::{DataKey, LegacyUserConfiguration};
 use crate::tests::sut::init_pool;
 use crate::*;
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{vec, IntoVal, Symbol, TryFromVal, Val};
 
 #[test]
 #[should_panic(expected = "HostError: Error(Contract, #1)")]
@@ -54,3 +57,144 @@ fn should_return_user_config() {
         true
     );
 }
+
+#[test]
+fn should_support_full_reserve_id_range() {
+    let env = Env::default();
+
+    let mut user_config = UserConfiguration::new(&env);
+
+    user_config.set_using_as_collateral(&env, 0, true);
+    user_config.set_borrowing(&env, 64, true);
+    user_config.set_using_as_collateral(&env, u8::MAX, true);
+
+    assert_eq!(user_config.total_assets(), 3);
+    assert!(user_config.is_borrowing_any());
+    assert!(user_config.is_borrowing(&env, 64));
+    assert!(!user_config.is_borrowing(&env, 0));
+    assert!(user_config.is_using_as_collateral(&env, u8::MAX));
+    assert!(!user_config.is_using_as_collateral(&env, 127));
+    assert!(!user_config.is_using_as_collateral_or_borrowing(&env, 128));
+
+    user_config.set_borrowing(&env, 64, false);
+
+    assert!(!user_config.is_borrowing_any());
+    assert_eq!(user_config.total_assets(), 2);
+
+    user_config.set_using_as_collateral(&env, 0, false);
+    user_config.set_using_as_collateral(&env, u8::MAX, false);
+
+    assert!(user_config.is_empty());
+    assert_eq!(user_config.total_assets(), 0);
+}
+
+fn set_legacy_user_config(env: &Env, pool: &Address, user: &Address) {
+    // collateral in reserve 0, debt in reserve 1
+    env.as_contract(pool, || {
+        env.storage().persistent().set(
+            &DataKey::UserConfig(user.clone()),
+            &LegacyUserConfiguration(0b0110, 2),
+        )
+    });
+}
+
+#[test]
+fn should_read_legacy_user_config() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let user = Address::generate(&env);
+    set_legacy_user_config(&env, &sut.pool.address, &user);
+
+    let user_config = sut.pool.user_configuration(&user);
+
+    assert_eq!(user_config.total_assets(), 2);
+    assert!(user_config.is_using_as_collateral(&env, 0));
+    assert!(user_config.is_borrowing(&env, 1));
+}
+
+#[test]
+fn should_store_legacy_user_config_in_current_layout_on_update() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let user = Address::generate(&env);
+    let asset = sut.reserves[2].token.address.clone();
+
+    env.as_contract(&sut.pool.address, || {
+        env.storage().persistent().set(
+            &DataKey::UserConfig(user.clone()),
+            &LegacyUserConfiguration(0b0010, 1),
+        )
+    });
+
+    sut.reserves[2].token_admin.mint(&user, &1_000_000_000);
+    sut.pool.deposit(&user, &asset, &1_000_000_000);
+
+    env.as_contract(&sut.pool.address, || {
+        let user_config: Val = env
+            .storage()
+            .persistent()
+            .get(&DataKey::UserConfig(user.clone()))
+            .unwrap();
+
+        assert!(UserConfiguration::try_from_val(&env, &user_config).is_ok());
+    });
+
+    let user_config = sut.pool.user_configuration(&user);
+
+    assert_eq!(user_config.total_assets(), 2);
+    assert!(user_config.is_using_as_collateral(&env, 0));
+    assert!(user_config.is_using_as_collateral(&env, 2));
+}
+
+#[test]
+fn should_read_migrated_legacy_user_config() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let user = Address::generate(&env);
+    let current_user = Address::generate(&env);
+    set_legacy_user_config(&env, &sut.pool.address, &user);
+
+    sut.pool
+        .migrate_user_configs(&vec![&env, user.clone(), current_user.clone()]);
+
+    let user_config = sut.pool.user_configuration(&user);
+
+    assert_eq!(user_config.total_assets(), 2);
+    assert!(user_config.is_using_as_collateral(&env, 0));
+    assert!(!user_config.is_borrowing(&env, 0));
+    assert!(user_config.is_borrowing(&env, 1));
+    assert!(user_config.is_borrowing_any());
+}
+
+#[test]
+fn should_emit_events_on_user_configs_migration() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let user = Address::generate(&env);
+    set_legacy_user_config(&env, &sut.pool.address, &user);
+
+    sut.pool
+        .migrate_user_configs(&vec![&env, user.clone(), Address::generate(&env)]);
+
+    let event = env.events().all().pop_back_unchecked();
+
+    assert_eq!(
+        vec![&env, event],
+        vec![
+            &env,
+            (
+                sut.pool.address.clone(),
+                (Symbol::new(&env, "user_configs_migrated"), 1u32).into_val(&env),
+                1u32.into_val(&env)
+            ),
+        ]
+    );
+}
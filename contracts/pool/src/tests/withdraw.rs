@@ -48,7 +48,7 @@ fn should_fail_when_pool_paused() {
 
     set_time(&env, &sut, 2 * DAY, false);
 
-    sut.pool.set_pause(&true);
+    sut.pool.set_pause(&sut.pool_admin, &true);
     sut.pool
         .withdraw(&borrower, &token_address, &1_000_000, &borrower);
 }
@@ -365,7 +365,7 @@ fn should_emit_events() {
         ]
     );
 
-    let event = events.get(23).unwrap();
+    let event = events.get(44).unwrap();
 
     assert_eq!(
         vec![&env, event],
@@ -626,8 +626,8 @@ fn should_fail_in_grace_period() {
     let collat_address = sut.reserves[0].token.address.clone();
     sut.pool.withdraw(&borrower, &collat_address, &1, &borrower);
 
-    sut.pool.set_pause(&true);
-    sut.pool.set_pause(&false);
+    sut.pool.set_pause(&sut.pool_admin, &true);
+    sut.pool.set_pause(&sut.pool_admin, &false);
     sut.pool.withdraw(&borrower, &collat_address, &1, &borrower);
 }
 
@@ -648,9 +648,9 @@ fn should_not_fail_after_grace_period() {
     let s_token_after = sut.reserves[0].debt_token().balance(&borrower);
     assert!(s_token_after < s_token_before);
 
-    sut.pool.set_pause(&true);
+    sut.pool.set_pause(&sut.pool_admin, &true);
     set_time(&env, &sut, start + gap, false);
-    sut.pool.set_pause(&false);
+    sut.pool.set_pause(&sut.pool_admin, &false);
     set_time(
         &env,
         &sut,
@@ -0,0 +1,147 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::tests::sut::{fill_pool, init_pool, remove_collateral, DAY};
+use crate::*;
+use soroban_sdk::testutils::{AuthorizedFunction, AuthorizedInvocation, Events};
+use soroban_sdk::{vec, IntoVal, Symbol};
+
+#[test]
+fn should_require_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, borrower, debt_config) = fill_pool(&env, &sut, true);
+    let asset = debt_config.token.address.clone();
+
+    sut.pool.start_wind_down(&1_000, &DAY);
+    sut.pool.start_settlement();
+    remove_collateral(&env, &sut, &borrower);
+    sut.pool.write_off_debt(&borrower, &asset);
+
+    assert_eq!(
+        env.auths(),
+        [(
+            sut.pool_admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    sut.pool.address.clone(),
+                    Symbol::new(&env, "write_off_debt"),
+                    (borrower.clone(), asset.clone()).into_val(&env)
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #8)")]
+fn should_fail_before_settlement() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, borrower, debt_config) = fill_pool(&env, &sut, true);
+
+    sut.pool.start_wind_down(&1_000, &DAY);
+    remove_collateral(&env, &sut, &borrower);
+    sut.pool
+        .write_off_debt(&borrower, &debt_config.token.address);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #308)")]
+fn should_fail_when_collateral_not_liquidated() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, borrower, debt_config) = fill_pool(&env, &sut, true);
+
+    sut.pool.start_wind_down(&1_000, &DAY);
+    sut.pool.start_settlement();
+    sut.pool
+        .write_off_debt(&borrower, &debt_config.token.address);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #201)")]
+fn should_fail_without_debt() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (lender, _, debt_config) = fill_pool(&env, &sut, true);
+
+    sut.pool.start_wind_down(&1_000, &DAY);
+    sut.pool.start_settlement();
+    remove_collateral(&env, &sut, &lender);
+    sut.pool.write_off_debt(&lender, &debt_config.token.address);
+}
+
+#[test]
+fn should_write_off_debt() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, borrower, debt_config) = fill_pool(&env, &sut, true);
+    let asset = debt_config.token.address.clone();
+    let debt_token = debt_config.debt_token();
+
+    sut.pool.start_wind_down(&1_000, &DAY);
+    sut.pool.start_settlement();
+    remove_collateral(&env, &sut, &borrower);
+
+    let s_token_underlying = sut
+        .pool
+        .token_balance(&asset, &debt_config.s_token().address);
+
+    sut.pool.write_off_debt(&borrower, &asset);
+
+    assert_eq!(debt_token.balance(&borrower), 0);
+    assert_eq!(debt_token.total_supply(), 0);
+    assert_eq!(sut.pool.token_total_supply(&debt_token.address), 0);
+    assert!(!sut.pool.user_configuration(&borrower).is_borrowing_any());
+    // nothing is paid to the lenders
+    assert_eq!(
+        sut.pool
+            .token_balance(&asset, &debt_config.s_token().address),
+        s_token_underlying
+    );
+}
+
+#[test]
+fn should_emit_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let sut = init_pool(&env, false);
+    let (_, borrower, debt_config) = fill_pool(&env, &sut, true);
+    let asset = debt_config.token.address.clone();
+
+    sut.pool.start_wind_down(&1_000, &DAY);
+    sut.pool.start_settlement();
+    remove_collateral(&env, &sut, &borrower);
+
+    let debt =
+        debt_config.debt_token().balance(&borrower) * sut.pool.debt_coeff(&asset) / 1_000_000_000;
+
+    sut.pool.write_off_debt(&borrower, &asset);
+
+    let event = env.events().all().pop_back_unchecked();
+
+    assert_eq!(
+        vec![&env, event],
+        vec![
+            &env,
+            (
+                sut.pool.address.clone(),
+                (Symbol::new(&env, "debt_written_off"), borrower.clone()).into_val(&env),
+                (asset, debt).into_val(&env)
+            ),
+        ]
+    );
+}
@@ -1,14 +1,23 @@
 use core::ops::Div;
 
+use common::PERCENTAGE_FACTOR;
 use pool_interface::types::error::Error;
 use pool_interface::types::pool_config::PoolConfig;
+use pool_interface::types::price_breaker::PriceBreaker;
 use pool_interface::types::price_feed::PriceFeed;
 use pool_interface::types::price_feed_config::PriceFeedConfig;
+use pool_interface::types::price_leg::PriceLeg;
+use pool_interface::types::price_source::PriceSource;
 use pool_interface::types::timestamp_precision::TimestampPrecision;
+use price_feed_interface::types::price_data::PriceData;
 use price_feed_interface::PriceFeedClient;
 use soroban_sdk::{assert_with_error, Address, Env, Map, Vec};
 
-use crate::storage::read_price_feeds;
+use crate::event;
+use crate::storage::{
+    read_last_good_price, read_price_breaker, read_price_feeds, write_last_good_price,
+    write_price_breaker,
+};
 
 pub struct PriceProvider<'a> {
     env: &'a Env,
@@ -16,6 +25,10 @@ pub struct PriceProvider<'a> {
     base_asset_decimals: u32,
     configs: Map<Address, PriceFeedConfig>,
     prices: Map<Address, i128>,
+    conservative: bool,
+    record_prices: bool,
+    /// Whether a feed of the asset being priced reported a price outside the sanity bounds
+    insane_price: bool,
 }
 
 impl<'a> PriceProvider<'a> {
@@ -26,22 +39,64 @@ impl<'a> PriceProvider<'a> {
             base_asset_decimals: pool_config.base_asset_decimals,
             configs: Map::new(env),
             prices: Map::new(env),
+            conservative: false,
+            record_prices: false,
+            insane_price: false,
         })
     }
 
+    /// Enables the conservative pricing of the assets configured for it,
+    /// used by the health checks of new borrows and withdrawals
+    pub fn conservative(mut self) -> Self {
+        self.conservative = true;
+        self
+    }
+
+    /// Stores the last good prices and the circuit breaker state and emits the price events,
+    /// used by the pool operations so the price views stay read-only
+    pub fn record_prices(mut self) -> Self {
+        self.record_prices = true;
+        self
+    }
+
     pub fn convert_to_base(&mut self, asset: &Address, amount: i128) -> Result<i128, Error> {
+        self.convert_to_base_bounded(asset, amount, None)
+    }
+
+    /// Values collateral at the lower of the spot and TWAP prices in the conservative mode
+    pub fn convert_collat_to_base(&mut self, asset: &Address, amount: i128) -> Result<i128, Error> {
+        self.convert_to_base_bounded(asset, amount, Some(i128::min))
+    }
+
+    /// Values debt at the higher of the spot and TWAP prices in the conservative mode
+    pub fn convert_debt_to_base(&mut self, asset: &Address, amount: i128) -> Result<i128, Error> {
+        self.convert_to_base_bounded(asset, amount, Some(i128::max))
+    }
+
+    fn convert_to_base_bounded(
+        &mut self,
+        asset: &Address,
+        amount: i128,
+        bound: Option<fn(i128, i128) -> i128>,
+    ) -> Result<i128, Error> {
         if self.base_asset_address == *asset {
             return Ok(amount);
         }
 
         let config = self.config(asset)?;
-        let median_twap_price = self.price_in_base(asset, &config)?;
+        let mut price = self.price_in_base(asset, &config)?;
+
+        if let Some(bound) = bound.filter(|_| self.conservative && config.conservative_pricing) {
+            if let Some(spot_price) = self.feeds_price(&config, PriceSource::LastPrice)? {
+                price = bound(price, spot_price);
+            }
+        }
 
         let precision = 10i128
             .checked_pow(config.asset_decimals)
             .ok_or(Error::MathOverflowError)?;
 
-        median_twap_price
+        price
             .checked_mul(amount)
             .ok_or(Error::InvalidAssetPrice)?
             .checked_div(precision)
@@ -106,70 +161,348 @@ impl<'a> PriceProvider<'a> {
     }
 
     fn price_in_base(&mut self, asset: &Address, config: &PriceFeedConfig) -> Result<i128, Error> {
-        let price = self.prices.get(asset.clone());
+        if let Some(price) = self.prices.get(asset.clone()) {
+            return Ok(price);
+        }
+
+        self.insane_price = false;
 
-        match price {
-            Some(price) => Ok(price),
+        let mut source = PriceSource::Twap;
+        let mut price = self.feeds_price(config, source)?;
+
+        if price.is_none() && config.lastprice_fallback {
+            source = PriceSource::LastPrice;
+            price = self.feeds_price(config, source)?;
+        }
+
+        let price = match price {
+            Some(price) => {
+                let (price, is_held) = self.breaker_price(asset, config, price)?;
+
+                // The held reference price isn't a fresh one and must not age out
+                if !is_held {
+                    self.record_last_good_price(asset, config, price);
+                }
+
+                price
+            }
             None => {
-                let mut sorted_twap_prices = Map::new(self.env);
+                let error = if self.insane_price {
+                    Error::InvalidAssetPrice
+                } else if config.min_feeds > 1 {
+                    Error::NotEnoughPriceFeeds
+                } else {
+                    Error::NoPriceForAsset
+                };
 
-                for feed in config.feeds.iter() {
-                    let twap = self.twap(&feed);
+                source = PriceSource::StoredPrice;
+                self.stored_price(asset, config).ok_or(error)?
+            }
+        };
 
-                    if twap.is_err() {
-                        continue;
-                    }
+        if source != PriceSource::Twap && self.record_prices {
+            event::price_fallback(self.env, asset, source, price);
+        }
+
+        self.prices.set(asset.clone(), price);
+
+        Ok(price)
+    }
+
+    /// Weighted median of the prices in base reported by the feeds,
+    /// `None` if fewer than `min_feeds` feeds returned a fresh price within the sanity bounds
+    fn feeds_price(
+        &mut self,
+        config: &PriceFeedConfig,
+        source: PriceSource,
+    ) -> Result<Option<i128>, Error> {
+        let mut prices = Vec::new(self.env);
+
+        for feed in config.feeds.iter() {
+            let price_in_base = match self.path_price(&feed, source)? {
+                Some(price) => price,
+                None => continue,
+            };
+
+            let is_sanity_price = price_in_base >= config.min_sanity_price_in_base
+                && price_in_base <= config.max_sanity_price_in_base;
+
+            // An insane feed is skipped like a stale one, the quorum and deviation checks decide
+            if !is_sanity_price {
+                self.insane_price = true;
+                continue;
+            }
+
+            insert_sorted(&mut prices, price_in_base, feed.weight.unwrap_or(1));
+        }
+
+        if prices.is_empty() || prices.len() < config.min_feeds {
+            return Ok(None);
+        }
+
+        let median_price = self.median(&prices)?;
+
+        if config.max_feed_deviation == 0 {
+            return Ok(Some(median_price));
+        }
+
+        self.agreed_price(config, &prices, median_price).map(Some)
+    }
+
+    /// Price in base along the path of the feed, `None` if any leg of the path has no fresh price
+    fn path_price(&mut self, feed: &PriceFeed, source: PriceSource) -> Result<Option<i128>, Error> {
+        let first_leg = PriceLeg {
+            feed: feed.feed.clone(),
+            feed_asset: feed.feed_asset.clone(),
+            feed_decimals: feed.feed_decimals,
+            twap_window: feed.twap_window,
+            min_twap_span: feed.min_twap_span,
+            min_timestamp_delta: feed.min_timestamp_delta,
+            timestamp_precision: feed.timestamp_precision.clone(),
+        };
 
-                    let base_precision = 10i128
-                        .checked_pow(self.base_asset_decimals)
-                        .ok_or(Error::MathOverflowError)?;
+        let mut price = 10i128
+            .checked_pow(self.base_asset_decimals)
+            .ok_or(Error::MathOverflowError)?;
+
+        for leg in [first_leg].into_iter().chain(feed.path.iter()) {
+            let leg_price = match self.leg_price(&leg, source) {
+                Ok(leg_price) => leg_price,
+                Err(_) => return Ok(None),
+            };
+
+            let leg_precision = 10i128
+                .checked_pow(leg.feed_decimals)
+                .ok_or(Error::MathOverflowError)?;
+
+            price = price
+                .checked_mul(leg_price)
+                .ok_or(Error::MathOverflowError)?
+                .checked_div(leg_precision)
+                .ok_or(Error::MathOverflowError)?;
+        }
 
-                    let feed_precision = 10i128
-                        .checked_pow(feed.feed_decimals)
-                        .ok_or(Error::MathOverflowError)?;
+        Ok(Some(price))
+    }
 
-                    let twap_price = twap?
-                        .checked_mul(base_precision)
-                        .ok_or(Error::MathOverflowError)?
-                        .checked_div(feed_precision)
-                        .ok_or(Error::MathOverflowError)?;
+    fn leg_price(&mut self, leg: &PriceLeg, source: PriceSource) -> Result<i128, Error> {
+        match source {
+            PriceSource::LastPrice => self.lastprice(leg),
+            _ => self.twap(leg),
+        }
+    }
+
+    /// Median of the prices within `max_feed_deviation` from the median of all the feeds.
+    /// Fails if the outliers are not a minority of the feeds or leave less than `min_feeds` prices.
+    fn agreed_price(
+        &mut self,
+        config: &PriceFeedConfig,
+        prices: &Vec<(i128, u32)>,
+        median_price: i128,
+    ) -> Result<i128, Error> {
+        let mut agreed_prices = Vec::new(self.env);
+        let mut agreed_weight = 0u64;
+        let mut total_weight = 0u64;
+
+        for (price, weight) in prices.iter() {
+            total_weight += u64::from(weight);
+
+            let deviation = price
+                .checked_sub(median_price)
+                .ok_or(Error::MathOverflowError)?
+                .abs()
+                .checked_mul(PERCENTAGE_FACTOR.into())
+                .ok_or(Error::MathOverflowError)?
+                .checked_div(median_price)
+                .ok_or(Error::MathOverflowError)?;
+
+            if deviation <= i128::from(config.max_feed_deviation) {
+                agreed_weight += u64::from(weight);
+                agreed_prices.push_back((price, weight));
+            }
+        }
+
+        assert_with_error!(
+            self.env,
+            agreed_weight * 2 > total_weight && agreed_prices.len() >= config.min_feeds,
+            Error::PriceDeviationExceeded
+        );
+
+        if agreed_prices.len() == prices.len() {
+            return Ok(median_price);
+        }
+
+        self.median(&agreed_prices)
+    }
+
+    /// Passes the price through the circuit breaker. While the breaker is tripped
+    /// the price is held at the reference price the move was measured from,
+    /// which is reported by the returned flag.
+    /// Once the window elapses the move is measured from the last good price,
+    /// if it is newer than the reference, before the reference rolls forward.
+    fn breaker_price(
+        &mut self,
+        asset: &Address,
+        config: &PriceFeedConfig,
+        price: i128,
+    ) -> Result<(i128, bool), Error> {
+        if config.max_price_change == 0 {
+            return Ok((price, false));
+        }
 
-                    let is_sanity_price = twap_price >= config.min_sanity_price_in_base
-                        && twap_price <= config.max_sanity_price_in_base;
+        let now = self.env.ledger().timestamp();
 
-                    assert_with_error!(self.env, is_sanity_price, Error::InvalidAssetPrice);
+        let (mut breaker, window_elapsed) = match read_price_breaker(self.env, asset) {
+            Some(breaker) if breaker.is_tripped(now, config.breaker_timeout) => {
+                return Ok((breaker.reference_price, true));
+            }
+            Some(breaker) if breaker.tripped_at.is_none() => {
+                let window_elapsed =
+                    now.saturating_sub(breaker.reference_timestamp) > config.price_change_window;
 
-                    sorted_twap_prices.set(twap_price, twap_price);
+                (breaker, window_elapsed)
+            }
+            // No breaker yet, or it was reset by the oracle admin or the timeout
+            _ => {
+                if self.record_prices {
+                    let breaker = PriceBreaker {
+                        reference_price: price,
+                        reference_timestamp: now,
+                        tripped_at: None,
+                    };
+                    write_price_breaker(self.env, asset, &breaker);
                 }
 
-                assert_with_error!(
-                    self.env,
-                    !sorted_twap_prices.is_empty(),
-                    Error::NoPriceForAsset
-                );
+                return Ok((price, false));
+            }
+        };
 
-                let median_twap_price = self.median(&sorted_twap_prices.keys())?;
+        if window_elapsed {
+            if let Some(last_good_price) = read_last_good_price(self.env, asset)
+                .filter(|last_good_price| last_good_price.timestamp > breaker.reference_timestamp)
+            {
+                breaker.reference_price = last_good_price.price;
+                breaker.reference_timestamp = last_good_price.timestamp;
+            }
+        }
 
-                self.prices.set(asset.clone(), median_twap_price);
+        let change = price
+            .checked_sub(breaker.reference_price)
+            .ok_or(Error::MathOverflowError)?
+            .abs()
+            .checked_mul(PERCENTAGE_FACTOR.into())
+            .ok_or(Error::MathOverflowError)?
+            .checked_div(breaker.reference_price)
+            .ok_or(Error::MathOverflowError)?;
 
-                Ok(median_twap_price)
+        if change <= i128::from(config.max_price_change) {
+            if window_elapsed && self.record_prices {
+                let breaker = PriceBreaker {
+                    reference_price: price,
+                    reference_timestamp: now,
+                    tripped_at: None,
+                };
+                write_price_breaker(self.env, asset, &breaker);
             }
+
+            return Ok((price, false));
+        }
+
+        if self.record_prices {
+            breaker.tripped_at = Some(now);
+            write_price_breaker(self.env, asset, &breaker);
+            event::price_breaker_tripped(self.env, asset, breaker.reference_price, price);
+        }
+
+        Ok((breaker.reference_price, true))
+    }
+
+    /// Stores the price for the fallback once the stored one is half its max age old,
+    /// so the operations write it at most once per half of the max age
+    fn record_last_good_price(&self, asset: &Address, config: &PriceFeedConfig, price: i128) {
+        if !self.record_prices || config.stored_price_max_age == 0 {
+            return;
+        }
+
+        let timestamp = self.env.ledger().timestamp();
+
+        let is_fresh = read_last_good_price(self.env, asset).is_some_and(|stored| {
+            timestamp.saturating_sub(stored.timestamp) < config.stored_price_max_age / 2
+        });
+
+        if !is_fresh {
+            write_last_good_price(self.env, asset, &PriceData { price, timestamp });
+        }
+    }
+
+    fn stored_price(&self, asset: &Address, config: &PriceFeedConfig) -> Option<i128> {
+        if config.stored_price_max_age == 0 {
+            return None;
+        }
+
+        let stored = read_last_good_price(self.env, asset)?;
+        let age = self
+            .env
+            .ledger()
+            .timestamp()
+            .checked_sub(stored.timestamp)?;
+
+        (age <= config.stored_price_max_age).then_some(stored.price)
+    }
+
+    fn lastprice(&mut self, config: &PriceLeg) -> Result<i128, Error> {
+        let client = PriceFeedClient::new(self.env, &config.feed);
+
+        let price = client
+            .try_lastprice(&config.feed_asset.clone().into())
+            .ok()
+            .and_then(|price| price.ok())
+            .flatten()
+            .ok_or(Error::NoPriceForAsset)?;
+
+        let curr_time = precise_timestamp(self.env, &config.timestamp_precision);
+
+        let timestamp_delta = curr_time
+            .checked_sub(price.timestamp)
+            .ok_or(Error::MathOverflowError)?;
+
+        if timestamp_delta > config.min_timestamp_delta {
+            return Err(Error::NoPriceForAsset);
         }
+
+        Ok(price.price)
     }
 
-    fn twap(&mut self, config: &PriceFeed) -> Result<i128, Error> {
+    /// TWAP over the window of the leg. The resolution is asked on every call,
+    /// so the record count follows the feed if it changes its resolution.
+    fn twap(&mut self, config: &PriceLeg) -> Result<i128, Error> {
         let client = PriceFeedClient::new(self.env, &config.feed);
 
+        let twap_window = precise_duration(config.twap_window, &config.timestamp_precision);
+        let min_twap_span = precise_duration(config.min_twap_span, &config.timestamp_precision);
+
+        let resolution = feed_resolution(self.env, &config.feed).ok_or(Error::NoPriceForAsset)?;
+
+        // resolution is in the units of the feed timestamps, one more record covers the window start
+        let records = twap_window
+            .checked_div(resolution.into())
+            .ok_or(Error::NoPriceForAsset)?
+            .saturating_add(1);
+
         let prices = client
-            .prices(&config.feed_asset.clone().into(), &config.twap_records)
+            .try_prices(
+                &config.feed_asset.clone().into(),
+                &u32::try_from(records).unwrap_or(u32::MAX),
+            )
+            .ok()
+            .and_then(|prices| prices.ok())
+            .flatten()
             .ok_or(Error::NoPriceForAsset)?;
 
         if prices.is_empty() {
             return Err(Error::NoPriceForAsset);
         }
 
-        let prices_len = prices.len();
-
         let curr_time = precise_timestamp(self.env, &config.timestamp_precision);
 
         let mut sorted_prices = Map::new(self.env);
@@ -189,85 +522,111 @@ impl<'a> PriceProvider<'a> {
             return Err(Error::NoPriceForAsset);
         }
 
-        if prices_len == 1 {
-            return Ok(sorted_prices.values().first_unchecked());
-        }
+        let window_start = curr_time.saturating_sub(twap_window);
 
-        let mut cum_price = {
-            let price_curr = prices.last_unchecked();
-            let timestamp_curr = timestamps.last_unchecked();
+        let twap_span = curr_time
+            .checked_sub(timestamps.first_unchecked().max(window_start))
+            .ok_or(Error::MathOverflowError)?;
 
-            let time_delta = curr_time
-                .checked_sub(timestamp_curr)
-                .ok_or(Error::MathOverflowError)?;
+        if twap_span < min_twap_span {
+            return Err(Error::NoPriceForAsset);
+        }
 
-            if time_delta.eq(&0) {
-                price_curr
-            } else {
-                price_curr
-                    .checked_mul(time_delta.into())
-                    .ok_or(Error::MathOverflowError)?
-            }
-        };
+        if twap_span == 0 {
+            return Ok(prices.last_unchecked());
+        }
 
-        for i in (1..prices_len).rev() {
-            let price_curr = prices.get_unchecked(i - 1);
-            let timestamp_curr = timestamps.get_unchecked(i - 1);
-            let timestamp_prev = timestamps.get_unchecked(i);
+        let mut cum_price = 0i128;
+        let mut period_end = curr_time;
 
-            let time_delta = timestamp_prev
-                .checked_sub(timestamp_curr)
+        for i in (0..prices.len()).rev() {
+            let timestamp = timestamps.get_unchecked(i);
+
+            let time_delta = period_end
+                .checked_sub(timestamp.max(window_start))
                 .ok_or(Error::MathOverflowError)?;
 
-            let tw_price = price_curr
+            let tw_price = prices
+                .get_unchecked(i)
                 .checked_mul(time_delta.into())
                 .ok_or(Error::MathOverflowError)?;
 
             cum_price = cum_price
                 .checked_add(tw_price)
                 .ok_or(Error::MathOverflowError)?;
-        }
 
-        let twap_time = curr_time
-            .checked_sub(timestamps.first_unchecked())
-            .ok_or(Error::MathOverflowError)?;
+            if timestamp <= window_start {
+                break;
+            }
+
+            period_end = timestamp;
+        }
 
         let twap_price = cum_price
-            .checked_div(twap_time.into())
+            .checked_div(twap_span.into())
             .ok_or(Error::MathOverflowError)?;
 
         Ok(twap_price)
     }
 
-    fn median(&mut self, prices: &Vec<i128>) -> Result<i128, Error> {
-        let prices_len = prices.len();
+    /// Weighted median of the prices sorted in ascending order, the mean of the two middle
+    /// prices when the weight splits exactly in half between them
+    fn median(&mut self, prices: &Vec<(i128, u32)>) -> Result<i128, Error> {
+        let total_weight: u64 = prices.iter().map(|(_, weight)| u64::from(weight)).sum();
+        let mut cum_weight = 0u64;
 
-        if prices_len == 1 {
-            return Ok(prices.first_unchecked());
-        }
+        for i in 0..prices.len() {
+            let (price, weight) = prices.get_unchecked(i);
 
-        let index = prices_len / 2;
+            cum_weight += u64::from(weight);
 
-        let median_price = if prices_len % 2 == 0 {
-            let price_1 = prices.get_unchecked(index - 1);
-            let price_2 = prices.get_unchecked(index);
+            if cum_weight * 2 > total_weight {
+                return Ok(price);
+            }
 
-            price_1
-                .checked_add(price_2)
-                .ok_or(Error::MathOverflowError)?
-                .div(2)
-        } else {
-            prices.get_unchecked(index)
-        };
+            if cum_weight * 2 == total_weight {
+                // Non-zero weights leave a next price, the price is kept as is if there is none
+                let next_price = prices
+                    .get(i + 1)
+                    .map_or(price, |(next_price, _)| next_price);
+
+                return Ok(price
+                    .checked_add(next_price)
+                    .ok_or(Error::MathOverflowError)?
+                    .div(2));
+            }
+        }
 
-        Ok(median_price)
+        Err(Error::NoPriceForAsset)
     }
 }
 
+/// Inserts the weighted price keeping the prices sorted, equal prices are kept side by side
+fn insert_sorted(prices: &mut Vec<(i128, u32)>, price: i128, weight: u32) {
+    let mut index = prices.len();
+
+    while index > 0 && prices.get_unchecked(index - 1).0 > price {
+        index -= 1;
+    }
+
+    prices.insert(index, (price, weight));
+}
+
+/// Resolution of the feed in the units of its timestamps, `None` if the feed does not report it
+pub(crate) fn feed_resolution(env: &Env, feed: &Address) -> Option<u32> {
+    PriceFeedClient::new(env, feed)
+        .try_resolution()
+        .ok()
+        .and_then(|resolution| resolution.ok())
+}
+
 pub(crate) fn precise_timestamp(env: &Env, precision: &TimestampPrecision) -> u64 {
-    let secs = env.ledger().timestamp();
+    precise_duration(env.ledger().timestamp(), precision)
+}
+
+fn precise_duration(secs: u64, precision: &TimestampPrecision) -> u64 {
     match precision {
-        TimestampPrecision::Msec => secs * 1000,
+        TimestampPrecision::Msec => secs.saturating_mul(1000),
         TimestampPrecision::Sec => secs,
     }
 }
@@ -4,7 +4,9 @@ use soroban_sdk::{Address, Env};
 
 use crate::event;
 use crate::methods::utils::validation::require_not_exceed_assets_limit;
-use crate::storage::{read_user_config, write_user_config};
+use crate::storage::{
+    read_reserve_id_holders, read_user_config, write_reserve_id_holders, write_user_config,
+};
 
 pub struct UserConfigurator<'a> {
     env: &'a Env,
@@ -44,8 +46,15 @@ impl<'a> UserConfigurator<'a> {
 
         let env = self.env;
         let user_config = self.read_user_config()?.user_config.as_mut().unwrap();
+        let held_before = user_config.is_using_as_collateral_or_borrowing(env, reserve_id);
 
         user_config.set_using_as_collateral(env, reserve_id, false);
+        update_reserve_id_holders(
+            env,
+            reserve_id,
+            held_before,
+            user_config.is_using_as_collateral_or_borrowing(env, reserve_id),
+        );
 
         event::reserve_used_as_collateral_disabled(env, self.user, asset);
 
@@ -67,8 +76,15 @@ impl<'a> UserConfigurator<'a> {
         let env = self.env;
         let assets_limit = self.assets_limit;
         let user_config = self.read_user_config()?.user_config.as_mut().unwrap();
+        let held_before = user_config.is_using_as_collateral_or_borrowing(env, reserve_id);
 
         user_config.set_using_as_collateral(env, reserve_id, true);
+        update_reserve_id_holders(
+            env,
+            reserve_id,
+            held_before,
+            user_config.is_using_as_collateral_or_borrowing(env, reserve_id),
+        );
         require_not_exceed_assets_limit(env, user_config.total_assets(), assets_limit);
 
         event::reserve_used_as_collateral_enabled(env, self.user, asset);
@@ -86,8 +102,15 @@ impl<'a> UserConfigurator<'a> {
         let env = self.env;
         let assets_limit = self.assets_limit;
         let user_config = self.read_user_config()?.user_config.as_mut().unwrap();
+        let held_before = user_config.is_using_as_collateral_or_borrowing(env, reserve_id);
 
         user_config.set_borrowing(env, reserve_id, true);
+        update_reserve_id_holders(
+            env,
+            reserve_id,
+            held_before,
+            user_config.is_using_as_collateral_or_borrowing(env, reserve_id),
+        );
         require_not_exceed_assets_limit(env, user_config.total_assets(), assets_limit);
 
         self.should_write = true;
@@ -102,8 +125,15 @@ impl<'a> UserConfigurator<'a> {
 
         let env = self.env;
         let user_config = self.read_user_config()?.user_config.as_mut().unwrap();
+        let held_before = user_config.is_using_as_collateral_or_borrowing(env, reserve_id);
 
         user_config.set_borrowing(env, reserve_id, false);
+        update_reserve_id_holders(
+            env,
+            reserve_id,
+            held_before,
+            user_config.is_using_as_collateral_or_borrowing(env, reserve_id),
+        );
 
         self.should_write = true;
 
@@ -132,7 +162,8 @@ impl<'a> UserConfigurator<'a> {
         }
 
         self.user_config = Some(if self.create_if_none {
-            read_user_config(self.env, self.user).unwrap_or_default()
+            read_user_config(self.env, self.user)
+                .unwrap_or_else(|_| UserConfiguration::new(self.env))
         } else {
             read_user_config(self.env, self.user)?
         });
@@ -140,3 +171,21 @@ impl<'a> UserConfigurator<'a> {
         Ok(self)
     }
 }
+
+/// Keeps count of the users referring to the reserve id, so that the id is reused
+/// by `init_reserve` only when no stale bits are left after delisting
+fn update_reserve_id_holders(env: &Env, reserve_id: u8, held_before: bool, held_after: bool) {
+    if held_before == held_after {
+        return;
+    }
+
+    if let Some(holders) = read_reserve_id_holders(env, reserve_id) {
+        let holders = if held_after {
+            holders + 1
+        } else {
+            holders.saturating_sub(1)
+        };
+
+        write_reserve_id_holders(env, reserve_id, holders);
+    }
+}
@@ -76,7 +76,7 @@ impl STokenTrait for SToken {
     }
 
     fn version() -> u32 {
-        1
+        s_token_interface::VERSION
     }
 
     /// Returns the amount of tokens that the `spender` is allowed to withdraw from the `from` address.
@@ -52,6 +52,7 @@ fn create_token<'a>(
     let initial_health = 2_500;
     let grace_period = 60 * 60 * 24;
 
+    e.budget().reset_default();
     pool.initialize(
         &pool_admin,
         &PoolConfig {
@@ -82,15 +83,26 @@ fn create_token<'a>(
             asset_decimals: 7,
             min_sanity_price_in_base: 5_000_000,
             max_sanity_price_in_base: 100_000_000,
+            lastprice_fallback: false,
+            stored_price_max_age: 0,
+            max_feed_deviation: 0,
+            min_feeds: 1,
+            max_price_change: 0,
+            price_change_window: 0,
+            breaker_timeout: 0,
+            conservative_pricing: false,
             feeds: vec![
                 &e,
                 PriceFeed {
                     feed: price_feed.address.clone(),
                     feed_asset: OracleAsset::Stellar(underlying_asset.address.clone()),
                     feed_decimals: 14,
-                    twap_records: 10,
+                    twap_window: 3600,
+                    min_twap_span: 0,
                     min_timestamp_delta: 100,
                     timestamp_precision: TimestampPrecision::Sec,
+                    path: vec![&e],
+                    weight: None,
                 },
             ],
         },
@@ -10,6 +10,7 @@ deploy() {
     address=$(stellar contract deploy \
         --wasm $1 \
         --source $2 \
+        ${3:+--salt $3} \
         --rpc-url "$SOROBAN_RPC_URL" \
         --network-passphrase "$PASSPHRASE")
     echo $address
@@ -25,6 +26,17 @@ install() {
     echo $hash
 }
 
+invoke() {
+    local result=$(stellar --verbose contract invoke \
+        --source $2 \
+        --id $1 \
+        --rpc-url "$SOROBAN_RPC_URL" \
+        --network-passphrase "$PASSPHRASE" \
+        -- \
+        $3)
+    echo $result
+}
+
 addressFromResult() {
     IFS=',' read -ra values <<<"$(sed 's/\[\|\]//g' <<<"$1")"
     local value1="$(echo "${values[0]}" | tr -d '[:space:]' | sed 's/"//g' | sed 's/\[//g')"
@@ -54,7 +66,8 @@ then
     TOKEN_USDC="CCW67TSZV3SSS2HXMBQ5JFGCKJNXKZM7UQUWUZPUTHXSTZLEO7SJMI75"
     echo "  USDC contract address: $TOKEN_USDC"
 
-    DEPLOYER=$(deploy "$ARTIFACTS/deployer.wasm" $ADMIN_SECRET)
+    DEPLOYER_SALT=$(openssl rand -hex 32)
+    DEPLOYER=$(deploy "$ARTIFACTS/deployer.wasm" $ADMIN_SECRET $DEPLOYER_SALT)
     echo "  Deployer contract address: $DEPLOYER"
 
     S_TOKEN_HASH=$(install "$ARTIFACTS/s_token.wasm" $ADMIN_SECRET)
@@ -99,7 +112,8 @@ else
     TOKEN_RWA=$(deploy "$ARTIFACTS/token.wasm" $ADMIN_SECRET)
     echo "  RWA contract address: $TOKEN_RWA"
 
-    DEPLOYER=$(deploy "$ARTIFACTS/deployer.wasm" $ADMIN_SECRET)
+    DEPLOYER_SALT=$(openssl rand -hex 32)
+    DEPLOYER=$(deploy "$ARTIFACTS/deployer.wasm" $ADMIN_SECRET $DEPLOYER_SALT)
     echo "  Deployer contract address: $DEPLOYER"
 
     S_TOKEN_HASH=$(install "$ARTIFACTS/s_token.wasm" $ADMIN_SECRET)
@@ -116,6 +130,13 @@ else
     echo "  Price Feed contract address: $PRICE_FEED"
 fi
 
+# The deployer registers pools and reserves deployed by its admin only.
+# Only the account that deployed it with the salt is accepted as the admin.
+invoke $DEPLOYER $ADMIN_SECRET "initialize \
+    --admin $ADMIN_PUBLIC \
+    --salt $DEPLOYER_SALT" 1>/dev/null
+echo "  Deployer admin: $ADMIN_PUBLIC"
+
 contracts="$ARTIFACTS/.contracts"
 {
     echo "SLENDER_TOKEN_XLM=$TOKEN_XLM"
@@ -125,6 +146,8 @@ contracts="$ARTIFACTS/.contracts"
     echo "SLENDER_S_TOKEN_HASH=$S_TOKEN_HASH"
     echo "SLENDER_DEBT_TOKEN_HASH=$DEBT_TOKEN_HASH"
     echo "SLENDER_DEPLOYER=$DEPLOYER"
+    echo "SLENDER_DEPLOYER_SALT=$DEPLOYER_SALT"
+    echo "SLENDER_DEPLOYER_INITIALIZED=TRUE"
     echo "SLENDER_PRICE_FEED=$PRICE_FEED"
     echo "SLENDER_POOL_HASH=$POOL_HASH"
 } >$contracts
@@ -0,0 +1,229 @@
+use std::fmt;
+use std::slice::Iter;
+
+use soroban_sdk::xdr::{ScMapEntry, ScVal};
+
+pub type Result<T> = core::result::Result<T, DecodeError>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// Topics are empty or the first topic is not a symbol
+    MissingName,
+    /// Event name is not emitted by the contract
+    UnknownEvent(String),
+    /// Event layout version is not supported by the decoder
+    UnsupportedVersion { event: String, version: u32 },
+    /// Topics or data don't match the layout of the event
+    Malformed {
+        event: String,
+        expected: &'static str,
+    },
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::MissingName => write!(f, "event has no name"),
+            DecodeError::UnknownEvent(name) => write!(f, "unknown event '{name}'"),
+            DecodeError::UnsupportedVersion { event, version } => {
+                write!(f, "unsupported version {version} of event '{event}'")
+            }
+            DecodeError::Malformed { event, expected } => {
+                write!(f, "malformed event '{event}': expected {expected}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// Returns the event name, which is the first topic of every pool and token event
+pub(crate) fn name(topics: &[ScVal]) -> Result<String> {
+    match topics.first() {
+        Some(ScVal::Symbol(symbol)) => Ok(symbol.to_utf8_string_lossy()),
+        _ => Err(DecodeError::MissingName),
+    }
+}
+
+/// Sequential reader of event topics or of a tuple of event data
+pub(crate) struct Values<'a> {
+    event: &'a str,
+    values: Iter<'a, ScVal>,
+}
+
+impl<'a> Values<'a> {
+    pub fn new(event: &'a str, values: &'a [ScVal]) -> Self {
+        Self {
+            event,
+            values: values.iter(),
+        }
+    }
+
+    pub fn tuple(event: &'a str, data: &'a ScVal) -> Result<Self> {
+        Ok(Self::new(event, vec(event, data)?))
+    }
+
+    pub fn next(&mut self, expected: &'static str) -> Result<&'a ScVal> {
+        self.values
+            .next()
+            .ok_or_else(|| malformed(self.event, expected))
+    }
+
+    pub fn address(&mut self) -> Result<String> {
+        address(self.event, self.next("address")?)
+    }
+
+    pub fn i128(&mut self) -> Result<i128> {
+        i128(self.event, self.next("i128")?)
+    }
+
+    pub fn u32(&mut self) -> Result<u32> {
+        u32(self.event, self.next("u32")?)
+    }
+
+    pub fn u64(&mut self) -> Result<u64> {
+        u64(self.event, self.next("u64")?)
+    }
+
+    pub fn bool(&mut self) -> Result<bool> {
+        bool(self.event, self.next("bool")?)
+    }
+
+    pub fn string(&mut self) -> Result<String> {
+        string(self.event, self.next("string")?)
+    }
+
+    pub fn bytes(&mut self) -> Result<Vec<u8>> {
+        bytes(self.event, self.next("bytes")?)
+    }
+
+    /// Checks the layout version going right after the name of a configuration event
+    pub fn version(&mut self, supported: u32) -> Result<()> {
+        let version = self.u32()?;
+
+        if version != supported {
+            return Err(DecodeError::UnsupportedVersion {
+                event: self.event.to_string(),
+                version,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Fails if there are values left after the last expected one
+    pub fn end(mut self) -> Result<()> {
+        match self.values.next() {
+            None => Ok(()),
+            Some(_) => Err(malformed(self.event, "no more values")),
+        }
+    }
+}
+
+pub(crate) fn malformed(event: &str, expected: &'static str) -> DecodeError {
+    DecodeError::Malformed {
+        event: event.to_string(),
+        expected,
+    }
+}
+
+pub(crate) fn address(event: &str, val: &ScVal) -> Result<String> {
+    match val {
+        ScVal::Address(address) => Ok(address.to_string()),
+        _ => Err(malformed(event, "address")),
+    }
+}
+
+pub(crate) fn i128(event: &str, val: &ScVal) -> Result<i128> {
+    match val {
+        ScVal::I128(parts) => Ok(parts.into()),
+        _ => Err(malformed(event, "i128")),
+    }
+}
+
+pub(crate) fn u32(event: &str, val: &ScVal) -> Result<u32> {
+    match val {
+        ScVal::U32(value) => Ok(*value),
+        _ => Err(malformed(event, "u32")),
+    }
+}
+
+pub(crate) fn u64(event: &str, val: &ScVal) -> Result<u64> {
+    match val {
+        ScVal::U64(value) => Ok(*value),
+        _ => Err(malformed(event, "u64")),
+    }
+}
+
+pub(crate) fn bool(event: &str, val: &ScVal) -> Result<bool> {
+    match val {
+        ScVal::Bool(value) => Ok(*value),
+        _ => Err(malformed(event, "bool")),
+    }
+}
+
+pub(crate) fn string(event: &str, val: &ScVal) -> Result<String> {
+    match val {
+        ScVal::String(value) => Ok(value.to_utf8_string_lossy()),
+        _ => Err(malformed(event, "string")),
+    }
+}
+
+pub(crate) fn bytes(event: &str, val: &ScVal) -> Result<Vec<u8>> {
+    match val {
+        ScVal::Bytes(value) => Ok(value.to_vec()),
+        _ => Err(malformed(event, "bytes")),
+    }
+}
+
+pub(crate) fn unit(event: &str, val: &ScVal) -> Result<()> {
+    match val {
+        ScVal::Void => Ok(()),
+        _ => Err(malformed(event, "unit")),
+    }
+}
+
+pub(crate) fn vec<'a>(event: &str, val: &'a ScVal) -> Result<&'a [ScVal]> {
+    match val {
+        ScVal::Vec(Some(items)) => Ok(items.as_slice()),
+        ScVal::Vec(None) => Ok(&[]),
+        _ => Err(malformed(event, "vec")),
+    }
+}
+
+/// `None` of a contract `Option` is encoded as void
+pub(crate) fn option<'a, T>(
+    event: &str,
+    val: &'a ScVal,
+    decode: impl FnOnce(&str, &'a ScVal) -> Result<T>,
+) -> Result<Option<T>> {
+    match val {
+        ScVal::Void => Ok(None),
+        val => decode(event, val).map(Some),
+    }
+}
+
+/// Enum variant of a contract type is encoded as a vec of the variant symbol and its values
+pub(crate) fn variant<'a>(event: &'a str, val: &'a ScVal) -> Result<(String, Values<'a>)> {
+    let items = vec(event, val)?;
+    let name = match items.first() {
+        Some(ScVal::Symbol(symbol)) => symbol.to_utf8_string_lossy(),
+        _ => return Err(malformed(event, "enum variant")),
+    };
+
+    Ok((name, Values::new(event, &items[1..])))
+}
+
+/// Field of a contract struct, which is encoded as a map keyed by field names
+pub(crate) fn field<'a>(event: &str, val: &'a ScVal, name: &'static str) -> Result<&'a ScVal> {
+    let entries: &[ScMapEntry] = match val {
+        ScVal::Map(Some(map)) => map.as_slice(),
+        _ => return Err(malformed(event, "struct")),
+    };
+
+    entries
+        .iter()
+        .find(|entry| matches!(&entry.key, ScVal::Symbol(key) if key.as_slice() == name.as_bytes()))
+        .map(|entry| &entry.val)
+        .ok_or_else(|| malformed(event, name))
+}
@@ -0,0 +1,833 @@
+use pool_interface::types::{pause_action::PauseAction, price_source::PriceSource, role::Role};
+use soroban_sdk::xdr::ScVal;
+
+use crate::decode::{self, malformed, DecodeError, Result, Values};
+
+/// Layout version of the pool configuration events supported by the decoder
+pub const CONFIG_EVENT_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoolConfig {
+    pub base_asset_address: String,
+    pub base_asset_decimals: u32,
+    pub initial_health: u32,
+    pub grace_period: u64,
+    pub timestamp_window: u64,
+    pub flash_loan_fee: u32,
+    pub user_assets_limit: u32,
+    pub min_collat_amount: i128,
+    pub min_debt_amount: i128,
+    pub liquidation_protocol_fee: u32,
+    pub ir_alpha: u32,
+    pub ir_initial_rate: u32,
+    pub ir_max_rate: u32,
+    pub ir_scaling_coeff: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReserveType {
+    Fungible { s_token: String, debt_token: String },
+    Rwa,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollateralParams {
+    pub liq_cap: i128,
+    pub pen_order: u32,
+    pub util_cap: u32,
+    pub discount: u32,
+}
+
+/// Event emitted by the pool contract
+#[derive(Debug, Clone, PartialEq)]
+pub enum PoolEvent {
+    Initialized {
+        admin: String,
+        config: PoolConfig,
+    },
+    RoleGranted {
+        role: Role,
+        who: String,
+    },
+    RoleRevoked {
+        role: Role,
+        who: String,
+    },
+    AdminProposed {
+        admin: String,
+        pending_admin: String,
+    },
+    AdminProposalCancelled {
+        admin: String,
+        pending_admin: String,
+    },
+    AdminChanged {
+        prev_admin: String,
+        admin: String,
+    },
+    TimelockDelayChanged {
+        delay: u64,
+    },
+    OperationScheduled {
+        id: u32,
+        eta: u64,
+    },
+    OperationExecuted {
+        id: u32,
+    },
+    OperationCancelled {
+        id: u32,
+    },
+    CollateralEnabled {
+        who: String,
+        asset: String,
+    },
+    CollateralDisabled {
+        who: String,
+        asset: String,
+    },
+    Deposit {
+        who: String,
+        asset: String,
+        amount: i128,
+    },
+    Withdraw {
+        who: String,
+        to: String,
+        asset: String,
+        amount: i128,
+    },
+    Borrow {
+        who: String,
+        asset: String,
+        amount: i128,
+    },
+    Repay {
+        who: String,
+        asset: String,
+        amount: i128,
+    },
+    DebtWrittenOff {
+        who: String,
+        asset: String,
+        amount: i128,
+    },
+    Liquidation {
+        who: String,
+        covered_debt: i128,
+        liquidated_collateral: i128,
+    },
+    FlashLoan {
+        who: String,
+        receiver: String,
+        asset: String,
+        amount: i128,
+        premium: i128,
+        borrow: bool,
+    },
+    CollatConfigChanged {
+        asset: String,
+        prev_params: CollateralParams,
+        params: CollateralParams,
+    },
+    BorrowingEnabled {
+        asset: String,
+    },
+    BorrowingDisabled {
+        asset: String,
+    },
+    ReserveStatusChanged {
+        asset: String,
+        prev_active: bool,
+        active: bool,
+    },
+    ReserveUpdated {
+        asset: String,
+        lender_ar: i128,
+        borrower_ar: i128,
+        lender_ir: i128,
+        borrower_ir: i128,
+        utilization: i128,
+        last_update_timestamp: u64,
+    },
+    ReserveInitialized {
+        asset: String,
+        reserve_type: ReserveType,
+        id: u32,
+    },
+    ReserveFreezeChanged {
+        asset: String,
+        frozen: bool,
+    },
+    ReserveDelisted {
+        asset: String,
+    },
+    /// Price feed configurations are kept encoded as their layout follows the oracle settings
+    PriceFeedsChanged {
+        asset: String,
+        prev_config: Option<ScVal>,
+        config: ScVal,
+    },
+    /// Migrated feed did not report its resolution and got the default TWAP window
+    TwapWindowDefaulted {
+        asset: String,
+        feed: String,
+        twap_window: u64,
+    },
+    PriceFallback {
+        asset: String,
+        source: PriceSource,
+        price: i128,
+    },
+    /// Price moved too far from the reference price, which is used until the breaker resets
+    PriceBreakerTripped {
+        asset: String,
+        reference_price: i128,
+        price: i128,
+    },
+    PriceBreakerReset {
+        asset: String,
+    },
+    PauseChanged {
+        prev_paused: bool,
+        paused: bool,
+    },
+    PoolConfigurationChanged {
+        prev_config: Option<PoolConfig>,
+        config: PoolConfig,
+    },
+    ActionPauseChanged {
+        action: PauseAction,
+        paused: bool,
+    },
+    ReservePauseChanged {
+        asset: String,
+        action: PauseAction,
+        paused: bool,
+    },
+    AccrualSuspensionChanged {
+        enabled: bool,
+    },
+    ProtocolFeeClaimed {
+        asset: String,
+        recipient: String,
+        amount: i128,
+    },
+    Upgraded {
+        prev_version: u32,
+        new_wasm_hash: Vec<u8>,
+    },
+    TokenUpgraded {
+        asset: String,
+        token: String,
+        prev_version: u32,
+        version: u32,
+    },
+    StorageMigrated {
+        from_version: u32,
+        to_version: u32,
+    },
+    UserConfigsMigrated {
+        migrated: u32,
+    },
+    WindDownStarted {
+        rate_step: u32,
+        step_secs: u64,
+    },
+    SettlementStarted,
+}
+
+impl PoolEvent {
+    pub fn decode(topics: &[ScVal], data: &ScVal) -> Result<Self> {
+        let name = decode::name(topics)?;
+        let event = name.as_str();
+        let mut topics = Values::new(event, &topics[1..]);
+
+        let decoded = match event {
+            "initialize" => {
+                let admin = topics.address()?;
+                let base_asset_address = topics.address()?;
+                let mut data = Values::tuple(event, data)?;
+                let ir_alpha = data.u32()?;
+                let ir_initial_rate = data.u32()?;
+                let ir_max_rate = data.u32()?;
+                let ir_scaling_coeff = data.u32()?;
+
+                let config = PoolConfig {
+                    base_asset_address,
+                    base_asset_decimals: data.u32()?,
+                    initial_health: data.u32()?,
+                    grace_period: data.u64()?,
+                    timestamp_window: data.u64()?,
+                    flash_loan_fee: data.u32()?,
+                    user_assets_limit: data.u32()?,
+                    min_collat_amount: data.i128()?,
+                    min_debt_amount: data.i128()?,
+                    liquidation_protocol_fee: data.u32()?,
+                    ir_alpha,
+                    ir_initial_rate,
+                    ir_max_rate,
+                    ir_scaling_coeff,
+                };
+                data.end()?;
+
+                PoolEvent::Initialized { admin, config }
+            }
+            "role_granted" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                PoolEvent::RoleGranted {
+                    role: role(event, topics.next("role")?)?,
+                    who: decode::address(event, data)?,
+                }
+            }
+            "role_revoked" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                PoolEvent::RoleRevoked {
+                    role: role(event, topics.next("role")?)?,
+                    who: decode::address(event, data)?,
+                }
+            }
+            "admin_proposed" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                PoolEvent::AdminProposed {
+                    admin: topics.address()?,
+                    pending_admin: decode::address(event, data)?,
+                }
+            }
+            "admin_proposal_cancelled" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                PoolEvent::AdminProposalCancelled {
+                    admin: topics.address()?,
+                    pending_admin: decode::address(event, data)?,
+                }
+            }
+            "admin_changed" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                PoolEvent::AdminChanged {
+                    prev_admin: topics.address()?,
+                    admin: decode::address(event, data)?,
+                }
+            }
+            "timelock_delay_changed" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                PoolEvent::TimelockDelayChanged {
+                    delay: decode::u64(event, data)?,
+                }
+            }
+            "operation_scheduled" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                PoolEvent::OperationScheduled {
+                    id: topics.u32()?,
+                    eta: decode::u64(event, data)?,
+                }
+            }
+            "operation_executed" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                decode::unit(event, data)?;
+                PoolEvent::OperationExecuted { id: topics.u32()? }
+            }
+            "operation_cancelled" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                decode::unit(event, data)?;
+                PoolEvent::OperationCancelled { id: topics.u32()? }
+            }
+            "reserve_used_as_coll_enabled" => PoolEvent::CollateralEnabled {
+                who: topics.address()?,
+                asset: decode::address(event, data)?,
+            },
+            "reserve_used_as_coll_disabled" => PoolEvent::CollateralDisabled {
+                who: topics.address()?,
+                asset: decode::address(event, data)?,
+            },
+            "deposit" | "borrow" | "repay" | "debt_written_off" => {
+                let who = topics.address()?;
+                let mut data = Values::tuple(event, data)?;
+                let asset = data.address()?;
+                let amount = data.i128()?;
+                data.end()?;
+
+                match event {
+                    "deposit" => PoolEvent::Deposit { who, asset, amount },
+                    "borrow" => PoolEvent::Borrow { who, asset, amount },
+                    "debt_written_off" => PoolEvent::DebtWrittenOff { who, asset, amount },
+                    _ => PoolEvent::Repay { who, asset, amount },
+                }
+            }
+            "withdraw" => {
+                let mut data = Values::tuple(event, data)?;
+                let decoded = PoolEvent::Withdraw {
+                    who: topics.address()?,
+                    to: data.address()?,
+                    asset: data.address()?,
+                    amount: data.i128()?,
+                };
+                data.end()?;
+
+                decoded
+            }
+            "liquidation" => {
+                let mut data = Values::tuple(event, data)?;
+                let decoded = PoolEvent::Liquidation {
+                    who: topics.address()?,
+                    covered_debt: data.i128()?,
+                    liquidated_collateral: data.i128()?,
+                };
+                data.end()?;
+
+                decoded
+            }
+            "flash_loan" => {
+                let mut data = Values::tuple(event, data)?;
+                let decoded = PoolEvent::FlashLoan {
+                    who: topics.address()?,
+                    receiver: topics.address()?,
+                    asset: topics.address()?,
+                    amount: data.i128()?,
+                    premium: data.i128()?,
+                    borrow: data.bool()?,
+                };
+                data.end()?;
+
+                decoded
+            }
+            "collat_config_change" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                let mut data = Values::tuple(event, data)?;
+                let decoded = PoolEvent::CollatConfigChanged {
+                    asset: topics.address()?,
+                    prev_params: CollateralParams {
+                        liq_cap: data.i128()?,
+                        pen_order: data.u32()?,
+                        util_cap: data.u32()?,
+                        discount: data.u32()?,
+                    },
+                    params: CollateralParams {
+                        liq_cap: data.i128()?,
+                        pen_order: data.u32()?,
+                        util_cap: data.u32()?,
+                        discount: data.u32()?,
+                    },
+                };
+                data.end()?;
+
+                decoded
+            }
+            "borrowing_enabled" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                decode::unit(event, data)?;
+                PoolEvent::BorrowingEnabled {
+                    asset: topics.address()?,
+                }
+            }
+            "borrowing_disabled" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                decode::unit(event, data)?;
+                PoolEvent::BorrowingDisabled {
+                    asset: topics.address()?,
+                }
+            }
+            "reserve_status_changed" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                let mut data = Values::tuple(event, data)?;
+                let decoded = PoolEvent::ReserveStatusChanged {
+                    asset: topics.address()?,
+                    prev_active: data.bool()?,
+                    active: data.bool()?,
+                };
+                data.end()?;
+
+                decoded
+            }
+            "reserve_updated" => {
+                let mut data = Values::tuple(event, data)?;
+                let decoded = PoolEvent::ReserveUpdated {
+                    asset: topics.address()?,
+                    lender_ar: data.i128()?,
+                    borrower_ar: data.i128()?,
+                    lender_ir: data.i128()?,
+                    borrower_ir: data.i128()?,
+                    utilization: data.i128()?,
+                    last_update_timestamp: data.u64()?,
+                };
+                data.end()?;
+
+                decoded
+            }
+            "reserve_initialized" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                let mut data = Values::tuple(event, data)?;
+                let decoded = PoolEvent::ReserveInitialized {
+                    asset: topics.address()?,
+                    reserve_type: reserve_type(event, data.next("reserve type")?)?,
+                    id: data.u32()?,
+                };
+                data.end()?;
+
+                decoded
+            }
+            "reserve_freeze_changed" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                PoolEvent::ReserveFreezeChanged {
+                    asset: topics.address()?,
+                    frozen: decode::bool(event, data)?,
+                }
+            }
+            "reserve_delisted" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                decode::unit(event, data)?;
+                PoolEvent::ReserveDelisted {
+                    asset: topics.address()?,
+                }
+            }
+            "price_feeds_changed" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                let mut data = Values::tuple(event, data)?;
+                let prev_config =
+                    decode::option(event, data.next("price feed config")?, |_, val| {
+                        Ok(val.clone())
+                    })?;
+                let decoded = PoolEvent::PriceFeedsChanged {
+                    asset: topics.address()?,
+                    prev_config,
+                    config: data.next("price feed config")?.clone(),
+                };
+                data.end()?;
+
+                decoded
+            }
+            "twap_window_defaulted" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                let mut data = Values::tuple(event, data)?;
+                let decoded = PoolEvent::TwapWindowDefaulted {
+                    asset: topics.address()?,
+                    feed: data.address()?,
+                    twap_window: data.u64()?,
+                };
+                data.end()?;
+
+                decoded
+            }
+            "price_fallback" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                let mut data = Values::tuple(event, data)?;
+                let decoded = PoolEvent::PriceFallback {
+                    asset: topics.address()?,
+                    source: price_source(event, data.next("price source")?)?,
+                    price: data.i128()?,
+                };
+                data.end()?;
+
+                decoded
+            }
+            "price_breaker_tripped" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                let mut data = Values::tuple(event, data)?;
+                let decoded = PoolEvent::PriceBreakerTripped {
+                    asset: topics.address()?,
+                    reference_price: data.i128()?,
+                    price: data.i128()?,
+                };
+                data.end()?;
+
+                decoded
+            }
+            "price_breaker_reset" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                decode::unit(event, data)?;
+                PoolEvent::PriceBreakerReset {
+                    asset: topics.address()?,
+                }
+            }
+            "pause_changed" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                let mut data = Values::tuple(event, data)?;
+                let decoded = PoolEvent::PauseChanged {
+                    prev_paused: data.bool()?,
+                    paused: data.bool()?,
+                };
+                data.end()?;
+
+                decoded
+            }
+            "pool_configuration_changed" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                let mut data = Values::tuple(event, data)?;
+                let decoded = PoolEvent::PoolConfigurationChanged {
+                    prev_config: decode::option(event, data.next("pool config")?, pool_config)?,
+                    config: pool_config(event, data.next("pool config")?)?,
+                };
+                data.end()?;
+
+                decoded
+            }
+            "action_pause_changed" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                PoolEvent::ActionPauseChanged {
+                    action: pause_action(event, topics.next("pause action")?)?,
+                    paused: decode::bool(event, data)?,
+                }
+            }
+            "reserve_pause_changed" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                PoolEvent::ReservePauseChanged {
+                    asset: topics.address()?,
+                    action: pause_action(event, topics.next("pause action")?)?,
+                    paused: decode::bool(event, data)?,
+                }
+            }
+            "accrual_suspension_changed" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                PoolEvent::AccrualSuspensionChanged {
+                    enabled: decode::bool(event, data)?,
+                }
+            }
+            "protocol_fee_claimed" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                let mut data = Values::tuple(event, data)?;
+                let decoded = PoolEvent::ProtocolFeeClaimed {
+                    asset: topics.address()?,
+                    recipient: data.address()?,
+                    amount: data.i128()?,
+                };
+                data.end()?;
+
+                decoded
+            }
+            "upgraded" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                let mut data = Values::tuple(event, data)?;
+                let decoded = PoolEvent::Upgraded {
+                    prev_version: data.u32()?,
+                    new_wasm_hash: data.bytes()?,
+                };
+                data.end()?;
+
+                decoded
+            }
+            "token_upgraded" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                let mut data = Values::tuple(event, data)?;
+                let decoded = PoolEvent::TokenUpgraded {
+                    asset: topics.address()?,
+                    token: topics.address()?,
+                    prev_version: data.u32()?,
+                    version: data.u32()?,
+                };
+                data.end()?;
+
+                decoded
+            }
+            "storage_migrated" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                let mut data = Values::tuple(event, data)?;
+                let decoded = PoolEvent::StorageMigrated {
+                    from_version: data.u32()?,
+                    to_version: data.u32()?,
+                };
+                data.end()?;
+
+                decoded
+            }
+            "user_configs_migrated" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                PoolEvent::UserConfigsMigrated {
+                    migrated: decode::u32(event, data)?,
+                }
+            }
+            "wind_down_started" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                let mut data = Values::tuple(event, data)?;
+                let decoded = PoolEvent::WindDownStarted {
+                    rate_step: data.u32()?,
+                    step_secs: data.u64()?,
+                };
+                data.end()?;
+
+                decoded
+            }
+            "settlement_started" => {
+                topics.version(CONFIG_EVENT_VERSION)?;
+                decode::unit(event, data)?;
+                PoolEvent::SettlementStarted
+            }
+            _ => return Err(DecodeError::UnknownEvent(name)),
+        };
+        topics.end()?;
+
+        Ok(decoded)
+    }
+}
+
+/// Event emitted by the sToken or the debtToken contract
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenEvent {
+    Initialized {
+        underlying_asset: String,
+        pool: String,
+        decimals: u32,
+        name: String,
+        symbol: String,
+    },
+    Approve {
+        from: String,
+        spender: String,
+        amount: i128,
+        expiration_ledger: u32,
+    },
+    Transfer {
+        from: String,
+        to: String,
+        amount: i128,
+    },
+    Mint {
+        admin: String,
+        to: String,
+        amount: i128,
+    },
+    Burn {
+        from: String,
+        amount: i128,
+    },
+    Clawback {
+        from: String,
+        amount: i128,
+    },
+    SetAuthorized {
+        id: String,
+        authorize: bool,
+    },
+}
+
+impl TokenEvent {
+    pub fn decode(topics: &[ScVal], data: &ScVal) -> Result<Self> {
+        let name = decode::name(topics)?;
+        let event = name.as_str();
+        let mut topics = Values::new(event, &topics[1..]);
+
+        let decoded = match event {
+            "init" => {
+                let mut data = Values::tuple(event, data)?;
+                let decoded = TokenEvent::Initialized {
+                    underlying_asset: topics.address()?,
+                    pool: topics.address()?,
+                    decimals: data.u32()?,
+                    name: data.string()?,
+                    symbol: data.string()?,
+                };
+                data.end()?;
+
+                decoded
+            }
+            "approve" => {
+                let mut data = Values::tuple(event, data)?;
+                let decoded = TokenEvent::Approve {
+                    from: topics.address()?,
+                    spender: topics.address()?,
+                    amount: data.i128()?,
+                    expiration_ledger: data.u32()?,
+                };
+                data.end()?;
+
+                decoded
+            }
+            "transfer" => TokenEvent::Transfer {
+                from: topics.address()?,
+                to: topics.address()?,
+                amount: decode::i128(event, data)?,
+            },
+            "mint" => TokenEvent::Mint {
+                admin: topics.address()?,
+                to: topics.address()?,
+                amount: decode::i128(event, data)?,
+            },
+            "burn" => TokenEvent::Burn {
+                from: topics.address()?,
+                amount: decode::i128(event, data)?,
+            },
+            "clawback" => TokenEvent::Clawback {
+                from: topics.address()?,
+                amount: decode::i128(event, data)?,
+            },
+            "set_authorized" => TokenEvent::SetAuthorized {
+                id: topics.address()?,
+                authorize: decode::bool(event, data)?,
+            },
+            _ => return Err(DecodeError::UnknownEvent(name)),
+        };
+        topics.end()?;
+
+        Ok(decoded)
+    }
+}
+
+fn role(event: &str, val: &ScVal) -> Result<Role> {
+    let (name, values) = decode::variant(event, val)?;
+    values.end()?;
+
+    match name.as_str() {
+        "PoolAdmin" => Ok(Role::PoolAdmin),
+        "RiskAdmin" => Ok(Role::RiskAdmin),
+        "OracleAdmin" => Ok(Role::OracleAdmin),
+        "EmergencyGuardian" => Ok(Role::EmergencyGuardian),
+        "Treasury" => Ok(Role::Treasury),
+        _ => Err(malformed(event, "role")),
+    }
+}
+
+fn pause_action(event: &str, val: &ScVal) -> Result<PauseAction> {
+    let action = decode::u32(event, val)?;
+
+    PauseAction::ALL
+        .into_iter()
+        .find(|a| *a as u32 == action)
+        .ok_or_else(|| malformed(event, "pause action"))
+}
+
+fn price_source(event: &str, val: &ScVal) -> Result<PriceSource> {
+    let (name, values) = decode::variant(event, val)?;
+    values.end()?;
+
+    match name.as_str() {
+        "Twap" => Ok(PriceSource::Twap),
+        "LastPrice" => Ok(PriceSource::LastPrice),
+        "StoredPrice" => Ok(PriceSource::StoredPrice),
+        _ => Err(malformed(event, "price source")),
+    }
+}
+
+fn reserve_type(event: &str, val: &ScVal) -> Result<ReserveType> {
+    let (name, mut values) = decode::variant(event, val)?;
+
+    let reserve_type = match name.as_str() {
+        "Fungible" => ReserveType::Fungible {
+            s_token: values.address()?,
+            debt_token: values.address()?,
+        },
+        "RWA" => ReserveType::Rwa,
+        _ => return Err(malformed(event, "reserve type")),
+    };
+    values.end()?;
+
+    Ok(reserve_type)
+}
+
+fn pool_config(event: &str, val: &ScVal) -> Result<PoolConfig> {
+    let field = |name| decode::field(event, val, name);
+
+    Ok(PoolConfig {
+        base_asset_address: decode::address(event, field("base_asset_address")?)?,
+        base_asset_decimals: decode::u32(event, field("base_asset_decimals")?)?,
+        initial_health: decode::u32(event, field("initial_health")?)?,
+        grace_period: decode::u64(event, field("grace_period")?)?,
+        timestamp_window: decode::u64(event, field("timestamp_window")?)?,
+        flash_loan_fee: decode::u32(event, field("flash_loan_fee")?)?,
+        user_assets_limit: decode::u32(event, field("user_assets_limit")?)?,
+        min_collat_amount: decode::i128(event, field("min_collat_amount")?)?,
+        min_debt_amount: decode::i128(event, field("min_debt_amount")?)?,
+        liquidation_protocol_fee: decode::u32(event, field("liquidation_protocol_fee")?)?,
+        ir_alpha: decode::u32(event, field("ir_alpha")?)?,
+        ir_initial_rate: decode::u32(event, field("ir_initial_rate")?)?,
+        ir_max_rate: decode::u32(event, field("ir_max_rate")?)?,
+        ir_scaling_coeff: decode::u32(event, field("ir_scaling_coeff")?)?,
+    })
+}
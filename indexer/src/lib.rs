@@ -0,0 +1,10 @@
+#![cfg(not(target_family = "wasm"))]
+
+mod decode;
+mod event;
+mod model;
+mod tests;
+
+pub use decode::DecodeError;
+pub use event::*;
+pub use model::*;
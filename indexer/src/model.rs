@@ -0,0 +1,319 @@
+use std::collections::BTreeMap;
+
+use common::FixedI128;
+use pool_interface::types::pause_action::PauseAction;
+use soroban_sdk::xdr::{self, ContractEventBody, ScAddress, ScVal};
+
+use crate::decode::{DecodeError, Result};
+use crate::event::{CollateralParams, PoolConfig, PoolEvent, ReserveType, TokenEvent};
+
+/// Contract event as it comes from the ledger or RPC
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContractEvent {
+    pub contract: String,
+    pub topics: Vec<ScVal>,
+    pub data: ScVal,
+}
+
+impl ContractEvent {
+    /// Returns `None` for events not emitted by a contract
+    pub fn from_xdr(event: &xdr::ContractEvent) -> Option<Self> {
+        let contract = ScAddress::Contract(event.contract_id.clone()?).to_string();
+        let ContractEventBody::V0(body) = &event.body;
+
+        Some(Self {
+            contract,
+            topics: body.topics.to_vec(),
+            data: body.data.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    SToken,
+    DebtToken,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reserve {
+    pub id: u32,
+    pub reserve_type: ReserveType,
+    pub is_active: bool,
+    pub is_frozen: bool,
+    pub is_delisted: bool,
+    pub borrowing_enabled: bool,
+    pub collat_params: Option<CollateralParams>,
+    /// Mask of the actions paused for the reserve, see `PauseAction::mask`
+    pub paused_actions: u32,
+    pub lender_ar: i128,
+    pub borrower_ar: i128,
+    pub lender_ir: i128,
+    pub borrower_ir: i128,
+    pub utilization: i128,
+    /// Zero until the first accrual of the reserve
+    pub last_update_timestamp: u64,
+    pub s_token_supply: i128,
+    pub debt_token_supply: i128,
+}
+
+impl Reserve {
+    fn new(id: u32, reserve_type: ReserveType) -> Self {
+        Self {
+            id,
+            reserve_type,
+            is_active: true,
+            is_frozen: false,
+            is_delisted: false,
+            borrowing_enabled: false,
+            collat_params: None,
+            paused_actions: 0,
+            lender_ar: FixedI128::ONE.into_inner(),
+            borrower_ar: FixedI128::ONE.into_inner(),
+            lender_ir: 0,
+            borrower_ir: 0,
+            utilization: 0,
+            last_update_timestamp: 0,
+            s_token_supply: 0,
+            debt_token_supply: 0,
+        }
+    }
+}
+
+/// Position of a user in a fungible reserve
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Position {
+    pub s_token_balance: i128,
+    pub debt_token_balance: i128,
+    pub collateral_enabled: bool,
+}
+
+/// Event the decoder failed on during a replay
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedEvent {
+    /// Position of the event in the replayed events
+    pub index: usize,
+    pub error: DecodeError,
+}
+
+/// In-memory model of the pool built by folding its events and the events of its tokens.
+///
+/// Underlying collateral of a position can't be derived as the sToken underlying balance
+/// is not a part of the events, and RWA positions are not tracked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoolState {
+    pub pool: String,
+    pub admin: Option<String>,
+    pub config: Option<PoolConfig>,
+    pub paused: bool,
+    /// Mask of the actions paused for the whole pool, see `PauseAction::mask`
+    pub paused_actions: u32,
+    pub reserves: BTreeMap<String, Reserve>,
+    /// Token address to the reserve asset and the kind of the token
+    pub tokens: BTreeMap<String, (String, TokenKind)>,
+    /// (user, asset) to the position of the user in the reserve
+    pub positions: BTreeMap<(String, String), Position>,
+}
+
+impl PoolState {
+    pub fn new(pool: impl Into<String>) -> Self {
+        Self {
+            pool: pool.into(),
+            admin: None,
+            config: None,
+            paused: false,
+            paused_actions: 0,
+            reserves: BTreeMap::new(),
+            tokens: BTreeMap::new(),
+            positions: BTreeMap::new(),
+        }
+    }
+
+    /// Folds the events in the order they were emitted into a new model of the pool.
+    /// Events the decoder fails on are left out of the state and returned next to it.
+    pub fn replay<'a>(
+        pool: impl Into<String>,
+        events: impl IntoIterator<Item = &'a ContractEvent>,
+    ) -> (Self, Vec<SkippedEvent>) {
+        let mut state = Self::new(pool);
+        let mut skipped = Vec::new();
+
+        for (index, event) in events.into_iter().enumerate() {
+            if let Err(error) = state.apply(event) {
+                skipped.push(SkippedEvent { index, error });
+            }
+        }
+
+        (state, skipped)
+    }
+
+    /// Applies an event of the pool or of one of its tokens, events of other contracts are ignored
+    pub fn apply(&mut self, event: &ContractEvent) -> Result<()> {
+        if event.contract == self.pool {
+            let decoded = PoolEvent::decode(&event.topics, &event.data)?;
+            self.apply_pool_event(decoded);
+        } else if let Some((asset, kind)) = self.tokens.get(&event.contract).cloned() {
+            let decoded = TokenEvent::decode(&event.topics, &event.data)?;
+            self.apply_token_event(&event.contract, &asset, kind, decoded);
+        }
+
+        Ok(())
+    }
+
+    pub fn position(&self, who: &str, asset: &str) -> Option<&Position> {
+        self.positions.get(&(who.to_string(), asset.to_string()))
+    }
+
+    /// Debt of the user in the underlying asset as of the last accrual of the reserve
+    pub fn debt(&self, who: &str, asset: &str) -> Option<i128> {
+        let reserve = self.reserves.get(asset)?;
+        let position = self.position(who, asset)?;
+
+        FixedI128::from_inner(reserve.borrower_ar).mul_int(position.debt_token_balance)
+    }
+
+    pub fn is_paused(&self, asset: &str, action: PauseAction) -> bool {
+        let reserve_paused = self
+            .reserves
+            .get(asset)
+            .map(|r| r.paused_actions & action.mask() != 0)
+            .unwrap_or(false);
+
+        self.paused || self.paused_actions & action.mask() != 0 || reserve_paused
+    }
+
+    fn apply_pool_event(&mut self, event: PoolEvent) {
+        match event {
+            PoolEvent::Initialized { admin, config } => {
+                self.admin = Some(admin);
+                self.config = Some(config);
+            }
+            PoolEvent::AdminChanged { admin, .. } => self.admin = Some(admin),
+            PoolEvent::CollateralEnabled { who, asset } => {
+                self.position_mut(who, asset).collateral_enabled = true
+            }
+            PoolEvent::CollateralDisabled { who, asset } => {
+                self.position_mut(who, asset).collateral_enabled = false
+            }
+            PoolEvent::ReserveInitialized {
+                asset,
+                reserve_type,
+                id,
+            } => {
+                if let ReserveType::Fungible {
+                    s_token,
+                    debt_token,
+                } = &reserve_type
+                {
+                    self.tokens
+                        .insert(s_token.clone(), (asset.clone(), TokenKind::SToken));
+                    self.tokens
+                        .insert(debt_token.clone(), (asset.clone(), TokenKind::DebtToken));
+                }
+
+                self.reserves.insert(asset, Reserve::new(id, reserve_type));
+            }
+            PoolEvent::CollatConfigChanged { asset, params, .. } => {
+                self.update_reserve(&asset, |r| r.collat_params = Some(params))
+            }
+            PoolEvent::BorrowingEnabled { asset } => {
+                self.update_reserve(&asset, |r| r.borrowing_enabled = true)
+            }
+            PoolEvent::BorrowingDisabled { asset } => {
+                self.update_reserve(&asset, |r| r.borrowing_enabled = false)
+            }
+            PoolEvent::ReserveStatusChanged { asset, active, .. } => {
+                self.update_reserve(&asset, |r| r.is_active = active)
+            }
+            PoolEvent::ReserveFreezeChanged { asset, frozen } => {
+                self.update_reserve(&asset, |r| r.is_frozen = frozen)
+            }
+            PoolEvent::ReserveDelisted { asset } => {
+                self.update_reserve(&asset, |r| r.is_delisted = true)
+            }
+            PoolEvent::ReserveUpdated {
+                asset,
+                lender_ar,
+                borrower_ar,
+                lender_ir,
+                borrower_ir,
+                utilization,
+                last_update_timestamp,
+            } => self.update_reserve(&asset, |r| {
+                r.lender_ar = lender_ar;
+                r.borrower_ar = borrower_ar;
+                r.lender_ir = lender_ir;
+                r.borrower_ir = borrower_ir;
+                r.utilization = utilization;
+                r.last_update_timestamp = last_update_timestamp;
+            }),
+            PoolEvent::ReservePauseChanged {
+                asset,
+                action,
+                paused,
+            } => self.update_reserve(&asset, |r| {
+                r.paused_actions = set_mask(r.paused_actions, action, paused)
+            }),
+            PoolEvent::ActionPauseChanged { action, paused } => {
+                self.paused_actions = set_mask(self.paused_actions, action, paused)
+            }
+            PoolEvent::PauseChanged { paused, .. } => self.paused = paused,
+            PoolEvent::PoolConfigurationChanged { config, .. } => self.config = Some(config),
+            _ => {}
+        }
+    }
+
+    fn apply_token_event(&mut self, token: &str, asset: &str, kind: TokenKind, event: TokenEvent) {
+        match event {
+            TokenEvent::Mint { to, amount, .. } => {
+                self.change_balance(&to, asset, kind, amount);
+                self.change_supply(asset, kind, amount);
+            }
+            TokenEvent::Burn { from, amount } | TokenEvent::Clawback { from, amount } => {
+                self.change_balance(&from, asset, kind, -amount);
+                self.change_supply(asset, kind, -amount);
+            }
+            // sToken reports payouts of the underlying asset as transfers from itself
+            TokenEvent::Transfer { from, .. } if from == token => {}
+            TokenEvent::Transfer { from, to, amount } => {
+                self.change_balance(&from, asset, kind, -amount);
+                self.change_balance(&to, asset, kind, amount);
+            }
+            _ => {}
+        }
+    }
+
+    fn position_mut(&mut self, who: String, asset: String) -> &mut Position {
+        self.positions.entry((who, asset)).or_default()
+    }
+
+    fn update_reserve(&mut self, asset: &str, update: impl FnOnce(&mut Reserve)) {
+        if let Some(reserve) = self.reserves.get_mut(asset) {
+            update(reserve);
+        }
+    }
+
+    fn change_balance(&mut self, who: &str, asset: &str, kind: TokenKind, amount: i128) {
+        let position = self.position_mut(who.to_string(), asset.to_string());
+
+        match kind {
+            TokenKind::SToken => position.s_token_balance += amount,
+            TokenKind::DebtToken => position.debt_token_balance += amount,
+        }
+    }
+
+    fn change_supply(&mut self, asset: &str, kind: TokenKind, amount: i128) {
+        self.update_reserve(asset, |r| match kind {
+            TokenKind::SToken => r.s_token_supply += amount,
+            TokenKind::DebtToken => r.debt_token_supply += amount,
+        });
+    }
+}
+
+fn set_mask(mask: u32, action: PauseAction, paused: bool) -> u32 {
+    if paused {
+        mask | action.mask()
+    } else {
+        mask & !action.mask()
+    }
+}
@@ -0,0 +1,197 @@
+#![cfg(test)]
+
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::xdr::{ScSymbol, ScVal};
+use soroban_sdk::{Address, Env};
+
+use crate::tests::sut::strkey;
+use pool_interface::types::price_source::PriceSource;
+
+use crate::{DecodeError, PoolEvent, TokenEvent, CONFIG_EVENT_VERSION};
+
+fn symbol(name: &str) -> ScVal {
+    ScVal::Symbol(ScSymbol(name.try_into().unwrap()))
+}
+
+fn address(env: &Env) -> (String, ScVal) {
+    let address = Address::generate(env);
+
+    (strkey(&address), ScVal::try_from(&address).unwrap())
+}
+
+#[test]
+fn should_decode_pool_event() {
+    let env = Env::default();
+    let (who, who_val) = address(&env);
+    let (asset, asset_val) = address(&env);
+
+    let event = PoolEvent::decode(
+        &[symbol("deposit"), who_val],
+        &ScVal::Vec(Some(
+            vec![asset_val, ScVal::from(100i128)].try_into().unwrap(),
+        )),
+    )
+    .unwrap();
+
+    assert_eq!(
+        event,
+        PoolEvent::Deposit {
+            who,
+            asset,
+            amount: 100
+        }
+    );
+}
+
+#[test]
+fn should_decode_price_fallback() {
+    let env = Env::default();
+    let (asset, asset_val) = address(&env);
+
+    let event = PoolEvent::decode(
+        &[
+            symbol("price_fallback"),
+            ScVal::U32(CONFIG_EVENT_VERSION),
+            asset_val,
+        ],
+        &ScVal::Vec(Some(
+            vec![
+                ScVal::Vec(Some(vec![symbol("StoredPrice")].try_into().unwrap())),
+                ScVal::from(10_000_000i128),
+            ]
+            .try_into()
+            .unwrap(),
+        )),
+    )
+    .unwrap();
+
+    assert_eq!(
+        event,
+        PoolEvent::PriceFallback {
+            asset,
+            source: PriceSource::StoredPrice,
+            price: 10_000_000
+        }
+    );
+}
+
+#[test]
+fn should_decode_price_breaker_tripped() {
+    let env = Env::default();
+    let (asset, asset_val) = address(&env);
+
+    let event = PoolEvent::decode(
+        &[
+            symbol("price_breaker_tripped"),
+            ScVal::U32(CONFIG_EVENT_VERSION),
+            asset_val,
+        ],
+        &ScVal::Vec(Some(
+            vec![ScVal::from(10_000_000i128), ScVal::from(20_000_000i128)]
+                .try_into()
+                .unwrap(),
+        )),
+    )
+    .unwrap();
+
+    assert_eq!(
+        event,
+        PoolEvent::PriceBreakerTripped {
+            asset,
+            reference_price: 10_000_000,
+            price: 20_000_000
+        }
+    );
+}
+
+#[test]
+fn should_decode_token_event() {
+    let env = Env::default();
+    let (from, from_val) = address(&env);
+
+    let event = TokenEvent::decode(&[symbol("burn"), from_val], &ScVal::from(7i128)).unwrap();
+
+    assert_eq!(event, TokenEvent::Burn { from, amount: 7 });
+}
+
+#[test]
+fn should_fail_when_name_is_missing() {
+    assert_eq!(
+        PoolEvent::decode(&[], &ScVal::Void),
+        Err(DecodeError::MissingName)
+    );
+    assert_eq!(
+        TokenEvent::decode(&[ScVal::U32(1)], &ScVal::Void),
+        Err(DecodeError::MissingName)
+    );
+}
+
+#[test]
+fn should_fail_when_event_is_unknown() {
+    assert_eq!(
+        PoolEvent::decode(&[symbol("unknown")], &ScVal::Void),
+        Err(DecodeError::UnknownEvent("unknown".into()))
+    );
+}
+
+#[test]
+fn should_fail_when_version_is_unsupported() {
+    let data = ScVal::Vec(Some(
+        vec![ScVal::Bool(false), ScVal::Bool(true)]
+            .try_into()
+            .unwrap(),
+    ));
+
+    assert_eq!(
+        PoolEvent::decode(
+            &[
+                symbol("pause_changed"),
+                ScVal::U32(CONFIG_EVENT_VERSION + 1)
+            ],
+            &data
+        ),
+        Err(DecodeError::UnsupportedVersion {
+            event: "pause_changed".into(),
+            version: CONFIG_EVENT_VERSION + 1
+        })
+    );
+    assert_eq!(
+        PoolEvent::decode(
+            &[symbol("pause_changed"), ScVal::U32(CONFIG_EVENT_VERSION)],
+            &data
+        ),
+        Ok(PoolEvent::PauseChanged {
+            prev_paused: false,
+            paused: true
+        })
+    );
+}
+
+#[test]
+fn should_fail_when_layout_differs() {
+    let env = Env::default();
+    let (_, who_val) = address(&env);
+
+    assert_eq!(
+        PoolEvent::decode(&[symbol("borrow"), who_val.clone()], &ScVal::from(1i128)),
+        Err(DecodeError::Malformed {
+            event: "borrow".into(),
+            expected: "vec"
+        })
+    );
+    assert_eq!(
+        PoolEvent::decode(
+            &[
+                symbol("operation_executed"),
+                ScVal::U32(CONFIG_EVENT_VERSION),
+                ScVal::U32(1),
+                who_val
+            ],
+            &ScVal::Void
+        ),
+        Err(DecodeError::Malformed {
+            event: "operation_executed".into(),
+            expected: "no more values"
+        })
+    );
+}
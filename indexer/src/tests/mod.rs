@@ -0,0 +1,3 @@
+pub mod decode;
+pub mod replay;
+pub mod sut;
@@ -0,0 +1,277 @@
+#![cfg(test)]
+
+use pool_interface::types::pause_action::PauseAction;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::xdr::{ScSymbol, ScVal};
+use soroban_sdk::{Address, Env};
+
+use crate::tests::sut::{advance_time, init_pool, ledger_events, strkey, Sut, DAY};
+use crate::{
+    ContractEvent, DecodeError, PoolEvent, PoolState, ReserveType, SkippedEvent, TokenEvent,
+    TokenKind,
+};
+
+/// Lender supplies the second reserve, borrower borrows it against the first one,
+/// repays a part of the debt and the lender moves a part of the deposit to another user.
+fn run_scenario(env: &Env, sut: &Sut) -> (Address, Address, Address) {
+    let lender = Address::generate(env);
+    let borrower = Address::generate(env);
+    let receiver = Address::generate(env);
+
+    sut.reserves[0].token_admin.mint(&borrower, &100_000_000);
+    sut.reserves[1].token_admin.mint(&lender, &10_000_000_000);
+    sut.reserves[1].token_admin.mint(&borrower, &1_000_000_000);
+
+    sut.pool
+        .deposit(&lender, &sut.reserves[1].token.address, &5_000_000_000);
+    sut.pool
+        .deposit(&borrower, &sut.reserves[0].token.address, &10_000_000);
+
+    advance_time(env, sut, DAY);
+    sut.pool
+        .borrow(&borrower, &sut.reserves[1].token.address, &400_000_000);
+
+    advance_time(env, sut, 30 * DAY);
+    sut.pool
+        .repay(&borrower, &sut.reserves[1].token.address, &100_000_000);
+
+    advance_time(env, sut, DAY);
+    sut.pool.withdraw(
+        &lender,
+        &sut.reserves[1].token.address,
+        &1_000_000_000,
+        &lender,
+    );
+    sut.reserves[1]
+        .s_token
+        .transfer(&lender, &receiver, &500_000_000);
+
+    sut.pool
+        .set_action_pause(&sut.pool_admin, &PauseAction::Borrow, &true);
+    sut.pool.set_pause(&sut.pool_admin, &true);
+    sut.pool.set_pause(&sut.pool_admin, &false);
+
+    (lender, borrower, receiver)
+}
+
+#[test]
+fn should_decode_all_events() {
+    let env = Env::default();
+    let sut = init_pool(&env);
+    run_scenario(&env, &sut);
+
+    let pool = strkey(&sut.pool.address);
+    let tokens: std::vec::Vec<String> = sut
+        .reserves
+        .iter()
+        .flat_map(|r| [strkey(&r.s_token.address), strkey(&r.debt_token.address)])
+        .collect();
+
+    let mut pool_events = 0;
+    let mut token_events = 0;
+
+    for event in ledger_events(&env) {
+        if event.contract == pool {
+            PoolEvent::decode(&event.topics, &event.data).unwrap();
+            pool_events += 1;
+        } else if tokens.contains(&event.contract) {
+            TokenEvent::decode(&event.topics, &event.data).unwrap();
+            token_events += 1;
+        }
+    }
+
+    assert!(pool_events > 0);
+    assert!(token_events > 0);
+}
+
+#[test]
+fn should_track_reserves() {
+    let env = Env::default();
+    let sut = init_pool(&env);
+    run_scenario(&env, &sut);
+
+    let (state, skipped) = PoolState::replay(strkey(&sut.pool.address), &ledger_events(&env));
+
+    assert!(skipped.is_empty());
+
+    assert_eq!(state.admin, Some(strkey(&sut.pool_admin)));
+    assert_eq!(
+        state.config.as_ref().unwrap().base_asset_address,
+        strkey(&sut.reserves[0].token.address)
+    );
+    assert!(!state.paused);
+    assert_eq!(state.paused_actions, PauseAction::Borrow.mask());
+    assert_eq!(state.reserves.len(), 2);
+
+    for (i, config) in sut.reserves.iter().enumerate() {
+        let asset = strkey(&config.token.address);
+        let reserve = &state.reserves[&asset];
+        let expected = sut.pool.get_reserve(&config.token.address).unwrap();
+
+        assert_eq!(reserve.id, i as u32);
+        assert_eq!(
+            reserve.reserve_type,
+            ReserveType::Fungible {
+                s_token: strkey(&config.s_token.address),
+                debt_token: strkey(&config.debt_token.address),
+            }
+        );
+        assert_eq!(reserve.is_active, expected.configuration.is_active);
+        assert_eq!(
+            reserve.borrowing_enabled,
+            expected.configuration.borrowing_enabled
+        );
+        assert_eq!(
+            reserve.collat_params.unwrap().liq_cap,
+            expected.configuration.liquidity_cap
+        );
+        assert_eq!(reserve.lender_ar, expected.lender_ar);
+        assert_eq!(reserve.borrower_ar, expected.borrower_ar);
+        assert_eq!(reserve.lender_ir, expected.lender_ir);
+        assert_eq!(reserve.borrower_ir, expected.borrower_ir);
+        assert_eq!(reserve.s_token_supply, config.s_token.total_supply());
+        assert_eq!(reserve.debt_token_supply, config.debt_token.total_supply());
+
+        assert_eq!(
+            state.tokens[&strkey(&config.s_token.address)],
+            (asset.clone(), TokenKind::SToken)
+        );
+        assert_eq!(
+            state.tokens[&strkey(&config.debt_token.address)],
+            (asset, TokenKind::DebtToken)
+        );
+    }
+
+    let borrowed = sut
+        .pool
+        .get_reserve(&sut.reserves[1].token.address)
+        .unwrap();
+    assert_eq!(
+        state.reserves[&strkey(&sut.reserves[1].token.address)].last_update_timestamp,
+        borrowed.last_update_timestamp
+    );
+
+    assert!(state.is_paused(&strkey(&sut.reserves[1].token.address), PauseAction::Borrow));
+    assert!(!state.is_paused(
+        &strkey(&sut.reserves[1].token.address),
+        PauseAction::Deposit
+    ));
+}
+
+#[test]
+fn should_track_positions() {
+    let env = Env::default();
+    let sut = init_pool(&env);
+    let (lender, borrower, receiver) = run_scenario(&env, &sut);
+
+    let (state, skipped) = PoolState::replay(strkey(&sut.pool.address), &ledger_events(&env));
+
+    assert!(skipped.is_empty());
+
+    for user in [&lender, &borrower, &receiver] {
+        for config in sut.reserves.iter() {
+            let asset = strkey(&config.token.address);
+            let position = state
+                .position(&strkey(user), &asset)
+                .cloned()
+                .unwrap_or_default();
+
+            assert_eq!(position.s_token_balance, config.s_token.balance(user));
+            assert_eq!(position.debt_token_balance, config.debt_token.balance(user));
+        }
+    }
+
+    let asset_0 = strkey(&sut.reserves[0].token.address);
+    let asset_1 = strkey(&sut.reserves[1].token.address);
+
+    assert!(
+        state
+            .position(&strkey(&borrower), &asset_0)
+            .unwrap()
+            .collateral_enabled
+    );
+    assert!(
+        state
+            .position(&strkey(&lender), &asset_1)
+            .unwrap()
+            .collateral_enabled
+    );
+    assert!(
+        state
+            .position(&strkey(&receiver), &asset_1)
+            .unwrap()
+            .collateral_enabled
+    );
+
+    let debt = state.debt(&strkey(&borrower), &asset_1).unwrap();
+    assert!(debt > 300_000_000);
+    assert!(debt < 400_000_000);
+}
+
+#[test]
+fn should_ignore_other_contracts() {
+    let env = Env::default();
+    let sut = init_pool(&env);
+    run_scenario(&env, &sut);
+
+    let events = ledger_events(&env);
+    let (state, skipped) = PoolState::replay(strkey(&Address::generate(&env)), &events);
+
+    assert!(skipped.is_empty());
+
+    assert_eq!(state.admin, None);
+    assert!(state.reserves.is_empty());
+    assert!(state.positions.is_empty());
+}
+
+#[test]
+fn should_replay_incrementally() {
+    let env = Env::default();
+    let sut = init_pool(&env);
+    run_scenario(&env, &sut);
+
+    let events = ledger_events(&env);
+    let pool = strkey(&sut.pool.address);
+    let (replayed, _) = PoolState::replay(pool.clone(), &events);
+
+    let (head, tail) = events.split_at(events.len() / 2);
+    let (mut state, _) = PoolState::replay(pool, head);
+
+    for event in tail {
+        state.apply(event).unwrap();
+    }
+
+    assert_eq!(state, replayed);
+}
+
+#[test]
+fn should_skip_undecodable_events() {
+    let env = Env::default();
+    let sut = init_pool(&env);
+    run_scenario(&env, &sut);
+
+    let pool = strkey(&sut.pool.address);
+    let mut events = ledger_events(&env);
+    let (expected, _) = PoolState::replay(pool.clone(), &events);
+
+    let index = events.len() / 2;
+    events.insert(
+        index,
+        ContractEvent {
+            contract: pool.clone(),
+            topics: vec![ScVal::Symbol(ScSymbol("unknown".try_into().unwrap()))],
+            data: ScVal::Void,
+        },
+    );
+
+    let (state, skipped) = PoolState::replay(pool, &events);
+
+    assert_eq!(
+        skipped,
+        vec![SkippedEvent {
+            index,
+            error: DecodeError::UnknownEvent("unknown".into()),
+        }]
+    );
+    assert_eq!(state, expected);
+}
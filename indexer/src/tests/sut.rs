@@ -0,0 +1,233 @@
+#![cfg(test)]
+
+use debt_token_interface::DebtTokenClient;
+use pool_interface::types::collateral_params_input::CollateralParamsInput;
+use pool_interface::types::oracle_asset::OracleAsset;
+use pool_interface::types::pool_config::PoolConfig;
+use pool_interface::types::price_feed::PriceFeed;
+use pool_interface::types::price_feed_config_input::PriceFeedConfigInput;
+use pool_interface::types::reserve_type::ReserveType;
+use pool_interface::types::timestamp_precision::TimestampPrecision;
+use pool_interface::LendingPoolClient;
+use price_feed_interface::types::asset::Asset;
+use price_feed_interface::types::price_data::PriceData;
+use price_feed_interface::PriceFeedClient;
+use s_token_interface::STokenClient;
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::token::StellarAssetClient as TokenAdminClient;
+use soroban_sdk::xdr::{ScAddress, ScVal};
+use soroban_sdk::{vec, Address, Env, IntoVal, TryFromVal};
+
+use crate::ContractEvent;
+
+#[allow(clippy::too_many_arguments)]
+mod pool {
+    soroban_sdk::contractimport!(file = "../target/wasm32-unknown-unknown/release/pool.wasm");
+}
+
+mod s_token {
+    soroban_sdk::contractimport!(file = "../target/wasm32-unknown-unknown/release/s_token.wasm");
+}
+
+mod debt_token {
+    soroban_sdk::contractimport!(file = "../target/wasm32-unknown-unknown/release/debt_token.wasm");
+}
+
+mod price_feed {
+    soroban_sdk::contractimport!(
+        file = "../target/wasm32-unknown-unknown/release/price_feed_mock.wasm"
+    );
+}
+
+pub(crate) const DAY: u64 = 24 * 60 * 60;
+
+pub(crate) struct ReserveConfig<'a> {
+    pub token: TokenClient<'a>,
+    pub token_admin: TokenAdminClient<'a>,
+    pub s_token: STokenClient<'a>,
+    pub debt_token: DebtTokenClient<'a>,
+}
+
+pub(crate) struct Sut<'a> {
+    pub pool: LendingPoolClient<'a>,
+    pub price_feed: PriceFeedClient<'a>,
+    pub pool_admin: Address,
+    pub reserves: std::vec::Vec<ReserveConfig<'a>>,
+}
+
+/// Pool with two fungible reserves, both are collateral and borrowable, priced 1:1 with the base asset
+pub(crate) fn init_pool<'a>(env: &Env) -> Sut<'a> {
+    env.budget().reset_unlimited();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1);
+
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let pool = LendingPoolClient::new(env, &env.register_contract_wasm(None, pool::WASM));
+    let price_feed = PriceFeedClient::new(env, &env.register_contract_wasm(None, price_feed::WASM));
+
+    let decimals = [7, 9];
+    let reserves: std::vec::Vec<ReserveConfig<'a>> = decimals
+        .iter()
+        .map(|_| {
+            let token = env.register_stellar_asset_contract(token_admin.clone());
+            let s_token = STokenClient::new(env, &env.register_contract_wasm(None, s_token::WASM));
+            let debt_token =
+                DebtTokenClient::new(env, &env.register_contract_wasm(None, debt_token::WASM));
+
+            ReserveConfig {
+                token: TokenClient::new(env, &token),
+                token_admin: TokenAdminClient::new(env, &token),
+                s_token,
+                debt_token,
+            }
+        })
+        .collect();
+
+    pool.initialize(
+        &admin,
+        &PoolConfig {
+            base_asset_address: reserves[0].token.address.clone(),
+            base_asset_decimals: 7,
+            flash_loan_fee: 5,
+            initial_health: 0,
+            timestamp_window: 20,
+            grace_period: 1,
+            user_assets_limit: 4,
+            min_collat_amount: 0,
+            min_debt_amount: 0,
+            liquidation_protocol_fee: 0,
+            ir_alpha: 143,
+            ir_initial_rate: 200,
+            ir_max_rate: 50_000,
+            ir_scaling_coeff: 9_000,
+        },
+    );
+
+    let mut feed_inputs = vec![env];
+
+    for (i, reserve) in reserves.iter().enumerate() {
+        let asset = &reserve.token.address;
+
+        reserve.s_token.initialize(
+            &"SToken".into_val(env),
+            &"STOKEN".into_val(env),
+            &pool.address,
+            asset,
+        );
+        reserve.debt_token.initialize(
+            &"DebtToken".into_val(env),
+            &"DTOKEN".into_val(env),
+            &pool.address,
+            asset,
+        );
+
+        pool.init_reserve(
+            asset,
+            &ReserveType::Fungible(
+                reserve.s_token.address.clone(),
+                reserve.debt_token.address.clone(),
+            ),
+        );
+        pool.enable_borrowing_on_reserve(asset, &true);
+        pool.configure_as_collateral(
+            asset,
+            &CollateralParamsInput {
+                liq_cap: 100_000_000 * 10_i128.pow(decimals[i]),
+                pen_order: i as u32 + 1,
+                util_cap: 9_000,
+                discount: 6_000,
+            },
+        );
+
+        price_feed.init(
+            &Asset::Stellar(asset.clone()),
+            &vec![
+                env,
+                PriceData {
+                    price: 10_i128.pow(14),
+                    timestamp: 0,
+                },
+            ],
+        );
+
+        feed_inputs.push_back(PriceFeedConfigInput {
+            asset: asset.clone(),
+            asset_decimals: decimals[i],
+            min_sanity_price_in_base: 5_000_000,
+            max_sanity_price_in_base: 100_000_000,
+            lastprice_fallback: false,
+            stored_price_max_age: 0,
+            max_feed_deviation: 0,
+            min_feeds: 1,
+            max_price_change: 0,
+            price_change_window: 0,
+            breaker_timeout: 0,
+            conservative_pricing: false,
+            feeds: vec![
+                env,
+                PriceFeed {
+                    feed: price_feed.address.clone(),
+                    feed_asset: OracleAsset::Stellar(asset.clone()),
+                    feed_decimals: 14,
+                    twap_window: 3600,
+                    min_twap_span: 0,
+                    min_timestamp_delta: 100,
+                    timestamp_precision: TimestampPrecision::Sec,
+                    path: vec![env],
+                    weight: None,
+                },
+            ],
+        });
+    }
+
+    pool.set_price_feeds(&feed_inputs);
+
+    Sut {
+        pool,
+        price_feed,
+        pool_admin: admin,
+        reserves,
+    }
+}
+
+/// Moves the ledger time forward keeping the prices fresh
+pub(crate) fn advance_time(env: &Env, sut: &Sut, time: u64) {
+    let timestamp = env.ledger().timestamp() + time;
+
+    for reserve in sut.reserves.iter() {
+        sut.price_feed.init(
+            &Asset::Stellar(reserve.token.address.clone()),
+            &vec![
+                env,
+                PriceData {
+                    price: 10_i128.pow(14),
+                    timestamp,
+                },
+            ],
+        );
+    }
+
+    env.ledger().with_mut(|li| li.timestamp = timestamp);
+}
+
+pub(crate) fn strkey(address: &Address) -> String {
+    ScAddress::try_from(address).unwrap().to_string()
+}
+
+/// All events emitted in the environment in the form they come from the ledger
+pub(crate) fn ledger_events(env: &Env) -> std::vec::Vec<ContractEvent> {
+    env.events()
+        .all()
+        .iter()
+        .map(|(contract, topics, data)| ContractEvent {
+            contract: strkey(&contract),
+            topics: topics
+                .iter()
+                .map(|topic| ScVal::try_from_val(env, &topic).unwrap())
+                .collect(),
+            data: ScVal::try_from_val(env, &data).unwrap(),
+        })
+        .collect()
+}
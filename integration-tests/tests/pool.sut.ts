@@ -42,7 +42,9 @@ interface PriceFeed {
     feed_asset: SlenderAsset;
     feed_asset_type: string;
     feed_decimals: number;
-    twap_records: number;
+    twap_window: number;
+    min_twap_span?: number;
+    weight?: number;
     min_timestamp_delta: number;
     timestamp_precision: string;
 }
@@ -52,6 +54,14 @@ interface PriceFeedConfig {
     asset_decimals: number;
     min_sanity_price_in_base: number;
     max_sanity_price_in_base: number;
+    lastprice_fallback: boolean;
+    stored_price_max_age: number;
+    max_feed_deviation: number;
+    min_feeds: number;
+    max_price_change: number;
+    price_change_window: number;
+    breaker_timeout: number;
+    conservative_pricing: boolean;
 }
 
 interface PriceData {
@@ -80,6 +90,7 @@ export async function init(client: SorobanClient, customXlm = true): Promise<voi
     await initToken(client, "USDC", "USD Coin", 9);
     await initToken(client, "RWA", "RWA asset", 9);
 
+    await initDeployer(client);
     await initPool(client, `${generateSalt(++salt)}`);
     // need to create treasury account to be able to receive native XLM token
     await client.registerAccount(treasuryKeys.publicKey());
@@ -122,7 +133,7 @@ export async function init(client: SorobanClient, customXlm = true): Promise<voi
                 feed_asset_type: 'Stellar',
                 feed_decimals: 14,
                 feed: process.env.SLENDER_PRICE_FEED,
-                twap_records: 1,
+                twap_window: 0,
                 min_timestamp_delta: 100_000_000_000,
                 timestamp_precision: "Sec"
             },
@@ -137,7 +148,7 @@ export async function init(client: SorobanClient, customXlm = true): Promise<voi
                 feed_asset_type: 'Stellar',
                 feed_decimals: 16,
                 feed: process.env.SLENDER_PRICE_FEED,
-                twap_records: 1,
+                twap_window: 0,
                 min_timestamp_delta: 100_000_000_000,
                 timestamp_precision: "Sec"
             },
@@ -152,7 +163,7 @@ export async function init(client: SorobanClient, customXlm = true): Promise<voi
                 feed_asset_type: 'Stellar',
                 feed_decimals: 16,
                 feed: process.env.SLENDER_PRICE_FEED,
-                twap_records: 1,
+                twap_window: 0,
                 min_timestamp_delta: 100_000_000_000,
                 timestamp_precision: "Sec"
             },
@@ -167,7 +178,7 @@ export async function init(client: SorobanClient, customXlm = true): Promise<voi
                 feed_asset_type: 'Stellar',
                 feed_decimals: 16,
                 feed: process.env.SLENDER_PRICE_FEED,
-                twap_records: 1,
+                twap_window: 0,
                 min_timestamp_delta: 100_000_000_000,
                 timestamp_precision: "Sec"
             },
@@ -186,6 +197,7 @@ export async function releaseInit(client: SorobanClient): Promise<void> {
     const generateSalt = (value: number): string =>
         String(value).padStart(64, "0");
 
+    await initDeployer(client);
     await initPool(client, `${generateSalt(++salt)}`);
 
     await initSToken(client, "XRP", `${generateSalt(++salt)}`);
@@ -219,7 +231,7 @@ export async function releaseInit(client: SorobanClient): Promise<void> {
                 feed_asset_type: process.env['XLM_FEED_ASSET_TYPE'],
                 feed_decimals: +process.env['XLM_FEED_DECIMALS'],
                 feed: process.env.SLENDER_PRICE_FEED,
-                twap_records: +process.env['XLM_PRICE_TWAP_RECORDS'],
+                twap_window: +process.env['XLM_PRICE_TWAP_WINDOW'],
                 min_timestamp_delta: +process.env['XLM_MIN_TIMESTAMP_DELTA'],
                 timestamp_precision: process.env['XLM_PRICE_TIMESTAMP_PRECISION']
             },
@@ -234,7 +246,7 @@ export async function releaseInit(client: SorobanClient): Promise<void> {
                 feed_asset_type: process.env['XRP_FEED_ASSET_TYPE'],
                 feed_decimals: +process.env['XRP_FEED_DECIMALS'],
                 feed: process.env.SLENDER_PRICE_FEED,
-                twap_records: +process.env['XRP_PRICE_TWAP_RECORDS'],
+                twap_window: +process.env['XRP_PRICE_TWAP_WINDOW'],
                 min_timestamp_delta: +process.env['XRP_MIN_TIMESTAMP_DELTA'],
                 timestamp_precision: process.env['XRP_PRICE_TIMESTAMP_PRECISION']
             },
@@ -249,7 +261,7 @@ export async function releaseInit(client: SorobanClient): Promise<void> {
                 feed_asset_type: process.env['USDC_FEED_ASSET_TYPE'],
                 feed_decimals: +process.env['USDC_FEED_DECIMALS'],
                 feed: process.env.SLENDER_PRICE_FEED,
-                twap_records: +process.env['USDC_PRICE_TWAP_RECORDS'],
+                twap_window: +process.env['USDC_PRICE_TWAP_WINDOW'],
                 min_timestamp_delta: +process.env['USDC_MIN_TIMESTAMP_DELTA'],
                 timestamp_precision: process.env['USDC_PRICE_TIMESTAMP_PRECISION']
             },
@@ -483,6 +495,23 @@ export async function liquidate(
     return txResult;
 }
 
+export async function setPause(
+    client: SorobanClient,
+    signer: Keypair,
+    value: boolean
+): Promise<SendTransactionResult> {
+    const txResult = await client.sendTransaction(
+        process.env.SLENDER_POOL,
+        "set_pause",
+        signer,
+        3,
+        convertToScvAddress(signer.publicKey()),
+        convertToScvBool(value)
+    );
+
+    return txResult;
+}
+
 export async function collatCoeff(
     client: SorobanClient,
     asset: SlenderAsset
@@ -778,6 +807,19 @@ async function initToken(
     );
 }
 
+async function initDeployer(client: SorobanClient): Promise<void> {
+    await initContract("DEPLOYER_INITIALIZED", () =>
+        client.sendTransaction(
+            process.env.SLENDER_DEPLOYER,
+            "initialize",
+            adminKeys,
+            3,
+            convertToScvAddress(adminKeys.publicKey()),
+            convertToScvBytes(process.env.SLENDER_DEPLOYER_SALT, "hex")
+        )
+    );
+}
+
 async function initSToken(
     client: SorobanClient,
     asset: SlenderAsset,
@@ -912,7 +954,15 @@ async function initPoolPriceFeed(
         asset_decimals: number,
         max_sanity_price_in_base: bigint,
         min_sanity_price_in_base: bigint,
-        priceFeedConfig: PriceFeed
+        priceFeedConfig: PriceFeed,
+        lastprice_fallback?: boolean,
+        stored_price_max_age?: number,
+        max_feed_deviation?: number,
+        min_feeds?: number,
+        max_price_change?: number,
+        price_change_window?: number,
+        breaker_timeout?: number,
+        conservative_pricing?: boolean
     }[]
 ): Promise<void> {
     await initContract(
@@ -925,6 +975,8 @@ async function initPoolPriceFeed(
             convertToScvVec(inputs.map(input => convertToScvMap({
                 "asset": convertToScvAddress(process.env[`SLENDER_TOKEN_${input.asset}`]),
                 "asset_decimals": convertToScvU32(input.asset_decimals),
+                "breaker_timeout": convertToScvU64(input.breaker_timeout ?? 0),
+                "conservative_pricing": convertToScvBool(input.conservative_pricing ?? false),
                 "feeds": convertToScvVec([
                     convertToScvMap({
                         "feed": convertToScvAddress(input.priceFeedConfig.feed),
@@ -934,12 +986,23 @@ async function initPoolPriceFeed(
                         ]),
                         "feed_decimals": convertToScvU32(input.priceFeedConfig.feed_decimals),
                         "min_timestamp_delta": convertToScvU64(input.priceFeedConfig.min_timestamp_delta),
+                        "min_twap_span": convertToScvU64(input.priceFeedConfig.min_twap_span ?? 0),
+                        "path": convertToScvVec([]),
                         "timestamp_precision": convertToScvVec([xdr.ScVal.scvSymbol(input.priceFeedConfig.timestamp_precision)]),
-                        "twap_records": convertToScvU32(input.priceFeedConfig.twap_records)
+                        "twap_window": convertToScvU64(input.priceFeedConfig.twap_window),
+                        "weight": input.priceFeedConfig.weight === undefined
+                            ? xdr.ScVal.scvVoid()
+                            : convertToScvU32(input.priceFeedConfig.weight)
                     })
                 ]),
+                "lastprice_fallback": convertToScvBool(input.lastprice_fallback ?? false),
+                "max_feed_deviation": convertToScvU32(input.max_feed_deviation ?? 0),
+                "max_price_change": convertToScvU32(input.max_price_change ?? 0),
                 "max_sanity_price_in_base": convertToScvI128(input.max_sanity_price_in_base),
-                "min_sanity_price_in_base": convertToScvI128(input.min_sanity_price_in_base)
+                "min_feeds": convertToScvU32(input.min_feeds ?? 1),
+                "min_sanity_price_in_base": convertToScvI128(input.min_sanity_price_in_base),
+                "price_change_window": convertToScvU64(input.price_change_window ?? 0),
+                "stored_price_max_age": convertToScvU64(input.stored_price_max_age ?? 0)
             })))
         )
     );
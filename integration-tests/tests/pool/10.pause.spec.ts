@@ -0,0 +1,55 @@
+import { SorobanClient } from "../soroban.client";
+import {
+    cleanSlenderEnvKeys,
+    deploy,
+    deposit,
+    init,
+    mintUnderlyingTo,
+    sTokenBalanceOf,
+    setPause,
+} from "../pool.sut";
+import {
+    adminKeys,
+    lender1Keys,
+} from "../soroban.config";
+import { assert, expect, use } from "chai";
+import chaiAsPromised from 'chai-as-promised';
+use(chaiAsPromised);
+
+describe("LendingPool: Pool admin pauses and unpauses the pool", function () {
+    let client: SorobanClient;
+    let lender1Address: string;
+
+    before(async function () {
+        client = new SorobanClient();
+
+        await cleanSlenderEnvKeys();
+        await deploy();
+        await init(client);
+
+        lender1Address = lender1Keys.publicKey();
+
+        await client.registerAccount(lender1Address);
+        await mintUnderlyingTo(client, "XLM", lender1Address, 1_000_000_000n);
+    });
+
+    it("Case 1: Lender can't deposit while the pool is paused", async function () {
+        await setPause(client, adminKeys, true);
+
+        await expect(deposit(client, lender1Keys, "XLM", 100_000_000n)).to.eventually.rejected;
+    });
+
+    it("Case 2: Non-admin can't unpause the pool", async function () {
+        await expect(setPause(client, lender1Keys, false)).to.eventually.rejected;
+    });
+
+    it("Case 3: Lender deposits after the pool admin unpaused the pool", async function () {
+        await setPause(client, adminKeys, false);
+
+        await deposit(client, lender1Keys, "XLM", 100_000_000n);
+
+        const lender1SXlmBalance = await sTokenBalanceOf(client, "XLM", lender1Address);
+
+        assert.equal(lender1SXlmBalance, 100_000_000n);
+    });
+});
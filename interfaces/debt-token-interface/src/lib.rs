@@ -4,6 +4,15 @@
 use soroban_sdk::{contractclient, contractspecfn, Address, BytesN, Env, String};
 pub struct Spec;
 
+/// Version of the interface implemented by the current debt token contract.
+pub const VERSION: u32 = 1;
+
+/// Oldest debt token version the pool is able to work with.
+pub const MIN_COMPATIBLE_VERSION: u32 = 1;
+
+/// Newest debt token version the pool is able to work with.
+pub const MAX_COMPATIBLE_VERSION: u32 = VERSION;
+
 #[contractspecfn(name = "Spec", export = false)]
 #[contractclient(name = "DebtTokenClient")]
 pub trait DebtTokenTrait {
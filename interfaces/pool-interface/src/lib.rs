@@ -1,23 +1,41 @@
 #![deny(warnings)]
 #![no_std]
 
+use price_feed_interface::types::price_data::PriceData;
 use soroban_sdk::{contractclient, contractspecfn, Address, Bytes, BytesN, Env, Vec};
 use types::account_position::AccountPosition;
 use types::collateral_params_input::CollateralParamsInput;
 use types::error::Error;
 use types::flash_loan_asset::FlashLoanAsset;
+use types::migration_input::MigrationInput;
+use types::pause_action::PauseAction;
 use types::pause_info::PauseInfo;
+use types::pause_matrix::PauseMatrix;
 use types::pool_config::PoolConfig;
+use types::price_breaker::PriceBreaker;
 use types::price_feed_config::PriceFeedConfig;
 use types::price_feed_config_input::PriceFeedConfigInput;
+use types::queued_operation::QueuedOperation;
 use types::reserve_data::ReserveData;
 use types::reserve_type::ReserveType;
+use types::role::Role;
+use types::timelock_operation::TimelockOperation;
 use types::user_config::UserConfiguration;
+use types::wind_down_info::WindDownInfo;
 
 pub mod types;
 
 pub struct Spec;
 
+/// Version of the interface implemented by the current pool contract.
+pub const VERSION: u32 = 1;
+
+/// Oldest pool version the deployer is able to work with.
+pub const MIN_COMPATIBLE_VERSION: u32 = 1;
+
+/// Newest pool version the deployer is able to work with.
+pub const MAX_COMPATIBLE_VERSION: u32 = VERSION;
+
 /// Interface for SToken
 #[contractspecfn(name = "Spec", export = false)]
 #[contractclient(name = "LendingPoolClient")]
@@ -33,12 +51,54 @@ pub trait LendingPoolTrait {
         new_wasm_hash: BytesN<32>,
     ) -> Result<(), Error>;
 
+    fn upgrade_tokens(
+        env: Env,
+        s_token_wasm_hash: BytesN<32>,
+        debt_token_wasm_hash: BytesN<32>,
+    ) -> Result<(), Error>;
+
     fn version() -> u32;
 
+    fn migrate(env: Env, from_version: u32, input: MigrationInput) -> Result<(), Error>;
+
+    fn migrate_user_configs(env: Env, users: Vec<Address>) -> Result<(), Error>;
+
+    fn storage_version(env: Env) -> u32;
+
+    fn grant_role(env: Env, role: Role, who: Address) -> Result<(), Error>;
+
+    fn revoke_role(env: Env, role: Role) -> Result<(), Error>;
+
+    fn role(env: Env, role: Role) -> Result<Address, Error>;
+
+    fn propose_admin(env: Env, new_admin: Address) -> Result<(), Error>;
+
+    fn accept_admin(env: Env) -> Result<(), Error>;
+
+    fn cancel_admin_proposal(env: Env) -> Result<(), Error>;
+
+    fn pending_admin(env: Env) -> Option<Address>;
+
+    fn set_timelock_delay(env: Env, delay: u64) -> Result<(), Error>;
+
+    fn timelock_delay(env: Env) -> u64;
+
+    fn schedule_operation(env: Env, operation: TimelockOperation, eta: u64) -> Result<u32, Error>;
+
+    fn execute_operation(env: Env, id: u32) -> Result<(), Error>;
+
+    fn cancel_operation(env: Env, id: u32) -> Result<(), Error>;
+
+    fn queued_operations(env: Env) -> Vec<QueuedOperation>;
+
     fn init_reserve(env: Env, asset: Address, reserve_type: ReserveType) -> Result<(), Error>;
 
+    fn delist_reserve(env: Env, asset: Address) -> Result<(), Error>;
+
     fn set_reserve_status(env: Env, asset: Address, is_active: bool) -> Result<(), Error>;
 
+    fn set_reserve_freeze(env: Env, asset: Address, is_frozen: bool) -> Result<(), Error>;
+
     fn configure_as_collateral(
         env: Env,
         asset: Address,
@@ -61,6 +121,12 @@ pub trait LendingPoolTrait {
 
     fn price_feeds(env: Env, asset: Address) -> Option<PriceFeedConfig>;
 
+    fn last_good_price(env: Env, asset: Address) -> Option<PriceData>;
+
+    fn reset_price_breaker(env: Env, asset: Address) -> Result<(), Error>;
+
+    fn price_breaker(env: Env, asset: Address) -> Option<PriceBreaker>;
+
     fn deposit(env: Env, who: Address, asset: Address, amount: i128) -> Result<(), Error>;
 
     fn repay(env: Env, who: Address, asset: Address, amount: i128) -> Result<(), Error>;
@@ -91,10 +157,39 @@ pub trait LendingPoolTrait {
 
     fn borrow(env: Env, who: Address, asset: Address, amount: i128) -> Result<(), Error>;
 
-    fn set_pause(env: Env, value: bool) -> Result<(), Error>;
+    fn set_pause(env: Env, who: Address, value: bool) -> Result<(), Error>;
 
     fn pause_info(env: Env) -> PauseInfo;
 
+    fn start_wind_down(env: Env, rate_step: u32, step_secs: u64) -> Result<(), Error>;
+
+    fn start_settlement(env: Env) -> Result<(), Error>;
+
+    fn wind_down_info(env: Env) -> WindDownInfo;
+
+    fn redeem(env: Env, who: Address, asset: Address, to: Address) -> Result<(), Error>;
+
+    fn write_off_debt(env: Env, who: Address, asset: Address) -> Result<(), Error>;
+
+    fn set_accrual_suspension(env: Env, enabled: bool) -> Result<(), Error>;
+
+    fn set_action_pause(
+        env: Env,
+        who: Address,
+        action: PauseAction,
+        value: bool,
+    ) -> Result<(), Error>;
+
+    fn set_reserve_pause(
+        env: Env,
+        who: Address,
+        asset: Address,
+        action: PauseAction,
+        value: bool,
+    ) -> Result<(), Error>;
+
+    fn pause_matrix(env: Env) -> PauseMatrix;
+
     fn account_position(env: Env, who: Address) -> Result<AccountPosition, Error>;
 
     fn liquidate(env: Env, liquidator: Address, who: Address) -> Result<(), Error>;
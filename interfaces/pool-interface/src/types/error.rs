@@ -10,6 +10,11 @@ pub enum Error {
     BellowMinValue = 3,
     ExceededMaxValue = 4,
     GracePeriod = 5,
+    InvalidRole = 6,
+    WindDown = 7,
+    InvalidWindDownStage = 8,
+    MigrationRequired = 9,
+    InvalidStorageVersion = 10,
 
     NoActiveReserve = 100,
     ReservesMaxCapacityExceeded = 101,
@@ -17,6 +22,12 @@ pub enum Error {
     InvalidAssetPrice = 103,
     LiquidationOrderMustBeUnique = 104,
     NotFungible = 105,
+    ReserveNotEmpty = 106,
+    ReserveFrozen = 107,
+    InvalidTokenVersion = 108,
+    PriceDeviationExceeded = 109,
+    NotEnoughPriceFeeds = 110,
+    PriceBreakerTripped = 111,
 
     NotEnoughAvailableUserBalance = 200,
     DebtError = 201,
@@ -29,6 +40,7 @@ pub enum Error {
     LiquidateMathError = 305,
     MustNotBeInCollateralAsset = 306,
     FlashLoanReceiverError = 307,
+    CollateralNotLiquidated = 308,
 
     MathOverflowError = 400,
     MustBeLtePercentageFactor = 401,
@@ -39,4 +51,9 @@ pub enum Error {
     AccruedRateMathError = 500,
     CollateralCoeffMathError = 501,
     DebtCoeffMathError = 502,
+
+    MustBeScheduled = 600,
+    InvalidEta = 601,
+    OperationNotReady = 602,
+    OperationExpired = 603,
 }
@@ -0,0 +1,13 @@
+use soroban_sdk::{contracttype, Address, Map};
+
+/// Pool accounting the storage before v3 didn't keep, attested by the pool admin on migration
+#[derive(Clone)]
+#[contracttype]
+pub struct MigrationInput {
+    /// Total deposits of the RWA reserves. Reserves missing here get the pool balance
+    /// less the protocol fee, which also counts the tokens sent to the pool directly.
+    pub rwa_total_supplies: Map<Address, i128>,
+    /// Number of users holding a collateral or borrowing bit of the reserve id.
+    /// Ids missing here are not counted, so they are never reused after delisting.
+    pub reserve_id_holders: Map<u32, u32>,
+}
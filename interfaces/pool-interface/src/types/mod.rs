@@ -4,14 +4,24 @@ pub mod base_asset_config;
 pub mod collateral_params_input;
 pub mod error;
 pub mod flash_loan_asset;
+pub mod migration_input;
 pub mod oracle_asset;
+pub mod pause_action;
 pub mod pause_info;
+pub mod pause_matrix;
 pub mod pool_config;
+pub mod price_breaker;
 pub mod price_feed;
 pub mod price_feed_config;
 pub mod price_feed_config_input;
+pub mod price_leg;
+pub mod price_source;
+pub mod queued_operation;
 pub mod reserve_configuration;
 pub mod reserve_data;
 pub mod reserve_type;
+pub mod role;
+pub mod timelock_operation;
 pub mod timestamp_precision;
 pub mod user_config;
+pub mod wind_down_info;
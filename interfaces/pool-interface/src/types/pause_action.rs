@@ -0,0 +1,32 @@
+use soroban_sdk::contracttype;
+
+/// Pool actions which can be paused separately, for the whole pool or for a single reserve
+#[contracttype]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum PauseAction {
+    Deposit = 0,
+    Withdraw = 1,
+    Borrow = 2,
+    Repay = 3,
+    Liquidate = 4,
+    FlashLoan = 5,
+    Transfer = 6,
+}
+
+impl PauseAction {
+    pub const ALL: [PauseAction; 7] = [
+        PauseAction::Deposit,
+        PauseAction::Withdraw,
+        PauseAction::Borrow,
+        PauseAction::Repay,
+        PauseAction::Liquidate,
+        PauseAction::FlashLoan,
+        PauseAction::Transfer,
+    ];
+
+    /// Bit of the action in a paused actions mask
+    pub fn mask(&self) -> u32 {
+        1 << (*self as u32)
+    }
+}
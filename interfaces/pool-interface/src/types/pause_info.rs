@@ -5,10 +5,22 @@ pub struct PauseInfo {
     pub paused: bool,
     pub grace_period_secs: u64,
     pub unpaused_at: u64,
+    pub paused_at: u64,
+    /// Interest isn't accrued for the time the pool is paused
+    pub suspend_accrual: bool,
 }
 
 impl PauseInfo {
     pub fn grace_period_ends_at(&self) -> u64 {
         self.unpaused_at + self.grace_period_secs
     }
+
+    /// Timestamp up to which interest is accrued
+    pub fn accrual_timestamp(&self, now: u64) -> u64 {
+        if self.paused && self.suspend_accrual {
+            now.min(self.paused_at)
+        } else {
+            now
+        }
+    }
 }
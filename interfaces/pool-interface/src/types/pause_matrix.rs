@@ -0,0 +1,13 @@
+use soroban_sdk::{contracttype, Address, Map, Vec};
+
+use super::pause_action::PauseAction;
+
+#[contracttype]
+pub struct PauseMatrix {
+    /// Whole pool pause
+    pub paused: bool,
+    /// Actions paused for every reserve
+    pub actions: Vec<PauseAction>,
+    /// Actions paused for the particular reserves
+    pub reserves: Map<Address, Vec<PauseAction>>,
+}
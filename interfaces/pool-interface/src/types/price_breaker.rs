@@ -0,0 +1,24 @@
+use soroban_sdk::contracttype;
+
+/// State of the circuit breaker guarding an asset price against abrupt moves
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct PriceBreaker {
+    /// Price the moves within the window are measured from
+    pub reference_price: i128,
+    /// Ledger timestamp the reference price was accepted at
+    pub reference_timestamp: u64,
+    /// Ledger timestamp the breaker tripped at
+    pub tripped_at: Option<u64>,
+}
+
+impl PriceBreaker {
+    /// Tripped breaker resets after `timeout` seconds,
+    /// zero timeout leaves the reset up to the oracle admin
+    pub fn is_tripped(&self, now: u64, timeout: u64) -> bool {
+        match self.tripped_at {
+            Some(tripped_at) => timeout == 0 || now.saturating_sub(tripped_at) <= timeout,
+            None => false,
+        }
+    }
+}
@@ -1,6 +1,7 @@
-use soroban_sdk::{contracttype, Address};
+use soroban_sdk::{contracttype, Address, Vec};
 
 use super::oracle_asset::OracleAsset;
+use super::price_leg::PriceLeg;
 use super::timestamp_precision::TimestampPrecision;
 
 #[derive(Clone)]
@@ -9,7 +10,15 @@ pub struct PriceFeed {
     pub feed: Address,
     pub feed_asset: OracleAsset,
     pub feed_decimals: u32,
-    pub twap_records: u32,
+    /// Period in seconds the TWAP is computed over, ending at the current time
+    pub twap_window: u64,
+    /// Minimum time in seconds the feed records must cover within the TWAP window
+    pub min_twap_span: u64,
     pub min_timestamp_delta: u64,
     pub timestamp_precision: TimestampPrecision,
+    /// Legs converting the price quoted by the feed into the base asset, in order.
+    /// Empty when the feed quotes the asset in the base asset.
+    pub path: Vec<PriceLeg>,
+    /// Weight of the feed in the median across feeds, `None` counts as 1
+    pub weight: Option<u32>,
 }
@@ -9,4 +9,26 @@ pub struct PriceFeedConfig {
     pub min_sanity_price_in_base: i128,
     pub max_sanity_price_in_base: i128,
     pub feeds: Vec<PriceFeed>,
+    /// Falls back to the SEP-40 `lastprice` of the feeds when none of them returns a fresh TWAP
+    pub lastprice_fallback: bool,
+    /// Maximum age in seconds of the last good price the pool falls back to
+    /// when the feeds return no fresh price. Zero disables the fallback.
+    pub stored_price_max_age: u64,
+    /// Maximum deviation of a feed price from the median of the feeds in basis points.
+    /// Outlier feeds are dropped while most of the feeds agree, otherwise the price is rejected.
+    /// Zero disables the check.
+    pub max_feed_deviation: u32,
+    /// Minimum number of feeds that must return a fresh price for the price to be accepted
+    pub min_feeds: u32,
+    /// Maximum move of the price within `price_change_window` in basis points before
+    /// the circuit breaker trips. Zero disables the breaker.
+    pub max_price_change: u32,
+    /// Window in seconds the price moves are measured in
+    pub price_change_window: u64,
+    /// Seconds after which a tripped breaker resets by itself.
+    /// Zero leaves the reset to the oracle admin.
+    pub breaker_timeout: u64,
+    /// Values collateral at the lower and debt at the higher of the SEP-40 `lastprice`
+    /// and TWAP prices in the health checks of new borrows and withdrawals
+    pub conservative_pricing: bool,
 }
@@ -0,0 +1,21 @@
+use soroban_sdk::{contracttype, Address};
+
+use super::oracle_asset::OracleAsset;
+use super::timestamp_precision::TimestampPrecision;
+
+/// Intermediate conversion of a price path, e.g. XLM to the base asset
+/// for a feed quoting the asset in XLM
+#[derive(Clone)]
+#[contracttype]
+pub struct PriceLeg {
+    pub feed: Address,
+    /// Quote asset of the previous leg of the path as the feed lists it
+    pub feed_asset: OracleAsset,
+    pub feed_decimals: u32,
+    /// Period in seconds the TWAP is computed over, ending at the current time
+    pub twap_window: u64,
+    /// Minimum time in seconds the feed records must cover within the TWAP window
+    pub min_twap_span: u64,
+    pub min_timestamp_delta: u64,
+    pub timestamp_precision: TimestampPrecision,
+}
@@ -0,0 +1,13 @@
+use soroban_sdk::contracttype;
+
+/// Source of an asset price, in the order the sources are tried
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum PriceSource {
+    /// Median of the feeds TWAP
+    Twap,
+    /// Median of the feeds SEP-40 `lastprice`
+    LastPrice,
+    /// Last good price stored by the pool
+    StoredPrice,
+}
@@ -0,0 +1,12 @@
+use soroban_sdk::contracttype;
+
+use super::timelock_operation::TimelockOperation;
+
+#[contracttype]
+#[derive(Clone)]
+pub struct QueuedOperation {
+    pub id: u32,
+    /// Timestamp after which the operation can be executed
+    pub eta: u64,
+    pub operation: TimelockOperation,
+}
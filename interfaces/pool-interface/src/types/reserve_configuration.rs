@@ -4,6 +4,9 @@ use soroban_sdk::contracttype;
 #[derive(Debug, Clone)]
 pub struct ReserveConfiguration {
     pub is_active: bool,
+    /// Frozen reserve rejects new deposits, borrows and collateral enabling
+    /// while repay, withdraw and liquidation keep working.
+    pub is_frozen: bool,
     pub borrowing_enabled: bool,
     pub liquidity_cap: i128,
     pub pen_order: u32,
@@ -20,6 +23,7 @@ impl ReserveConfiguration {
             pen_order: Default::default(),
             util_cap: Default::default(),
             is_active: true,
+            is_frozen: false,
             borrowing_enabled: false,
             discount: Default::default(),
         }
@@ -0,0 +1,16 @@
+use soroban_sdk::contracttype;
+
+#[contracttype]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    /// Upgrades, reserves listing and roles management
+    PoolAdmin,
+    /// Collateral parameters, caps and interest rate configuration
+    RiskAdmin,
+    /// Price feeds configuration
+    OracleAdmin,
+    /// Pausing the pool
+    EmergencyGuardian,
+    /// Protocol fee claiming
+    Treasury,
+}
@@ -0,0 +1,23 @@
+use soroban_sdk::{contracttype, Address, BytesN, Vec};
+
+use super::collateral_params_input::CollateralParamsInput;
+use super::pool_config::PoolConfig;
+use super::price_feed_config_input::PriceFeedConfigInput;
+
+/// Admin operation which takes effect only after the timelock delay
+#[contracttype]
+#[derive(Clone)]
+pub enum TimelockOperation {
+    SetPoolConfiguration(PoolConfig),
+    ConfigureAsCollateral(Address, CollateralParamsInput),
+    SetPriceFeeds(Vec<PriceFeedConfigInput>),
+    /// New wasm hash of the pool
+    Upgrade(BytesN<32>),
+    /// Asset, `true` to upgrade the s-token or `false` for the debt token, new wasm hash
+    UpgradeToken(Address, bool, BytesN<32>),
+    /// New wasm hashes of the s-token and the debt token of every fungible reserve
+    UpgradeTokens(BytesN<32>, BytesN<32>),
+    SetTimelockDelay(u64),
+    /// Rate step, step duration in seconds
+    StartWindDown(u32, u64),
+}
@@ -1,21 +1,24 @@
-use soroban_sdk::{assert_with_error, contracttype, Env};
-
-use crate::types::error::Error;
+use soroban_sdk::{contracttype, Env, Vec};
 
 const BORROWING_MASK: u128 = 0x55555555555555555555555555555555;
+const RESERVES_PER_WORD: u32 = u128::BITS / 2;
 
+/// Bitmap of the user's positions. Every reserve takes two bits: the even bit is set when
+/// the user borrows the reserve, the odd one when the reserve is used as collateral.
+/// Words are allocated on demand, so the bitmap covers the whole `u8` range of reserve ids
+/// while users of the first reserves pay for a single word only.
 #[contracttype]
-#[derive(Default)]
-pub struct UserConfiguration(u128, u32);
+pub struct UserConfiguration(Vec<u128>, u32);
 
 impl UserConfiguration {
+    pub fn new(env: &Env) -> Self {
+        Self(Vec::new(env), 0)
+    }
+
     pub fn set_borrowing(&mut self, env: &Env, reserve_index: u8, borrow: bool) {
-        Self::require_reserve_index(env, reserve_index);
         let is_borrowed_before = self.is_borrowing(env, reserve_index);
 
-        let reserve_index: u128 = reserve_index.into();
-        self.0 = (self.0 & !(1 << (reserve_index * 2)))
-            | ((if borrow { 1 } else { 0 }) << (reserve_index * 2));
+        self.set_bit(reserve_index, 0, borrow);
 
         if is_borrowed_before == borrow {
             return;
@@ -34,12 +37,9 @@ impl UserConfiguration {
         reserve_index: u8,
         use_as_collateral: bool,
     ) {
-        Self::require_reserve_index(env, reserve_index);
         let is_collat_before = self.is_using_as_collateral(env, reserve_index);
 
-        let reserve_index: u128 = reserve_index.into();
-        self.0 = (self.0 & !(1 << (reserve_index * 2 + 1)))
-            | ((if use_as_collateral { 1 } else { 0 }) << (reserve_index * 2 + 1));
+        self.set_bit(reserve_index, 1, use_as_collateral);
 
         if is_collat_before == use_as_collateral {
             return;
@@ -52,43 +52,81 @@ impl UserConfiguration {
         };
     }
 
-    pub fn is_using_as_collateral(&self, env: &Env, reserve_index: u8) -> bool {
-        Self::require_reserve_index(env, reserve_index);
-
-        let reserve_index: u128 = reserve_index.into();
-        (self.0 >> (reserve_index * 2 + 1)) & 1 != 0
+    pub fn is_using_as_collateral(&self, _env: &Env, reserve_index: u8) -> bool {
+        (self.word(reserve_index) >> (Self::bit_offset(reserve_index) + 1)) & 1 != 0
     }
 
-    pub fn is_using_as_collateral_or_borrowing(&self, env: &Env, reserve_index: u8) -> bool {
-        Self::require_reserve_index(env, reserve_index);
-
-        let reserve_index: u128 = reserve_index.into();
-        (self.0 >> (reserve_index * 2)) & 3 != 0
+    pub fn is_using_as_collateral_or_borrowing(&self, _env: &Env, reserve_index: u8) -> bool {
+        (self.word(reserve_index) >> Self::bit_offset(reserve_index)) & 3 != 0
     }
 
-    pub fn is_borrowing(&self, env: &Env, reserve_index: u8) -> bool {
-        Self::require_reserve_index(env, reserve_index);
-        let reserve_index: u128 = reserve_index.into();
-        (self.0 >> (reserve_index * 2)) & 1 != 0
+    pub fn is_borrowing(&self, _env: &Env, reserve_index: u8) -> bool {
+        (self.word(reserve_index) >> Self::bit_offset(reserve_index)) & 1 != 0
     }
 
     pub fn is_borrowing_any(&self) -> bool {
-        self.0 & BORROWING_MASK != 0
+        self.0.iter().any(|word| word & BORROWING_MASK != 0)
+    }
+
+    pub fn is_using_any_as_collateral(&self) -> bool {
+        self.0.iter().any(|word| word & !BORROWING_MASK != 0)
     }
 
     pub fn is_empty(&self) -> bool {
-        self.0 == 0
+        self.0.iter().all(|word| word == 0)
     }
 
     pub fn total_assets(&self) -> u32 {
         self.1
     }
 
-    fn require_reserve_index(env: &Env, reserve_index: u8) {
-        assert_with_error!(
-            env,
-            reserve_index < core::mem::size_of::<u128>() as u8 / 2, // todo: size_of returns bytes, not bits, need to multiply by 8
-            Error::ExceededMaxValue
+    /// Builds the configuration from the single word layout used before
+    /// the bitmap was extended beyond 64 reserves.
+    pub fn from_legacy(env: &Env, word: u128, total_assets: u32) -> Self {
+        let mut words = Vec::new(env);
+
+        if word != 0 {
+            words.push_back(word);
+        }
+
+        Self(words, total_assets)
+    }
+
+    fn word(&self, reserve_index: u8) -> u128 {
+        self.0
+            .get(Self::word_index(reserve_index))
+            .unwrap_or_default()
+    }
+
+    fn set_bit(&mut self, reserve_index: u8, bit: u32, value: bool) {
+        let word_index = Self::word_index(reserve_index);
+
+        if !value && word_index >= self.0.len() {
+            return;
+        }
+
+        while self.0.len() <= word_index {
+            self.0.push_back(0);
+        }
+
+        let offset = Self::bit_offset(reserve_index) + bit;
+        let word = self.0.get_unchecked(word_index);
+
+        self.0.set(
+            word_index,
+            (word & !(1 << offset)) | ((if value { 1 } else { 0 }) << offset),
         );
+
+        while let Some(0) = self.0.last() {
+            self.0.pop_back();
+        }
+    }
+
+    fn word_index(reserve_index: u8) -> u32 {
+        u32::from(reserve_index) / RESERVES_PER_WORD
+    }
+
+    fn bit_offset(reserve_index: u8) -> u32 {
+        (u32::from(reserve_index) % RESERVES_PER_WORD) * 2
     }
 }
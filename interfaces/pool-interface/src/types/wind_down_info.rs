@@ -0,0 +1,45 @@
+use soroban_sdk::contracttype;
+
+#[contracttype]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindDownStage {
+    /// Pool operates normally
+    Inactive,
+    /// New deposits, borrows and flash loans are disabled, borrower rates are stepped up
+    WindDown,
+    /// Lenders redeem the remaining underlying pro rata to their s-tokens
+    Settlement,
+}
+
+#[contracttype]
+pub struct WindDownInfo {
+    pub stage: WindDownStage,
+    pub started_at: u64,
+    /// Borrower interest rate added on every elapsed step of the wind-down
+    pub rate_step: u32,
+    pub step_secs: u64,
+    /// Time the settlement started at, the rate premium stops growing from then on
+    pub settled_at: u64,
+}
+
+impl WindDownInfo {
+    pub fn is_active(&self) -> bool {
+        self.stage != WindDownStage::Inactive
+    }
+
+    /// Borrower interest rate premium accumulated by the steps elapsed up to `now`
+    /// or up to the start of the settlement, whichever is earlier
+    pub fn rate_premium(&self, now: u64) -> u64 {
+        if !self.is_active() || self.step_secs == 0 {
+            return 0;
+        }
+
+        let until = match self.stage {
+            WindDownStage::Settlement => now.min(self.settled_at),
+            _ => now,
+        };
+        let steps = until.saturating_sub(self.started_at) / self.step_secs;
+
+        steps.saturating_mul(u64::from(self.rate_step))
+    }
+}
@@ -4,6 +4,15 @@
 use soroban_sdk::{contractclient, contractspecfn, Address, BytesN, Env, String};
 pub struct Spec;
 
+/// Version of the interface implemented by the current s-token contract.
+pub const VERSION: u32 = 1;
+
+/// Oldest s-token version the pool is able to work with.
+pub const MIN_COMPATIBLE_VERSION: u32 = 1;
+
+/// Newest s-token version the pool is able to work with.
+pub const MAX_COMPATIBLE_VERSION: u32 = VERSION;
+
 /// Interface for SToken
 #[contractspecfn(name = "Spec", export = false)]
 #[contractclient(name = "STokenClient")]
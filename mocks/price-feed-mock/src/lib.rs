@@ -7,7 +7,10 @@ use crate::storage::*;
 use price_feed_interface::types::asset::Asset;
 use price_feed_interface::types::price_data::PriceData;
 use price_feed_interface::PriceFeedTrait;
-use soroban_sdk::{contract, contractimpl, Env, Vec};
+use soroban_sdk::{contract, contractimpl, Env, Map, Vec};
+
+/// Update period of the mocked feed, in the units of the price timestamps
+const RESOLUTION: u32 = 300;
 
 #[contract]
 pub struct PriceFeedMock;
@@ -27,22 +30,43 @@ impl PriceFeedTrait for PriceFeedMock {
     }
 
     fn resolution(_env: Env) -> u32 {
-        unimplemented!()
+        RESOLUTION
     }
 
     fn price(_env: Env, _asset: Asset, _timestamp: u64) -> Option<PriceData> {
         unimplemented!()
     }
 
-    fn prices(env: Env, asset: Asset, _records: u32) -> Option<Vec<PriceData>> {
-        read_prices(&env, &asset)
+    fn prices(env: Env, asset: Asset, records: u32) -> Option<Vec<PriceData>> {
+        let mut sorted_prices = Map::new(&env);
+
+        for price in read_prices(&env, &asset)? {
+            sorted_prices.set(price.timestamp, price);
+        }
+
+        let prices = sorted_prices.values();
+        let skip = prices.len().saturating_sub(records);
+
+        Some(prices.slice(skip..))
     }
 
-    fn lastprice(_env: Env, _asset: Asset) -> Option<PriceData> {
-        unimplemented!()
+    fn lastprice(env: Env, asset: Asset) -> Option<PriceData> {
+        read_lastprice(&env, &asset).or_else(|| {
+            read_prices(&env, &asset)?
+                .iter()
+                .max_by_key(|price| price.timestamp)
+        })
     }
 
     fn init(env: Env, asset: Asset, prices: Vec<PriceData>) {
         write_init_data(&env, &asset, prices);
     }
 }
+
+#[contractimpl]
+impl PriceFeedMock {
+    /// Overrides the price returned by `lastprice` regardless of the prices history
+    pub fn set_lastprice(env: Env, asset: Asset, price: PriceData) {
+        write_lastprice(&env, &asset, &price);
+    }
+}
@@ -7,6 +7,7 @@ use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
 pub enum DataKey {
     StellarPrices(Address),
     OtherPrices(Symbol),
+    LastPrice(Asset),
 }
 
 pub fn write_init_data(env: &Env, asset: &Asset, prices: Vec<PriceData>) {
@@ -30,3 +31,15 @@ pub fn read_prices(env: &Env, asset: &Asset) -> Option<Vec<PriceData>> {
 
     Some(env.storage().instance().get(&data_key).unwrap())
 }
+
+pub fn write_lastprice(env: &Env, asset: &Asset, price: &PriceData) {
+    env.storage()
+        .instance()
+        .set(&DataKey::LastPrice(asset.clone()), price);
+}
+
+pub fn read_lastprice(env: &Env, asset: &Asset) -> Option<PriceData> {
+    env.storage()
+        .instance()
+        .get(&DataKey::LastPrice(asset.clone()))
+}